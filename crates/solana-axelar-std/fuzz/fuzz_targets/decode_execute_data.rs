@@ -0,0 +1,49 @@
+//! Feeds arbitrary bytes to the Borsh decoders of the execute data types.
+//!
+//! Decoding must never panic, and whatever decodes must encode back to the
+//! exact same bytes, since the gateway hashes and stores decoded values.
+
+#![no_main]
+
+use borsh::BorshDeserialize;
+use libfuzzer_sys::fuzz_target;
+use solana_axelar_std::execute_data::{BatchedExecuteData, ExecuteData};
+use solana_axelar_std::hasher::LeafHash;
+use solana_axelar_std::merkle::verify_single_leaf_proof;
+use solana_axelar_std::{MerklizedMessageBatch, MerklizedPayload};
+
+fn round_trips<T: BorshDeserialize + borsh::BorshSerialize>(data: &[u8]) -> Option<T> {
+    let value = T::try_from_slice(data).ok()?;
+    assert_eq!(borsh::to_vec(&value).unwrap(), data);
+    Some(value)
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(execute_data) = round_trips::<ExecuteData>(data) {
+        // Decoded proofs come straight from the input, they must be rejected
+        // gracefully rather than trusted
+        for signer in &execute_data.signing_verifier_set_leaves {
+            verify_single_leaf_proof(
+                &signer.merkle_proof,
+                &execute_data.signing_verifier_set_merkle_root,
+                signer.leaf.position.into(),
+                signer.leaf.set_size.into(),
+                signer.leaf.hash(),
+            );
+        }
+        if let MerklizedPayload::NewMessages { messages } = &execute_data.payload_items {
+            for message in messages {
+                verify_single_leaf_proof(
+                    &message.proof,
+                    &execute_data.payload_merkle_root,
+                    message.leaf.position.into(),
+                    message.leaf.set_size.into(),
+                    message.leaf.hash(),
+                );
+            }
+        }
+    }
+    round_trips::<BatchedExecuteData>(data);
+    round_trips::<MerklizedPayload>(data);
+    round_trips::<MerklizedMessageBatch>(data);
+});
@@ -13,7 +13,24 @@ pub enum EncodingError {
     #[error("The set that needs to be merklized is too large")]
     SetSizeTooLarge,
 
+    /// Indicates that a requested leaf position is not part of the set.
+    #[error("Leaf position is out of bounds")]
+    PositionOutOfBounds,
+
     /// Represents I/O related errors (usually encoding related)
     #[error("IO Error: {0}")]
     IoError(#[from] std::io::Error),
 }
+
+/// Error type for the conversions and parsing of the wide integer types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum IntegerError {
+    /// Indicates that a value doesn't fit in the target integer type.
+    #[error("Integer value out of range")]
+    OutOfRange,
+
+    /// Indicates that a string is not a valid decimal or `0x` prefixed
+    /// hexadecimal integer.
+    #[error("Invalid integer literal")]
+    InvalidLiteral,
+}
@@ -5,8 +5,10 @@ use udigest::encoding::EncodeValue;
 
 use crate::{
     hasher::{Hasher, VecBuf},
-    message::{MerklizedMessage, MessageLeaf, Messages},
-    verifier_set::{self, verifier_set_hash, SigningVerifierSetInfo},
+    message::{MerklizedMessage, MerklizedMessageBatch, MessageLeaf, Messages},
+    verifier_set::{
+        self, verifier_set_hash, SigningVerifier, SigningVerifierSetBatch, SigningVerifierSetInfo,
+    },
     EncodingError, PublicKey, Signature, VerifierSet, VerifierSetLeaf,
 };
 
@@ -36,6 +38,39 @@ pub struct ExecuteData {
     pub payload_items: MerklizedPayload,
 }
 
+impl ExecuteData {
+    /// Decodes execute data encoded by [`encode`].
+    ///
+    /// # Errors
+    /// - IO Error when the bytes aren't valid execute data
+    pub fn decode(bytes: &[u8]) -> Result<Self, EncodingError> {
+        Ok(borsh::from_slice(bytes)?)
+    }
+}
+
+/// Same as [`ExecuteData`], with the signing verifiers proven by a single
+/// Merkle multiproof rather than one proof per verifier.
+///
+/// Once uploaded to a signature batch buffer, every signature of the batch can
+/// then be checked by a single `verify_signatures_batch` instruction, and the
+/// proof nodes shared between verifiers are only posted once.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+pub struct BatchedExecuteData {
+    /// The Merkle root of the signing verifier set.
+    pub signing_verifier_set_merkle_root: [u8; 32],
+
+    /// The signing verifiers along with the multiproof of their inclusion in
+    /// the signing verifier set.
+    pub signing_verifier_set_batch: SigningVerifierSetBatch,
+
+    /// The Merkle root of the payload data.
+    pub payload_merkle_root: [u8; 32],
+
+    /// The payload items, which can either be new messages or a verifier set
+    /// rotation, each accompanied by their respective Merkle proofs.
+    pub payload_items: MerklizedPayload,
+}
+
 /// Represents the different types of payloads that can be processed within the
 /// system.
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -58,6 +93,16 @@ pub enum PayloadType {
 #[cfg(feature = "idl-build")]
 impl anchor_lang::IdlBuild for PayloadType {}
 
+impl PayloadType {
+    /// The type of signature a payload has to be signed with.
+    pub fn of(payload: &Payload) -> Self {
+        match payload {
+            Payload::Messages(_) => PayloadType::ApproveMessages,
+            Payload::NewVerifierSet(_) => PayloadType::RotateSigners,
+        }
+    }
+}
+
 impl From<PayloadType> for u8 {
     fn from(payload_type: PayloadType) -> Self {
         match payload_type {
@@ -105,18 +150,11 @@ pub fn encode(
     domain_separator: [u8; 32],
     payload: Payload,
 ) -> Result<Vec<u8>, EncodingError> {
-    let payload_type = match payload {
-        Payload::Messages(_) => PayloadType::ApproveMessages,
-        Payload::NewVerifierSet(_) => PayloadType::RotateSigners,
-    };
+    let payload_type = PayloadType::of(&payload);
 
     // Verifier Set Merkle Tree
-    let leaves = verifier_set::merkle_tree_leaves(signing_verifier_set, &domain_separator)?
-        .collect::<Vec<_>>();
-    let signer_merkle_tree = merkle_tree::<Hasher, VerifierSetLeaf>(leaves.iter());
-    let signing_verifier_set_merkle_root = signer_merkle_tree
-        .root()
-        .ok_or(EncodingError::CannotMerklizeEmptyVerifierSet)?;
+    let (leaves, signer_merkle_tree, signing_verifier_set_merkle_root) =
+        signing_verifier_set_tree(signing_verifier_set, domain_separator)?;
 
     let signing_verifier_set_leaves = leaves
         .into_iter()
@@ -150,6 +188,77 @@ pub fn encode(
     Ok(buffer)
 }
 
+/// Encodes `execute_data` components like [`encode`], as [`BatchedExecuteData`].
+///
+/// # Errors
+/// - IO Error when encoding the data
+/// - Verifier Set has too many items in it
+/// - Verifier Set has no items in it
+/// - Payload messages have too many items in it
+/// - Payload messages has no items in it
+pub fn encode_batched(
+    signing_verifier_set: &VerifierSet,
+    signers_with_signatures: &BTreeMap<PublicKey, Signature>,
+    domain_separator: [u8; 32],
+    payload: Payload,
+) -> Result<Vec<u8>, EncodingError> {
+    let payload_type = PayloadType::of(&payload);
+
+    // Verifier Set Merkle Tree
+    let (leaves, signer_merkle_tree, signing_verifier_set_merkle_root) =
+        signing_verifier_set_tree(signing_verifier_set, domain_separator)?;
+
+    // Leaves are generated in position order, so the batch is ordered too
+    let verifiers = leaves
+        .into_iter()
+        .filter_map(|leaf| {
+            signers_with_signatures
+                .get(&leaf.signer_pubkey)
+                .map(|signature| SigningVerifier {
+                    signature: *signature,
+                    leaf,
+                })
+        })
+        .collect::<Vec<_>>();
+    let indices = verifiers
+        .iter()
+        .map(|verifier| usize::from(verifier.leaf.position))
+        .collect::<Vec<_>>();
+    let merkle_proof = signer_merkle_tree.proof(&indices).to_bytes();
+
+    // Payload Merkle Tree
+    let (payload_merkle_root, payload_items) =
+        hash_payload_internal::<Hasher>(payload, domain_separator)?;
+
+    let execute_data = BatchedExecuteData {
+        signing_verifier_set_merkle_root,
+        signing_verifier_set_batch: SigningVerifierSetBatch {
+            verifiers,
+            merkle_proof,
+            payload_type,
+        },
+        payload_merkle_root,
+        payload_items,
+    };
+    Ok(borsh::to_vec(&execute_data)?)
+}
+
+/// Builds the Merkle tree of the signing verifier set, returning its leaves,
+/// the tree itself and its root.
+fn signing_verifier_set_tree(
+    signing_verifier_set: &VerifierSet,
+    domain_separator: [u8; 32],
+) -> Result<(Vec<VerifierSetLeaf>, MerkleTree<Hasher>, [u8; 32]), EncodingError> {
+    let leaves = verifier_set::merkle_tree_leaves(signing_verifier_set, &domain_separator)?
+        .collect::<Vec<_>>();
+    let signer_merkle_tree = merkle_tree::<Hasher, VerifierSetLeaf>(leaves.iter());
+    let signing_verifier_set_merkle_root = signer_merkle_tree
+        .root()
+        .ok_or(EncodingError::CannotMerklizeEmptyVerifierSet)?;
+
+    Ok((leaves, signer_merkle_tree, signing_verifier_set_merkle_root))
+}
+
 fn estimate_size(execute_data: &ExecuteData) -> usize {
     size_of::<ExecuteData>()
         .saturating_add({
@@ -181,6 +290,14 @@ fn estimate_size(execute_data: &ExecuteData) -> usize {
         )
 }
 
+/// Builds the prefixed message that verifiers sign, before it gets hashed.
+///
+/// This is the message expected by the secp256k1 precompile, which hashes it
+/// itself; see [`prefixed_message_hash_payload_type`] for the digest.
+pub fn prefixed_message_payload_type(payload_type: PayloadType, message: &[u8; 32]) -> Vec<u8> {
+    [SOLANA_OFFCHAIN_PREFIX, &[payload_type.into()], message].concat()
+}
+
 #[allow(clippy::indexing_slicing)]
 pub fn prefixed_message_hash_payload_type(
     payload_type: PayloadType,
@@ -255,6 +372,56 @@ fn hash_payload_internal<T: rs_merkle::Hasher<Hash = [u8; 32]>>(
     Ok((payload_merkle_root, payload_items))
 }
 
+/// Merklizes a messages payload and builds a single multi-proof covering the
+/// messages at the given positions.
+///
+/// Returns the payload merkle root along with the batch, whose leaves are
+/// ordered by position.
+///
+/// # Errors
+/// - When the message set is empty
+/// - When the message set is too large
+/// - When a position is not part of the message set
+pub fn merklize_message_batch<T: rs_merkle::Hasher<Hash = [u8; 32]>>(
+    messages: Messages,
+    domain_separator: [u8; 32],
+    positions: &[u16],
+) -> Result<([u8; 32], MerklizedMessageBatch), EncodingError> {
+    let leaves =
+        crate::message::merkle_tree_leaves(messages, domain_separator)?.collect::<Vec<_>>();
+    let messages_merkle_tree = merkle_tree::<T, MessageLeaf>(leaves.iter());
+    let messages_merkle_root = messages_merkle_tree
+        .root()
+        .ok_or(EncodingError::CannotMerklizeEmptyMessageSet)?;
+
+    let mut positions = positions.to_vec();
+    positions.sort_unstable();
+    positions.dedup();
+
+    let indices = positions
+        .iter()
+        .map(|position| usize::from(*position))
+        .collect::<Vec<_>>();
+    let batch_leaves = indices
+        .iter()
+        .map(|index| {
+            leaves
+                .get(*index)
+                .cloned()
+                .ok_or(EncodingError::PositionOutOfBounds)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let proof = messages_merkle_tree.proof(&indices);
+
+    Ok((
+        messages_merkle_root,
+        MerklizedMessageBatch {
+            leaves: batch_leaves,
+            proof: proof.to_bytes(),
+        },
+    ))
+}
+
 pub(crate) fn merkle_tree<'a, T: rs_merkle::Hasher, K: udigest::Digestable + 'a>(
     leaves: impl Iterator<Item = &'a K>,
 ) -> MerkleTree<T> {
@@ -404,4 +571,140 @@ mod tests {
             assert!(encoded.len() <= estimated_size);
         }
     }
+
+    #[test]
+    fn merklize_message_batch_multi_proof_verifies() {
+        use crate::hasher::LeafHash;
+
+        let domain_separator = [1u8; 32];
+        let messages = Messages(
+            (0..5)
+                .map(|i| Message {
+                    cc_id: CrossChainId {
+                        chain: "test".to_owned(),
+                        id: i.to_string(),
+                    },
+                    source_address: "src".to_owned(),
+                    destination_address: "dst".to_owned(),
+                    destination_chain: "chain".to_owned(),
+                    payload_hash: [i; 32],
+                })
+                .collect(),
+        );
+
+        let payload_merkle_root =
+            hash_payload::<Hasher>(&domain_separator, Payload::Messages(messages.clone())).unwrap();
+
+        let (root, batch) =
+            merklize_message_batch::<Hasher>(messages, domain_separator, &[4, 1, 3, 1]).unwrap();
+        assert_eq!(root, payload_merkle_root);
+
+        let positions = batch
+            .leaves
+            .iter()
+            .map(|leaf| leaf.position)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![1, 3, 4]);
+
+        let indices = positions
+            .iter()
+            .map(|p| usize::from(*p))
+            .collect::<Vec<_>>();
+        let leaf_hashes = batch.leaves.iter().map(LeafHash::hash).collect::<Vec<_>>();
+        let proof = crate::MerkleProof::from_bytes(&batch.proof).unwrap();
+        assert!(proof.verify(root, &indices, &leaf_hashes, 5));
+    }
+
+    #[test]
+    fn merklize_message_batch_rejects_out_of_bounds_position() {
+        let messages = Messages(vec![Message {
+            cc_id: CrossChainId {
+                chain: "test".to_owned(),
+                id: "1".to_owned(),
+            },
+            source_address: "src".to_owned(),
+            destination_address: "dst".to_owned(),
+            destination_chain: "chain".to_owned(),
+            payload_hash: [0u8; 32],
+        }]);
+
+        let result = merklize_message_batch::<Hasher>(messages, [1u8; 32], &[1]);
+        assert!(matches!(result, Err(EncodingError::PositionOutOfBounds)));
+    }
+
+    #[test]
+    fn encode_batched_multi_proof_verifies() {
+        use crate::hasher::LeafHash;
+
+        let domain_separator = [1u8; 32];
+        let verifier_set = create_test_verifier_set(7);
+        let mut signatures = create_test_signatures(&verifier_set);
+        // Leave out one of the signers
+        let missing_signer = *signatures.keys().nth(2).unwrap();
+        signatures.remove(&missing_signer);
+
+        let payload = Payload::NewVerifierSet(create_test_verifier_set(3));
+        let encoded = encode_batched(&verifier_set, &signatures, domain_separator, payload)
+            .expect("encoding should succeed");
+        let execute_data: BatchedExecuteData = borsh::BorshDeserialize::try_from_slice(&encoded)
+            .expect("deserialization should succeed");
+
+        let batch = &execute_data.signing_verifier_set_batch;
+        assert_eq!(batch.payload_type, PayloadType::RotateSigners);
+        let positions = batch
+            .verifiers
+            .iter()
+            .map(|verifier| verifier.leaf.position)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![0, 1, 3, 4, 5, 6]);
+
+        let indices = positions
+            .iter()
+            .map(|p| usize::from(*p))
+            .collect::<Vec<_>>();
+        let leaf_hashes = batch
+            .verifiers
+            .iter()
+            .map(|verifier| verifier.leaf.hash())
+            .collect::<Vec<_>>();
+        let proof = crate::MerkleProof::from_bytes(&batch.merkle_proof).unwrap();
+        assert!(proof.verify(
+            execute_data.signing_verifier_set_merkle_root,
+            &indices,
+            &leaf_hashes,
+            7
+        ));
+    }
+
+    #[test]
+    fn encode_batched_is_smaller_than_per_signer_proofs() {
+        let domain_separator = [1u8; 32];
+        let verifier_set = create_test_verifier_set(32);
+        let signatures = create_test_signatures(&verifier_set);
+        let payload = Payload::NewVerifierSet(create_test_verifier_set(3));
+
+        let encoded = encode(
+            &verifier_set,
+            &signatures,
+            domain_separator,
+            payload.clone(),
+        )
+        .unwrap();
+        let encoded_batched =
+            encode_batched(&verifier_set, &signatures, domain_separator, payload).unwrap();
+
+        assert!(encoded_batched.len() < encoded.len());
+    }
+
+    #[test]
+    fn prefixed_message_hashes_to_signed_digest() {
+        let message = [7u8; 32];
+        for payload_type in [PayloadType::ApproveMessages, PayloadType::RotateSigners] {
+            let prefixed_message = prefixed_message_payload_type(payload_type, &message);
+            assert_eq!(
+                solana_keccak_hasher::hash(&prefixed_message).to_bytes(),
+                prefixed_message_hash_payload_type(payload_type, &message)
+            );
+        }
+    }
 }
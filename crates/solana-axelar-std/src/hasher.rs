@@ -40,13 +40,142 @@ impl udigest::encoding::Buffer for VecBuf {
     }
 }
 
+/// Capacity of the stack buffer used by [`LeafHash::hash`].
+///
+/// Verifier set leaves always fit. Message leaves fit unless their strings are
+/// unusually long, in which case hashing falls back to a heap buffer. The
+/// buffer stays well within the 4KB stack frame of SBF programs.
+pub const LEAF_ENCODING_CAPACITY: usize = 1024;
+
+/// Fixed-capacity encoding buffer that lives on the stack.
+///
+/// Writes that don't fit mark the buffer as overflowed instead of being
+/// truncated, so a partial encoding is never hashed.
+pub(crate) struct ArrayBuf<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+    overflowed: bool,
+}
+
+impl<const N: usize> ArrayBuf<N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    /// Returns the encoded bytes, or `None` if the encoding didn't fit.
+    pub(crate) fn as_slice(&self) -> Option<&[u8]> {
+        if self.overflowed {
+            return None;
+        }
+        self.bytes.get(..self.len)
+    }
+}
+
+impl<const N: usize> udigest::encoding::Buffer for ArrayBuf<N> {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.overflowed {
+            return;
+        }
+        let end = self.len.saturating_add(bytes.len());
+        match self.bytes.get_mut(self.len..end) {
+            Some(destination) => {
+                destination.copy_from_slice(bytes);
+                self.len = end;
+            }
+            None => self.overflowed = true,
+        }
+    }
+}
+
 /// Trait for hashing leaves within a Merkle tree, implemented by types that can
 /// be digested.
 pub trait LeafHash: udigest::Digestable {
     /// Returns a hashed representation of the implementing type.
+    ///
+    /// The encoding is written to a stack buffer of [`LEAF_ENCODING_CAPACITY`]
+    /// bytes, so hashing a leaf on chain doesn't allocate. Leaves that don't fit
+    /// are re-encoded into a heap buffer; both paths produce the same hash.
     fn hash(&self) -> [u8; 32] {
+        let mut buffer = ArrayBuf::<LEAF_ENCODING_CAPACITY>::new();
+        self.unambiguously_encode(EncodeValue::new(&mut buffer));
+        if let Some(encoded) = buffer.as_slice() {
+            return solana_keccak_hasher::hash(encoded).to_bytes();
+        }
+
         let mut buffer = VecBuf(vec![]);
         self.unambiguously_encode(EncodeValue::new(&mut buffer));
         solana_keccak_hasher::hash(&buffer.0).to_bytes()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CrossChainId, Message, MessageLeaf, PublicKey, VerifierSetLeaf};
+
+    fn heap_hash<T: udigest::Digestable>(value: &T) -> [u8; 32] {
+        let mut buffer = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buffer));
+        solana_keccak_hasher::hash(&buffer.0).to_bytes()
+    }
+
+    fn stack_encoding<T: udigest::Digestable>(value: &T) -> Option<Vec<u8>> {
+        let mut buffer = ArrayBuf::<LEAF_ENCODING_CAPACITY>::new();
+        value.unambiguously_encode(EncodeValue::new(&mut buffer));
+        buffer.as_slice().map(<[u8]>::to_vec)
+    }
+
+    fn message_leaf(source_chain: &str, id: &str, source_address: &str) -> MessageLeaf {
+        MessageLeaf {
+            message: Message {
+                cc_id: CrossChainId {
+                    chain: source_chain.to_owned(),
+                    id: id.to_owned(),
+                },
+                source_address: source_address.to_owned(),
+                destination_chain: "solana".to_owned(),
+                destination_address: "gtwLjHAsfKAR6GWB4hzTUAA1w4SDdFMKamtGA5ttMEe".to_owned(),
+                payload_hash: [3; 32],
+            },
+            position: 1,
+            set_size: 2,
+            domain_separator: [4; 32],
+        }
+    }
+
+    #[test]
+    fn verifier_set_leaf_is_hashed_on_the_stack() {
+        let leaf = VerifierSetLeaf {
+            nonce: u64::MAX,
+            quorum: u128::MAX,
+            signer_pubkey: PublicKey([2; 33]),
+            signer_weight: u128::MAX,
+            position: u16::MAX,
+            set_size: u16::MAX,
+            domain_separator: [1; 32],
+        };
+
+        assert!(stack_encoding(&leaf).is_some());
+        assert_eq!(leaf.hash(), heap_hash(&leaf));
+    }
+
+    #[test]
+    fn message_leaf_hash_matches_heap_encoding() {
+        let typical = message_leaf(
+            "ethereum",
+            &format!("0x{}-7", "ab".repeat(32)),
+            "0x4F4495243837681061C4743b74B3eEdf548D56A5",
+        );
+        assert!(stack_encoding(&typical).is_some());
+        assert_eq!(typical.hash(), heap_hash(&typical));
+
+        let long_address = "s".repeat(LEAF_ENCODING_CAPACITY);
+        let oversized = message_leaf("ethereum", "0x1-1", &long_address);
+        assert!(stack_encoding(&oversized).is_none());
+        assert_eq!(oversized.hash(), heap_hash(&oversized));
+    }
+}
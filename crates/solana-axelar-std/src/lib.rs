@@ -16,17 +16,27 @@ pub type MerkleTree = rs_merkle::MerkleTree<Hasher>;
 pub mod hasher;
 
 pub mod verifier_set;
-pub use verifier_set::{SigningVerifierSetInfo, VerifierSet, VerifierSetHash, VerifierSetLeaf};
+pub use verifier_set::{
+    PrecompiledVerifierSetInfo, SigningVerifier, SigningVerifierSetBatch, SigningVerifierSetInfo,
+    VerifierSet, VerifierSetHash, VerifierSetLeaf,
+};
 
 pub mod message;
-pub use message::{CrossChainId, MerklizedMessage, Message, MessageLeaf, Messages};
+pub use message::{
+    CrossChainId, MerklizedMessage, MerklizedMessageBatch, Message, MessageLeaf, Messages,
+};
 
 mod error;
-pub use error::EncodingError;
+pub use error::{EncodingError, IntegerError};
 
 pub mod execute_data;
 pub use execute_data::{MerklizedPayload, Payload, PayloadType};
 
+#[cfg(feature = "verify")]
+pub mod verify;
+#[cfg(feature = "verify")]
+pub use verify::{VerificationError, VerificationReport};
+
 pub mod merkle;
 
 pub mod program_utils;
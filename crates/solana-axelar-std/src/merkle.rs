@@ -1,7 +1,9 @@
-use rs_merkle::MerkleTree;
+use core::slice::ChunksExact;
+
+use rs_merkle::{Hasher as _, MerkleTree};
 use udigest::encoding::EncodeValue;
 
-use crate::hasher::VecBuf;
+use crate::hasher::{Hasher, VecBuf};
 
 pub(crate) fn merkle_tree<'a, T: rs_merkle::Hasher, K: udigest::Digestable + 'a>(
     leaves: impl Iterator<Item = &'a K>,
@@ -15,3 +17,136 @@ pub(crate) fn merkle_tree<'a, T: rs_merkle::Hasher, K: udigest::Digestable + 'a>
         .collect::<Vec<_>>();
     MerkleTree::<T>::from_leaves(&leaves)
 }
+
+/// Iterator over the hashes of a serialized Merkle proof, borrowing from the
+/// proof bytes instead of copying them.
+pub struct ProofHashes<'a> {
+    chunks: ChunksExact<'a, u8>,
+}
+
+impl<'a> ProofHashes<'a> {
+    /// Returns `None` if `proof` isn't a whole number of hashes.
+    pub fn new(proof: &'a [u8]) -> Option<Self> {
+        let chunks = proof.chunks_exact(32);
+        chunks.remainder().is_empty().then_some(Self { chunks })
+    }
+}
+
+impl<'a> Iterator for ProofHashes<'a> {
+    type Item = &'a [u8; 32];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().and_then(|chunk| chunk.try_into().ok())
+    }
+}
+
+/// Verifies that `leaf_hash` is the leaf at `position` of a tree with
+/// `set_size` leaves and the given `root`.
+///
+/// Accepts the same proofs as `MerkleProof::verify` for a single leaf, but
+/// walks the proof bytes in place, so nothing is allocated. Prefer this on
+/// chain whenever a proof covers one leaf.
+pub fn verify_single_leaf_proof(
+    proof: &[u8],
+    root: &[u8; 32],
+    position: usize,
+    set_size: usize,
+    leaf_hash: [u8; 32],
+) -> bool {
+    let Some(mut siblings) = ProofHashes::new(proof) else {
+        return false;
+    };
+    if position >= set_size {
+        return false;
+    }
+
+    let mut node = leaf_hash;
+    let mut index = position;
+    let mut layer_len = set_size;
+    while layer_len > 1 {
+        let is_left = index & 1 == 0;
+        // The last node of an odd layer has no sibling, so the proof doesn't
+        // carry one and the node is hashed with itself
+        let has_sibling = !is_left || index + 1 < layer_len;
+        node = if has_sibling {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            if is_left {
+                Hasher::concat_and_hash(&node, Some(sibling))
+            } else {
+                Hasher::concat_and_hash(sibling, Some(&node))
+            }
+        } else {
+            Hasher::concat_and_hash(&node, None)
+        };
+        index >>= 1;
+        layer_len = (layer_len >> 1) + (layer_len & 1);
+    }
+
+    siblings.next().is_none() && node == *root
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::MerkleProof;
+
+    fn leaves(count: u8) -> Vec<[u8; 32]> {
+        (0..count).map(|i| Hasher::hash(&[i])).collect()
+    }
+
+    #[test]
+    fn single_leaf_proof_matches_rs_merkle() {
+        for set_size in 1..=33 {
+            let leaves = leaves(set_size);
+            let tree = crate::MerkleTree::from_leaves(&leaves);
+            let root = tree.root().unwrap();
+
+            for (position, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(&[position]).to_bytes();
+                let set_size = leaves.len();
+
+                assert!(MerkleProof::from_bytes(&proof).unwrap().verify(
+                    root,
+                    &[position],
+                    &[*leaf],
+                    set_size
+                ));
+                assert!(verify_single_leaf_proof(
+                    &proof, &root, position, set_size, *leaf
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn single_leaf_proof_rejects_mismatches() {
+        let leaves = leaves(7);
+        let tree = crate::MerkleTree::from_leaves(&leaves);
+        let root = tree.root().unwrap();
+        let proof = tree.proof(&[4]).to_bytes();
+
+        assert!(verify_single_leaf_proof(&proof, &root, 4, 7, leaves[4]));
+        assert!(!verify_single_leaf_proof(&proof, &root, 4, 7, leaves[3]));
+        assert!(!verify_single_leaf_proof(&proof, &root, 5, 7, leaves[4]));
+        assert!(!verify_single_leaf_proof(&proof, &root, 4, 8, leaves[4]));
+        assert!(!verify_single_leaf_proof(&proof, &root, 7, 7, leaves[4]));
+        assert!(!verify_single_leaf_proof(&proof, &[0; 32], 4, 7, leaves[4]));
+
+        // Truncated, extended and misaligned proofs
+        let short = proof.get(..proof.len() - 32).unwrap();
+        assert!(!verify_single_leaf_proof(short, &root, 4, 7, leaves[4]));
+        let long = [proof.as_slice(), &[0_u8; 32][..]].concat();
+        assert!(!verify_single_leaf_proof(&long, &root, 4, 7, leaves[4]));
+        let misaligned = [proof.as_slice(), &[0_u8][..]].concat();
+        assert!(!verify_single_leaf_proof(
+            &misaligned,
+            &root,
+            4,
+            7,
+            leaves[4]
+        ));
+    }
+}
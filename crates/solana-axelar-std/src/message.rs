@@ -126,3 +126,22 @@ pub struct MerklizedMessage {
 
 #[cfg(feature = "idl-build")]
 impl anchor_lang::IdlBuild for MerklizedMessage {}
+
+/// Represents several messages within the payload, along with a single Merkle
+/// multi-proof covering all of them.
+///
+/// This allows approving a batch of messages at once, instead of providing a
+/// separate `MerklizedMessage` (and proof) for each one.
+#[derive(Debug, Eq, PartialEq, Clone, borsh::BorshSerialize, borsh::BorshDeserialize)]
+pub struct MerklizedMessageBatch {
+    /// The leaf nodes representing the messages in the Merkle tree, ordered by
+    /// their position.
+    pub leaves: Vec<MessageLeaf>,
+
+    /// The Merkle multi-proof demonstrating the inclusion of all leaves in the
+    /// payload's Merkle tree.
+    pub proof: Vec<u8>,
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for MerklizedMessageBatch {}
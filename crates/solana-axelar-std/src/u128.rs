@@ -1,6 +1,10 @@
+use std::str::FromStr;
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 
+use crate::IntegerError;
+
 /// Custom u128 type with 8-byte alignment instead of the default 16-byte alignment.
 ///
 /// This type is required for zero-copy accounts in Anchor. The standard `u128` type
@@ -17,8 +21,9 @@ use bytemuck::{Pod, Zeroable};
 /// data from the previous program version that used `u128`.
 ///
 /// The byte representation is identical to `u128`, ensuring backwards compatibility.
+/// Ordering compares the numeric values, not the little-endian bytes.
 #[repr(C)]
-#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Pod, Zeroable)]
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Pod, Zeroable)]
 pub struct U128 {
     pub(crate) bytes: [u8; 16],
 }
@@ -42,6 +47,36 @@ impl U128 {
         u128::from_le_bytes(self.bytes)
     }
 
+    /// Create an integer value from its representation as a byte array in
+    /// little endian.
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Self { bytes }
+    }
+
+    /// Create an integer value from its representation as a byte array in
+    /// big endian.
+    #[allow(clippy::big_endian_bytes)]
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        Self::new(u128::from_be_bytes(bytes))
+    }
+
+    /// Return the memory representation of this integer as a byte array in
+    /// little-endian byte order.
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        self.bytes
+    }
+
+    /// Return the memory representation of this integer as a byte array in
+    /// big-endian byte order.
+    #[allow(clippy::big_endian_bytes)]
+    pub const fn to_be_bytes(self) -> [u8; 16] {
+        self.get().to_be_bytes()
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.get() == 0
+    }
+
     #[must_use]
     pub fn checked_add(self, other: Self) -> Option<Self> {
         self.get().checked_add(other.get()).map(Self::new)
@@ -66,6 +101,38 @@ impl U128 {
     pub fn saturating_sub(self, other: Self) -> Self {
         Self::new(self.get().saturating_sub(other.get()))
     }
+
+    #[must_use]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.get().checked_mul(other.get()).map(Self::new)
+    }
+
+    #[must_use]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self::new(self.get().saturating_mul(other.get()))
+    }
+
+    #[must_use]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.get().checked_div(other.get()).map(Self::new)
+    }
+
+    #[must_use]
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        self.get().checked_rem(other.get()).map(Self::new)
+    }
+}
+
+impl PartialOrd for U128 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U128 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.get().cmp(&other.get())
+    }
 }
 
 impl From<u128> for U128 {
@@ -108,8 +175,37 @@ impl std::fmt::Display for U128 {
     }
 }
 
+/// Parses a decimal integer, or a hexadecimal one when prefixed with `0x`.
+impl FromStr for U128 {
+    type Err = IntegerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, radix) = crate::u256::literal_radix(s);
+        u128::from_str_radix(digits, radix)
+            .map(Self::new)
+            .map_err(|_err| IntegerError::InvalidLiteral)
+    }
+}
+
+/// Serialized as a decimal string, like [`crate::U256`], since JSON numbers
+/// lose precision beyond 53 bits in most clients.
+#[cfg(feature = "serde")]
+impl serde::Serialize for U128 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for U128 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let literal = String::deserialize(deserializer)?;
+        literal.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
-#[allow(clippy::little_endian_bytes)]
+#[allow(clippy::little_endian_bytes, clippy::big_endian_bytes)]
 mod tests {
     use super::*;
 
@@ -166,5 +262,46 @@ mod tests {
         // From u64
         let from_u64: U128 = 42u64.into();
         assert_eq!(from_u64.get(), 42u128);
+
+        // Byte order
+        let bytes = 0x0102_u128;
+        assert_eq!(U128::new(bytes).to_le_bytes(), bytes.to_le_bytes());
+        assert_eq!(U128::new(bytes).to_be_bytes(), bytes.to_be_bytes());
+        assert_eq!(U128::from_le_bytes(bytes.to_le_bytes()).get(), bytes);
+        assert_eq!(U128::from_be_bytes(bytes.to_be_bytes()).get(), bytes);
+    }
+
+    #[test]
+    fn multiplication_and_division() {
+        let a = U128::new(7);
+        let b = U128::new(2);
+
+        assert_eq!(a.checked_mul(b), Some(U128::new(14)));
+        assert_eq!(a.checked_div(b), Some(U128::new(3)));
+        assert_eq!(a.checked_rem(b), Some(U128::new(1)));
+        assert!(U128::MAX.checked_mul(b).is_none());
+        assert_eq!(U128::MAX.saturating_mul(b), U128::MAX);
+        assert!(a.checked_div(U128::ZERO).is_none());
+        assert!(a.checked_rem(U128::ZERO).is_none());
+    }
+
+    #[test]
+    fn ordering_is_numeric() {
+        // 256 has a smaller first little-endian byte than 1
+        assert!(U128::new(256) > U128::new(1));
+        assert!(U128::MAX > U128::new(u128::from(u64::MAX)));
+        assert_eq!(U128::new(5).max(U128::new(300)), U128::new(300));
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!("42".parse(), Ok(U128::new(42)));
+        assert_eq!("0x2a".parse(), Ok(U128::new(42)));
+        assert_eq!(U128::MAX.to_string().parse(), Ok(U128::MAX));
+        assert_eq!("".parse::<U128>(), Err(IntegerError::InvalidLiteral));
+        assert_eq!(
+            "340282366920938463463374607431768211456".parse::<U128>(),
+            Err(IntegerError::InvalidLiteral)
+        );
     }
 }
@@ -1,8 +1,11 @@
 //! U256 implementation of uint256.
 use std::fmt::Display;
+use std::str::FromStr;
 
 use bytemuck::{Pod, Zeroable};
 
+use crate::{IntegerError, U128};
+
 /// [U256] represents uint256.
 #[derive(
     Clone,
@@ -29,6 +32,9 @@ impl U256 {
     pub const ONE: U256 = Self {
         value: [0x01, 0x00, 0x00, 0x00],
     };
+    pub const MAX: U256 = Self {
+        value: [u64::MAX; 4],
+    };
 
     /// Create an integer value from its representation as a byte array in
     /// little endian.
@@ -37,6 +43,13 @@ impl U256 {
         Self { value: cast }
     }
 
+    /// Create an integer value from its representation as a byte array in
+    /// big endian, the layout used by the EVM and ABI encoding.
+    pub fn from_be_bytes(mut bytes: [u8; 32]) -> Self {
+        bytes.reverse();
+        Self::from_le_bytes(bytes)
+    }
+
     /// const method for initializing u256
     pub const fn from_u64(i: u64) -> Self {
         let mut new_self = Self::ZERO;
@@ -51,24 +64,104 @@ impl U256 {
         bytemuck::cast(bytes)
     }
 
+    /// Return the memory representation of this integer as a byte array in
+    /// big-endian byte order.
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    fn to_bnum(self) -> bnum::types::U256 {
+        bnum::types::U256::from_digits(self.value)
+    }
+
+    fn from_bnum(value: bnum::types::U256) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+
     /// Checked integer addition. Computes `self + rhs`, returning `None` if
     /// overflow occurred.
     #[must_use]
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
-        let lhs = bnum::types::U256::from_digits(self.value);
-        let rhs = bnum::types::U256::from_digits(rhs.value);
-
-        lhs.checked_add(rhs).map(|res| Self { value: res.into() })
+        self.to_bnum()
+            .checked_add(rhs.to_bnum())
+            .map(Self::from_bnum)
     }
 
     /// Checked integer subtraction. Computes `self - rhs`, returning `None` if
     /// overflow occurred.
     #[must_use]
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
-        let lhs = bnum::types::U256::from_digits(self.value);
-        let rhs = bnum::types::U256::from_digits(rhs.value);
+        self.to_bnum()
+            .checked_sub(rhs.to_bnum())
+            .map(Self::from_bnum)
+    }
+
+    /// Checked integer multiplication. Computes `self * rhs`, returning `None`
+    /// if overflow occurred.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.to_bnum()
+            .checked_mul(rhs.to_bnum())
+            .map(Self::from_bnum)
+    }
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if
+    /// `rhs` is zero.
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.to_bnum()
+            .checked_div(rhs.to_bnum())
+            .map(Self::from_bnum)
+    }
 
-        lhs.checked_sub(rhs).map(|res| Self { value: res.into() })
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if
+    /// `rhs` is zero.
+    #[must_use]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.to_bnum()
+            .checked_rem(rhs.to_bnum())
+            .map(Self::from_bnum)
+    }
+
+    /// Saturating integer addition. Computes `self + rhs`, saturating at
+    /// [`U256::MAX`].
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::from_bnum(self.to_bnum().saturating_add(rhs.to_bnum()))
+    }
+
+    /// Saturating integer subtraction. Computes `self - rhs`, saturating at
+    /// zero.
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::from_bnum(self.to_bnum().saturating_sub(rhs.to_bnum()))
+    }
+
+    /// Saturating integer multiplication. Computes `self * rhs`, saturating at
+    /// [`U256::MAX`].
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self::from_bnum(self.to_bnum().saturating_mul(rhs.to_bnum()))
+    }
+}
+
+/// Splits an optional `0x` prefix off an integer literal, returning the digits
+/// and their radix.
+pub(crate) fn literal_radix(literal: &str) -> (&str, u32) {
+    match literal
+        .strip_prefix("0x")
+        .or_else(|| literal.strip_prefix("0X"))
+    {
+        Some(digits) => (digits, 16),
+        None => (literal, 10),
     }
 }
 
@@ -80,9 +173,7 @@ impl PartialOrd for U256 {
 
 impl Ord for U256 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let lhs = bnum::types::U256::from_digits(self.value);
-        let rhs = bnum::types::U256::from_digits(other.value);
-        lhs.cmp(&rhs)
+        self.to_bnum().cmp(&other.to_bnum())
     }
 }
 
@@ -126,21 +217,212 @@ impl From<&u128> for U256 {
     }
 }
 
+impl From<U128> for U256 {
+    fn from(value: U128) -> Self {
+        Self::from(value.get())
+    }
+}
+
+impl TryFrom<U256> for u128 {
+    type Error = IntegerError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        match value.value {
+            [low, high, 0, 0] => Ok(u128::from(low) | (u128::from(high) << 64)),
+            _ => Err(IntegerError::OutOfRange),
+        }
+    }
+}
+
+impl TryFrom<U256> for u64 {
+    type Error = IntegerError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        match value.value {
+            [low, 0, 0, 0] => Ok(low),
+            _ => Err(IntegerError::OutOfRange),
+        }
+    }
+}
+
+impl TryFrom<U256> for U128 {
+    type Error = IntegerError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        u128::try_from(value).map(U128::new)
+    }
+}
+
 impl From<U256> for bnum::types::U256 {
     fn from(val: U256) -> Self {
         bnum::types::U256::from(val.value)
     }
 }
 
+impl From<bnum::types::U256> for U256 {
+    fn from(val: bnum::types::U256) -> Self {
+        Self::from_bnum(val)
+    }
+}
+
 impl From<U256> for alloy_primitives::U256 {
     fn from(val: U256) -> Self {
         alloy_primitives::U256::from_le_bytes(val.to_le_bytes())
     }
 }
 
+impl From<alloy_primitives::U256> for U256 {
+    fn from(val: alloy_primitives::U256) -> Self {
+        Self::from_le_bytes(val.to_le_bytes::<32>())
+    }
+}
+
 impl Display for U256 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let as_bnum = bnum::types::U256::from_digits(self.value);
         f.write_str(&as_bnum.to_string())
     }
 }
+
+/// Parses a decimal integer, or a hexadecimal one when prefixed with `0x`.
+impl FromStr for U256 {
+    type Err = IntegerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, radix) = literal_radix(s);
+        bnum::types::U256::from_str_radix(digits, radix)
+            .map(Self::from_bnum)
+            .map_err(|_err| IntegerError::InvalidLiteral)
+    }
+}
+
+/// Serialized as a decimal string, since JSON numbers can't hold 256 bits.
+#[cfg(feature = "serde")]
+impl serde::Serialize for U256 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for U256 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let literal = String::deserialize(deserializer)?;
+        literal.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    fn max_u128() -> U256 {
+        U256::from(u128::MAX)
+    }
+
+    #[test]
+    fn byte_order() {
+        let value = U256::from(0x0102_u64);
+
+        let le_bytes = value.to_le_bytes();
+        assert_eq!(le_bytes[..2], [0x02, 0x01]);
+        assert_eq!(U256::from_le_bytes(le_bytes), value);
+
+        let be_bytes = value.to_be_bytes();
+        assert_eq!(be_bytes[30..], [0x01, 0x02]);
+        assert_eq!(U256::from_be_bytes(be_bytes), value);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let two = U256::from(2_u8);
+
+        assert_eq!(
+            max_u128().checked_mul(two),
+            max_u128().checked_add(max_u128())
+        );
+        assert_eq!(U256::MAX.checked_mul(two), None);
+        assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+        assert_eq!(U256::ZERO.checked_sub(U256::ONE), None);
+
+        assert_eq!(U256::from(7_u8).checked_div(two), Some(U256::from(3_u8)));
+        assert_eq!(U256::from(7_u8).checked_rem(two), Some(U256::ONE));
+        assert_eq!(U256::ONE.checked_div(U256::ZERO), None);
+        assert_eq!(U256::ONE.checked_rem(U256::ZERO), None);
+
+        assert_eq!(U256::MAX.saturating_add(U256::ONE), U256::MAX);
+        assert_eq!(U256::MAX.saturating_mul(two), U256::MAX);
+        assert_eq!(U256::ZERO.saturating_sub(U256::ONE), U256::ZERO);
+        assert!(U256::ZERO.is_zero());
+        assert!(!U256::ONE.is_zero());
+    }
+
+    #[test]
+    fn ordering_spans_all_limbs() {
+        let above_u128 = max_u128().checked_add(U256::ONE).unwrap();
+
+        assert!(above_u128 > max_u128());
+        assert!(U256::MAX > above_u128);
+        assert!(U256::ZERO < U256::ONE);
+    }
+
+    #[test]
+    fn narrowing_conversions() {
+        assert_eq!(u128::try_from(max_u128()), Ok(u128::MAX));
+        assert_eq!(U128::try_from(max_u128()), Ok(U128::MAX));
+        assert_eq!(U256::from(U128::MAX), max_u128());
+        assert_eq!(u64::try_from(U256::from(u64::MAX)), Ok(u64::MAX));
+
+        let above_u128 = max_u128().checked_add(U256::ONE).unwrap();
+        assert_eq!(u128::try_from(above_u128), Err(IntegerError::OutOfRange));
+        assert_eq!(
+            u64::try_from(U256::from(u128::MAX)),
+            Err(IntegerError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn alloy_conversions() {
+        for value in [U256::ZERO, U256::ONE, max_u128(), U256::MAX] {
+            let alloy = alloy_primitives::U256::from(value);
+            assert_eq!(alloy.to_string(), value.to_string());
+            assert_eq!(U256::from(alloy), value);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_uses_decimal_strings() {
+        let json = serde_json::to_string(&U256::MAX).unwrap();
+        assert_eq!(json, format!("\"{}\"", U256::MAX));
+        assert_eq!(serde_json::from_str::<U256>(&json).unwrap(), U256::MAX);
+        assert_eq!(
+            serde_json::from_str::<U256>("\"0x10\"").unwrap(),
+            U256::from(16_u8)
+        );
+        assert!(serde_json::from_str::<U256>("16").is_err());
+
+        let json = serde_json::to_string(&U128::MAX).unwrap();
+        assert_eq!(serde_json::from_str::<U128>(&json).unwrap(), U128::MAX);
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!("0".parse(), Ok(U256::ZERO));
+        assert_eq!(
+            "340282366920938463463374607431768211455".parse(),
+            Ok(max_u128())
+        );
+        assert_eq!(format!("0x{}", "ff".repeat(32)).parse(), Ok(U256::MAX));
+        assert_eq!(U256::MAX.to_string().parse(), Ok(U256::MAX));
+
+        assert_eq!("".parse::<U256>(), Err(IntegerError::InvalidLiteral));
+        assert_eq!("-1".parse::<U256>(), Err(IntegerError::InvalidLiteral));
+        assert_eq!("0xzz".parse::<U256>(), Err(IntegerError::InvalidLiteral));
+        assert_eq!(
+            format!("0x1{}", "00".repeat(32)).parse::<U256>(),
+            Err(IntegerError::InvalidLiteral)
+        );
+    }
+}
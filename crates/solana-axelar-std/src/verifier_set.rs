@@ -89,6 +89,59 @@ pub struct SigningVerifierSetInfo {
 #[cfg(feature = "idl-build")]
 impl anchor_lang::IdlBuild for SigningVerifierSetInfo {}
 
+/// Contains information about a single verifier within the signing verifier
+/// set, whose signature was already checked by the secp256k1 precompile.
+///
+/// Unlike [`SigningVerifierSetInfo`], the signature itself isn't included, as
+/// it lives in the precompile instruction of the same transaction.
+#[derive(Debug, Eq, PartialEq, Clone, borsh::BorshSerialize, borsh::BorshDeserialize)]
+pub struct PrecompiledVerifierSetInfo {
+    /// The leaf node representing the verifier in the Merkle tree.
+    pub leaf: VerifierSetLeaf,
+
+    /// The Merkle proof demonstrating the verifier's inclusion in the signing
+    /// verifier set.
+    pub merkle_proof: Vec<u8>,
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for PrecompiledVerifierSetInfo {}
+
+/// A signature from a single verifier within a [`SigningVerifierSetBatch`].
+#[derive(Debug, Eq, PartialEq, Clone, borsh::BorshSerialize, borsh::BorshDeserialize)]
+pub struct SigningVerifier {
+    /// The signature provided by the verifier.
+    pub signature: Signature,
+
+    /// The leaf node representing the verifier in the Merkle tree.
+    pub leaf: VerifierSetLeaf,
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for SigningVerifier {}
+
+/// Contains information about several verifiers within the signing verifier
+/// set, whose inclusion in the set is proven by a single Merkle multiproof.
+///
+/// Unlike a list of [`SigningVerifierSetInfo`], the proof nodes shared between
+/// verifiers are only included once.
+#[derive(Debug, Eq, PartialEq, Clone, borsh::BorshSerialize, borsh::BorshDeserialize)]
+pub struct SigningVerifierSetBatch {
+    /// The signing verifiers, ordered by their leaf position.
+    pub verifiers: Vec<SigningVerifier>,
+
+    /// The Merkle multiproof demonstrating the inclusion of all `verifiers`
+    /// in the signing verifier set.
+    pub merkle_proof: Vec<u8>,
+
+    /// The command type indicating what the signed message is for
+    /// MessageApproval or SignerRotation
+    pub payload_type: PayloadType,
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for SigningVerifierSetBatch {}
+
 /// Generates the Merkle root hash for a given verifier set.
 ///
 /// The `verifier_set_hash` function constructs a Merkle tree from the leaves
@@ -0,0 +1,508 @@
+//! Offline verification of [`ExecuteData`], mirroring the checks the gateway
+//! performs on chain.
+//!
+//! Lets relayers reject bad proofs or signatures before paying for the
+//! transactions that would fail on chain.
+
+use std::collections::BTreeSet;
+
+use crate::execute_data::{prefixed_message_hash_payload_type, ExecuteData, MerklizedPayload};
+use crate::hasher::LeafHash;
+use crate::{MerkleProof, PayloadType, PublicKey, Signature, VerifierSetLeaf};
+
+/// Outcome of a successful [`ExecuteData::verify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// The type of payload the verifiers signed.
+    pub payload_type: PayloadType,
+
+    /// The total weight of the verifiers whose signature is valid.
+    pub signed_weight: u128,
+
+    /// The weight required for the payload to be accepted by the gateway.
+    pub quorum: u128,
+
+    /// The number of verifiers that signed the payload.
+    pub signer_count: usize,
+}
+
+impl VerificationReport {
+    /// Whether the signed weight reaches the quorum of the signing verifier
+    /// set.
+    pub const fn has_quorum(&self) -> bool {
+        self.signed_weight >= self.quorum
+    }
+}
+
+/// Reasons for [`ExecuteData`] to be rejected.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum VerificationError {
+    /// The execute data doesn't carry any signature.
+    #[error("No signing verifier")]
+    NoSigners,
+
+    /// A verifier leaf doesn't belong to the same verifier set as the others.
+    #[error("Verifier at position {position} belongs to another verifier set")]
+    InconsistentVerifierSet { position: u16 },
+
+    /// A verifier signed a different payload type than the others.
+    #[error("Verifier at position {position} signed another payload type")]
+    InconsistentPayloadType { position: u16 },
+
+    /// The same verifier signed more than once.
+    #[error("Verifier at position {position} signed more than once")]
+    DuplicateSigner { position: u16 },
+
+    /// A verifier isn't part of the signing verifier set Merkle root.
+    #[error("Invalid Merkle proof for the verifier at position {position}")]
+    InvalidVerifierMerkleProof { position: u16 },
+
+    /// A verifier's signature doesn't match the payload Merkle root.
+    #[error("Invalid signature for the verifier at position {position}")]
+    InvalidSignature { position: u16 },
+
+    /// The total weight of the signers overflows.
+    #[error("Signed weight overflow")]
+    WeightOverflow,
+
+    /// The payload items don't match the signed payload type.
+    #[error("Payload items don't match the signed payload type")]
+    PayloadTypeMismatch,
+
+    /// The new verifier set isn't the signed payload Merkle root.
+    #[error("New verifier set doesn't match the payload Merkle root")]
+    PayloadMerkleRootMismatch,
+
+    /// A message leaf uses another domain separator than the verifiers.
+    #[error("Message at position {position} has another domain separator")]
+    DomainSeparatorMismatch { position: u16 },
+
+    /// A message isn't part of the payload Merkle root.
+    #[error("Invalid Merkle proof for the message at position {position}")]
+    InvalidMessageMerkleProof { position: u16 },
+}
+
+impl ExecuteData {
+    /// Checks every Merkle proof and signature of the execute data, and
+    /// reports the weight that signed the payload.
+    ///
+    /// Reaching the quorum is not checked, see
+    /// [`VerificationReport::has_quorum`].
+    pub fn verify(&self) -> Result<VerificationReport, VerificationError> {
+        let first_signer = self
+            .signing_verifier_set_leaves
+            .first()
+            .ok_or(VerificationError::NoSigners)?;
+        let payload_type = first_signer.payload_type;
+        let reference_leaf = &first_signer.leaf;
+
+        let signed_message =
+            prefixed_message_hash_payload_type(payload_type, &self.payload_merkle_root);
+
+        let mut positions = BTreeSet::new();
+        let mut signed_weight = 0_u128;
+        for signer in &self.signing_verifier_set_leaves {
+            let leaf = &signer.leaf;
+            let position = leaf.position;
+
+            if !is_same_verifier_set(leaf, reference_leaf) {
+                return Err(VerificationError::InconsistentVerifierSet { position });
+            }
+            if signer.payload_type != payload_type {
+                return Err(VerificationError::InconsistentPayloadType { position });
+            }
+            if !positions.insert(position) {
+                return Err(VerificationError::DuplicateSigner { position });
+            }
+
+            let is_valid_proof = MerkleProof::from_bytes(&signer.merkle_proof).is_ok_and(|proof| {
+                proof.verify(
+                    self.signing_verifier_set_merkle_root,
+                    &[position.into()],
+                    &[leaf.hash()],
+                    leaf.set_size.into(),
+                )
+            });
+            if !is_valid_proof {
+                return Err(VerificationError::InvalidVerifierMerkleProof { position });
+            }
+
+            verify_signature(leaf, &signer.signature, &signed_message)?;
+
+            signed_weight = signed_weight
+                .checked_add(leaf.signer_weight)
+                .ok_or(VerificationError::WeightOverflow)?;
+        }
+
+        self.verify_payload_items(payload_type, &reference_leaf.domain_separator)?;
+
+        Ok(VerificationReport {
+            payload_type,
+            signed_weight,
+            quorum: reference_leaf.quorum,
+            signer_count: positions.len(),
+        })
+    }
+
+    fn verify_payload_items(
+        &self,
+        payload_type: PayloadType,
+        domain_separator: &[u8; 32],
+    ) -> Result<(), VerificationError> {
+        match (&self.payload_items, payload_type) {
+            (
+                MerklizedPayload::VerifierSetRotation {
+                    new_verifier_set_merkle_root,
+                },
+                PayloadType::RotateSigners,
+            ) => {
+                if *new_verifier_set_merkle_root != self.payload_merkle_root {
+                    return Err(VerificationError::PayloadMerkleRootMismatch);
+                }
+            }
+            (MerklizedPayload::NewMessages { messages }, PayloadType::ApproveMessages) => {
+                for message in messages {
+                    let leaf = &message.leaf;
+                    let position = leaf.position;
+
+                    if leaf.domain_separator != *domain_separator {
+                        return Err(VerificationError::DomainSeparatorMismatch { position });
+                    }
+
+                    let is_valid_proof =
+                        MerkleProof::from_bytes(&message.proof).is_ok_and(|proof| {
+                            proof.verify(
+                                self.payload_merkle_root,
+                                &[position.into()],
+                                &[leaf.hash()],
+                                leaf.set_size.into(),
+                            )
+                        });
+                    if !is_valid_proof {
+                        return Err(VerificationError::InvalidMessageMerkleProof { position });
+                    }
+                }
+            }
+            _ => return Err(VerificationError::PayloadTypeMismatch),
+        }
+
+        Ok(())
+    }
+}
+
+fn is_same_verifier_set(leaf: &VerifierSetLeaf, other: &VerifierSetLeaf) -> bool {
+    leaf.nonce == other.nonce
+        && leaf.quorum == other.quorum
+        && leaf.set_size == other.set_size
+        && leaf.domain_separator == other.domain_separator
+}
+
+/// Checks the signature of the verifier of `leaf` over `signed_message`, the
+/// same way the gateway does.
+fn verify_signature(
+    leaf: &VerifierSetLeaf,
+    signature: &Signature,
+    signed_message: &[u8; 32],
+) -> Result<(), VerificationError> {
+    let PublicKey(pubkey) = &leaf.signer_pubkey;
+
+    // The recovery id is placed at the end of the signature
+    let (signature, recovery_id) = match &signature.0 {
+        [first_64 @ .., recovery_id] => (first_64, recovery_id),
+    };
+
+    let message = libsecp256k1::Message::parse(signed_message);
+    let is_valid = libsecp256k1::Signature::parse_standard(signature)
+        .and_then(|signature| {
+            let recovery_id = libsecp256k1::RecoveryId::parse(*recovery_id)?;
+            libsecp256k1::recover(&message, &signature, &recovery_id)
+        })
+        .is_ok_and(|recovered| recovered.serialize_compressed() == *pubkey);
+
+    if !is_valid {
+        return Err(VerificationError::InvalidSignature {
+            position: leaf.position,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::execute_data;
+    use crate::{hasher::Hasher, CrossChainId, Message, Messages, Payload, VerifierSet};
+
+    const DOMAIN_SEPARATOR: [u8; 32] = [3; 32];
+
+    struct SecretKey(libsecp256k1::SecretKey);
+
+    impl SecretKey {
+        fn public_key(&self) -> PublicKey {
+            PublicKey(libsecp256k1::PublicKey::from_secret_key(&self.0).serialize_compressed())
+        }
+
+        fn sign(&self, payload_type: PayloadType, payload_merkle_root: &[u8; 32]) -> Signature {
+            let message = prefixed_message_hash_payload_type(payload_type, payload_merkle_root);
+            let (signature, recovery_id) =
+                libsecp256k1::sign(&libsecp256k1::Message::parse(&message), &self.0);
+            let mut bytes = [0_u8; 65];
+            let (signature_bytes, recovery_id_bytes) = bytes.split_at_mut(64);
+            signature_bytes.copy_from_slice(&signature.serialize());
+            recovery_id_bytes.copy_from_slice(&[recovery_id.serialize()]);
+            Signature(bytes)
+        }
+    }
+
+    fn secret_keys() -> Vec<SecretKey> {
+        (1..=4_u8)
+            .map(|seed| SecretKey(libsecp256k1::SecretKey::parse(&[seed; 32]).unwrap()))
+            .collect()
+    }
+
+    fn verifier_set(secret_keys: &[SecretKey]) -> VerifierSet {
+        VerifierSet {
+            nonce: 1,
+            signers: secret_keys
+                .iter()
+                .map(|secret_key| (secret_key.public_key(), 10_u128))
+                .collect(),
+            quorum: 30,
+        }
+    }
+
+    fn messages_payload() -> Payload {
+        Payload::Messages(Messages(
+            (0..3_u8)
+                .map(|id| Message {
+                    cc_id: CrossChainId {
+                        chain: "ethereum".to_owned(),
+                        id: id.to_string(),
+                    },
+                    source_address: "0xSource".to_owned(),
+                    destination_chain: "solana".to_owned(),
+                    destination_address: "destination".to_owned(),
+                    payload_hash: [id; 32],
+                })
+                .collect(),
+        ))
+    }
+
+    /// Encodes `payload` signed by the `signers` out of `secret_keys`.
+    fn encode(secret_keys: &[SecretKey], signers: usize, payload: Payload) -> Vec<u8> {
+        let payload_type = PayloadType::of(&payload);
+        let payload_merkle_root =
+            execute_data::hash_payload::<Hasher>(&DOMAIN_SEPARATOR, payload.clone()).unwrap();
+        let signatures = secret_keys
+            .iter()
+            .take(signers)
+            .map(|secret_key| {
+                (
+                    secret_key.public_key(),
+                    secret_key.sign(payload_type, &payload_merkle_root),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        execute_data::encode(
+            &verifier_set(secret_keys),
+            &signatures,
+            DOMAIN_SEPARATOR,
+            payload,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn decode_round_trips_encoded_execute_data() {
+        let secret_keys = secret_keys();
+        let encoded = encode(&secret_keys, 4, messages_payload());
+
+        let execute_data = ExecuteData::decode(&encoded).unwrap();
+
+        assert_eq!(borsh::to_vec(&execute_data).unwrap(), encoded);
+        assert_eq!(execute_data.signing_verifier_set_leaves.len(), 4);
+    }
+
+    #[test]
+    fn decode_rejects_trailing_bytes() {
+        let secret_keys = secret_keys();
+        let mut encoded = encode(&secret_keys, 4, messages_payload());
+        encoded.push(0);
+
+        assert!(ExecuteData::decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn verify_reports_signed_weight() {
+        let secret_keys = secret_keys();
+
+        let execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 4, messages_payload())).unwrap();
+        let report = execute_data.verify().unwrap();
+        assert_eq!(
+            report,
+            VerificationReport {
+                payload_type: PayloadType::ApproveMessages,
+                signed_weight: 40,
+                quorum: 30,
+                signer_count: 4,
+            }
+        );
+        assert!(report.has_quorum());
+
+        let execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 2, messages_payload())).unwrap();
+        let report = execute_data.verify().unwrap();
+        assert_eq!(report.signed_weight, 20);
+        assert!(!report.has_quorum());
+    }
+
+    #[test]
+    fn verify_accepts_verifier_set_rotation() {
+        let secret_keys = secret_keys();
+        let new_verifier_set = verifier_set(&secret_keys[..1]);
+
+        let execute_data = ExecuteData::decode(&encode(
+            &secret_keys,
+            3,
+            Payload::NewVerifierSet(new_verifier_set),
+        ))
+        .unwrap();
+
+        let report = execute_data.verify().unwrap();
+        assert_eq!(report.payload_type, PayloadType::RotateSigners);
+        assert!(report.has_quorum());
+    }
+
+    #[test]
+    fn verify_rejects_tampered_signature() {
+        let secret_keys = secret_keys();
+        let execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 4, messages_payload())).unwrap();
+
+        for index in 0..execute_data.signing_verifier_set_leaves.len() {
+            let mut tampered = execute_data.clone();
+            let signer = &mut tampered.signing_verifier_set_leaves[index];
+            signer.signature.0[0] ^= 1;
+            let position = signer.leaf.position;
+
+            assert_eq!(
+                tampered.verify(),
+                Err(VerificationError::InvalidSignature { position })
+            );
+        }
+        assert!(execute_data.verify().is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_signature_over_other_payload() {
+        let secret_keys = secret_keys();
+        let mut execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 4, messages_payload())).unwrap();
+
+        let signer = &mut execute_data.signing_verifier_set_leaves[1];
+        signer.signature = secret_keys[1].sign(PayloadType::ApproveMessages, &[0; 32]);
+
+        assert_eq!(
+            execute_data.verify(),
+            Err(VerificationError::InvalidSignature { position: 1 })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_invalid_verifier_proof() {
+        let secret_keys = secret_keys();
+        let mut execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 4, messages_payload())).unwrap();
+
+        execute_data.signing_verifier_set_leaves[2]
+            .leaf
+            .signer_weight = 1_000;
+
+        assert_eq!(
+            execute_data.verify(),
+            Err(VerificationError::InvalidVerifierMerkleProof { position: 2 })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_duplicate_signer() {
+        let secret_keys = secret_keys();
+        let mut execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 4, messages_payload())).unwrap();
+
+        let duplicate = execute_data.signing_verifier_set_leaves[0].clone();
+        execute_data.signing_verifier_set_leaves.push(duplicate);
+
+        assert_eq!(
+            execute_data.verify(),
+            Err(VerificationError::DuplicateSigner { position: 0 })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_invalid_message_proof() {
+        let secret_keys = secret_keys();
+        let mut execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 4, messages_payload())).unwrap();
+
+        if let MerklizedPayload::NewMessages { messages } = &mut execute_data.payload_items {
+            messages[1].leaf.message.payload_hash = [42; 32];
+        }
+
+        assert_eq!(
+            execute_data.verify(),
+            Err(VerificationError::InvalidMessageMerkleProof { position: 1 })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_mismatched_rotation_root() {
+        let secret_keys = secret_keys();
+        let mut execute_data = ExecuteData::decode(&encode(
+            &secret_keys,
+            4,
+            Payload::NewVerifierSet(verifier_set(&secret_keys[..1])),
+        ))
+        .unwrap();
+
+        execute_data.payload_items = MerklizedPayload::VerifierSetRotation {
+            new_verifier_set_merkle_root: [7; 32],
+        };
+
+        assert_eq!(
+            execute_data.verify(),
+            Err(VerificationError::PayloadMerkleRootMismatch)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_payload_type_mismatch() {
+        let secret_keys = secret_keys();
+        let mut execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 4, messages_payload())).unwrap();
+
+        execute_data.payload_items = MerklizedPayload::VerifierSetRotation {
+            new_verifier_set_merkle_root: execute_data.payload_merkle_root,
+        };
+
+        assert_eq!(
+            execute_data.verify(),
+            Err(VerificationError::PayloadTypeMismatch)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_empty_signers() {
+        let secret_keys = secret_keys();
+        let execute_data =
+            ExecuteData::decode(&encode(&secret_keys, 0, messages_payload())).unwrap();
+
+        assert_eq!(execute_data.verify(), Err(VerificationError::NoSigners));
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing, clippy::little_endian_bytes)]
+//! Wire layout of execute data as produced by the Amplifier
+//! `chain-codec-solana` contract.
+//!
+//! The fixtures are assembled field by field, independently of this crate's
+//! encoder, following the layout the contract writes: Borsh with Secp256k1
+//! public keys and recoverable signatures as plain byte arrays, without any
+//! variant tag.
+
+use solana_axelar_std::execute_data::ExecuteData;
+use solana_axelar_std::{MerklizedPayload, PayloadType};
+
+#[derive(Default)]
+struct Fixture(Vec<u8>);
+
+impl Fixture {
+    fn bytes(mut self, bytes: &[u8]) -> Self {
+        self.0.extend_from_slice(bytes);
+        self
+    }
+
+    fn vec(self, bytes: &[u8]) -> Self {
+        let len = u32::try_from(bytes.len()).unwrap();
+        self.bytes(&len.to_le_bytes()).bytes(bytes)
+    }
+
+    fn string(self, string: &str) -> Self {
+        self.vec(string.as_bytes())
+    }
+
+    /// A `SigningVerifierSetInfo` of a verifier set with a single signer.
+    fn signer(self, payload_type: u8) -> Self {
+        let mut pubkey = [0x33_u8; 33];
+        pubkey[0] = 0x02;
+        let mut signature = [0x22_u8; 65];
+        signature[64] = 0x01;
+
+        self.bytes(&signature)
+            // Leaf
+            .bytes(&7_u64.to_le_bytes())
+            .bytes(&10_u128.to_le_bytes())
+            .bytes(&pubkey)
+            .bytes(&10_u128.to_le_bytes())
+            .bytes(&0_u16.to_le_bytes())
+            .bytes(&1_u16.to_le_bytes())
+            .bytes(&[0x44; 32])
+            // Proof of a single leaf tree
+            .vec(&[])
+            .bytes(&[payload_type])
+    }
+}
+
+#[test]
+fn decodes_message_approval() {
+    let fixture = Fixture::default()
+        .bytes(&[0x11; 32])
+        .bytes(&1_u32.to_le_bytes())
+        .signer(0)
+        .bytes(&[0x55; 32])
+        // `MerklizedPayload::NewMessages` with one message
+        .bytes(&[1])
+        .bytes(&1_u32.to_le_bytes())
+        .string("ethereum")
+        .string("0xabcd-1")
+        .string("0x4F4495243837681061C4743b74B3eEdf548D56A5")
+        .string("solana")
+        .string("gtwLjHAsfKAR6GWB4hzTUAA1w4SDdFMKamtGA5ttMEe")
+        .bytes(&[0x66; 32])
+        .bytes(&0_u16.to_le_bytes())
+        .bytes(&2_u16.to_le_bytes())
+        .bytes(&[0x44; 32])
+        .vec(&[0x77; 32])
+        .0;
+
+    let execute_data = ExecuteData::decode(&fixture).unwrap();
+
+    assert_eq!(execute_data.signing_verifier_set_merkle_root, [0x11; 32]);
+    assert_eq!(execute_data.payload_merkle_root, [0x55; 32]);
+    assert_eq!(execute_data.signing_verifier_set_leaves.len(), 1);
+    let signer = &execute_data.signing_verifier_set_leaves[0];
+    assert_eq!(signer.signature.0[64], 0x01);
+    assert_eq!(signer.leaf.signer_pubkey.0[0], 0x02);
+    assert_eq!(signer.leaf.nonce, 7);
+    assert_eq!(signer.payload_type, PayloadType::ApproveMessages);
+
+    let messages = match &execute_data.payload_items {
+        MerklizedPayload::NewMessages { messages } => messages.as_slice(),
+        MerklizedPayload::VerifierSetRotation { .. } => &[],
+    };
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].leaf.message.cc_id.id, "0xabcd-1");
+    assert_eq!(messages[0].leaf.set_size, 2);
+    assert_eq!(messages[0].proof, [0x77; 32]);
+
+    assert_eq!(borsh::to_vec(&execute_data).unwrap(), fixture);
+}
+
+#[test]
+fn decodes_signer_rotation() {
+    let fixture = Fixture::default()
+        .bytes(&[0x11; 32])
+        .bytes(&1_u32.to_le_bytes())
+        .signer(1)
+        .bytes(&[0x55; 32])
+        // `MerklizedPayload::VerifierSetRotation`
+        .bytes(&[0])
+        .bytes(&[0x55; 32])
+        .0;
+
+    let execute_data = ExecuteData::decode(&fixture).unwrap();
+
+    assert_eq!(
+        execute_data.signing_verifier_set_leaves[0].payload_type,
+        PayloadType::RotateSigners
+    );
+    assert_eq!(
+        execute_data.payload_items,
+        MerklizedPayload::VerifierSetRotation {
+            new_verifier_set_merkle_root: [0x55; 32]
+        }
+    );
+    assert_eq!(borsh::to_vec(&execute_data).unwrap(), fixture);
+}
@@ -0,0 +1,184 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+//! Property-based round-trip tests of the execute data encoding.
+
+use std::collections::BTreeMap;
+
+use borsh::BorshDeserialize;
+use proptest::collection::{btree_map, vec};
+use proptest::prelude::*;
+use solana_axelar_std::execute_data::{encode, hash_payload, ExecuteData};
+use solana_axelar_std::hasher::{Hasher, LeafHash};
+use solana_axelar_std::merkle::verify_single_leaf_proof;
+use solana_axelar_std::verifier_set::verifier_set_hash;
+use solana_axelar_std::{
+    CrossChainId, MerklizedPayload, Message, Messages, Payload, PublicKey, Signature, VerifierSet,
+    ECDSA_RECOVERABLE_SIGNATURE_LEN,
+};
+
+fn public_key() -> impl Strategy<Value = PublicKey> {
+    any::<[u8; 33]>().prop_map(PublicKey)
+}
+
+fn verifier_set() -> impl Strategy<Value = VerifierSet> {
+    (
+        any::<u64>(),
+        btree_map(public_key(), 1..=u128::from(u64::MAX), 1..40),
+        any::<u128>(),
+    )
+        .prop_map(|(nonce, signers, quorum)| VerifierSet {
+            nonce,
+            signers,
+            quorum,
+        })
+}
+
+fn message() -> impl Strategy<Value = Message> {
+    (
+        "[a-z-]{0,20}",
+        ".{0,80}",
+        ".{0,64}",
+        "[a-z-]{0,20}",
+        ".{0,64}",
+        any::<[u8; 32]>(),
+    )
+        .prop_map(
+            |(chain, id, source_address, destination_chain, destination_address, payload_hash)| {
+                Message {
+                    cc_id: CrossChainId { chain, id },
+                    source_address,
+                    destination_chain,
+                    destination_address,
+                    payload_hash,
+                }
+            },
+        )
+}
+
+fn payload() -> impl Strategy<Value = Payload> {
+    prop_oneof![
+        vec(message(), 1..40).prop_map(|messages| Payload::Messages(Messages(messages))),
+        verifier_set().prop_map(Payload::NewVerifierSet),
+    ]
+}
+
+/// A verifier set along with signatures from a subset of its signers.
+fn signed_verifier_set() -> impl Strategy<Value = (VerifierSet, BTreeMap<PublicKey, Signature>)> {
+    verifier_set().prop_flat_map(|set| {
+        let size = set.signers.len();
+        (Just(set), vec(any::<bool>(), size)).prop_map(|(set, signed)| {
+            let signatures = set
+                .signers
+                .keys()
+                .zip(signed)
+                .filter(|(_, signed)| *signed)
+                .map(|(pubkey, _)| (*pubkey, Signature([7; ECDSA_RECOVERABLE_SIGNATURE_LEN])))
+                .collect();
+            (set, signatures)
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn execute_data_round_trips(
+        (set, signatures) in signed_verifier_set(),
+        domain_separator in any::<[u8; 32]>(),
+        payload in payload(),
+    ) {
+        let encoded = encode(&set, &signatures, domain_separator, payload).unwrap();
+        let decoded = ExecuteData::decode(&encoded).unwrap();
+
+        prop_assert_eq!(borsh::to_vec(&decoded).unwrap(), encoded);
+    }
+
+    #[test]
+    fn execute_data_commits_to_its_inputs(
+        (set, signatures) in signed_verifier_set(),
+        domain_separator in any::<[u8; 32]>(),
+        payload in payload(),
+    ) {
+        let encoded = encode(&set, &signatures, domain_separator, payload.clone()).unwrap();
+        let decoded = ExecuteData::decode(&encoded).unwrap();
+
+        let payload_root = hash_payload::<Hasher>(&domain_separator, payload.clone()).unwrap();
+        prop_assert_eq!(decoded.payload_merkle_root, payload_root);
+        let set_root = verifier_set_hash::<Hasher>(&set, &domain_separator).unwrap();
+        prop_assert_eq!(decoded.signing_verifier_set_merkle_root, set_root);
+
+        prop_assert_eq!(decoded.signing_verifier_set_leaves.len(), signatures.len());
+        for signer in &decoded.signing_verifier_set_leaves {
+            let signature = signatures.get(&signer.leaf.signer_pubkey);
+            prop_assert_eq!(signature, Some(&signer.signature));
+            prop_assert_eq!(signer.leaf.domain_separator, domain_separator);
+            prop_assert!(verify_single_leaf_proof(
+                &signer.merkle_proof,
+                &set_root,
+                signer.leaf.position.into(),
+                signer.leaf.set_size.into(),
+                signer.leaf.hash(),
+            ));
+        }
+
+        match (payload, decoded.payload_items) {
+            (
+                Payload::Messages(messages),
+                MerklizedPayload::NewMessages { messages: merklized },
+            ) => {
+                prop_assert_eq!(messages.0.len(), merklized.len());
+                for (message, merklized) in messages.0.iter().zip(&merklized) {
+                    prop_assert_eq!(&merklized.leaf.message, message);
+                    prop_assert!(verify_single_leaf_proof(
+                        &merklized.proof,
+                        &payload_root,
+                        merklized.leaf.position.into(),
+                        merklized.leaf.set_size.into(),
+                        merklized.leaf.hash(),
+                    ));
+                }
+            }
+            (
+                Payload::NewVerifierSet(new_set),
+                MerklizedPayload::VerifierSetRotation { new_verifier_set_merkle_root },
+            ) => {
+                let new_set_root =
+                    verifier_set_hash::<Hasher>(&new_set, &domain_separator).unwrap();
+                prop_assert_eq!(new_verifier_set_merkle_root, new_set_root);
+            }
+            (payload, items) => {
+                return Err(TestCaseError::fail(format!(
+                    "payload {payload:?} was merklized as {items:?}"
+                )));
+            }
+        }
+    }
+
+    #[test]
+    fn merklized_payload_round_trips(
+        domain_separator in any::<[u8; 32]>(),
+        payload in payload(),
+    ) {
+        let set = VerifierSet {
+            nonce: 0,
+            signers: BTreeMap::from([(PublicKey([2; 33]), 1)]),
+            quorum: 1,
+        };
+        let encoded = encode(&set, &BTreeMap::new(), domain_separator, payload).unwrap();
+        let items = ExecuteData::decode(&encoded).unwrap().payload_items;
+
+        let items_encoded = borsh::to_vec(&items).unwrap();
+        prop_assert_eq!(MerklizedPayload::try_from_slice(&items_encoded).unwrap(), items);
+    }
+
+    #[test]
+    fn truncated_execute_data_is_rejected(
+        (set, signatures) in signed_verifier_set(),
+        payload in payload(),
+        cut in any::<prop::sample::Index>(),
+    ) {
+        let encoded = encode(&set, &signatures, [0; 32], payload).unwrap();
+        let len = cut.index(encoded.len());
+
+        prop_assert!(ExecuteData::decode(&encoded[..len]).is_err());
+    }
+}
@@ -8,13 +8,15 @@ use mollusk_svm::{
 use mollusk_test_utils::get_event_authority_and_program_accounts;
 use rand::Rng;
 use solana_axelar_gateway::{
-    state::config::{InitialVerifierSet, InitializeConfigParams},
-    CallContractSigner, GatewayConfig, Message as CrossChainMessage,
-    SignatureVerificationSessionData, VerifierSetTracker,
+    state::config::{InitialVerifierSet, InitializeConfigParams, UpdateConfigParams},
+    CallContractNonce, CallContractPayloadBuffer, CallContractSigner, DestinationChainRegistry,
+    GatewayConfig, Message as CrossChainMessage, MessagePayload, OperatorshipProposal,
+    SignatureBatchBuffer, SignatureVerificationSessionData, VerifierSetEpoch, VerifierSetTracker,
 };
 use solana_axelar_std::{
-    hasher::LeafHash, MerkleTree, MessageLeaf, PayloadType, PublicKey, Signature,
-    SigningVerifierSetInfo, VerifierSetLeaf, U256,
+    hasher::LeafHash, MerkleTree, MessageLeaf, PayloadType, PrecompiledVerifierSetInfo, PublicKey,
+    Signature, SigningVerifier, SigningVerifierSetBatch, SigningVerifierSetInfo, VerifierSetLeaf,
+    U256,
 };
 use solana_sdk::{
     account::Account, instruction::Instruction, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey,
@@ -72,6 +74,83 @@ pub fn create_verifier_info(
     }
 }
 
+/// Size of the signature batch chunks written per `write_signature_batch_buffer`
+/// instruction, leaving room for the rest of the transaction.
+pub const SIGNATURE_BATCH_CHUNK_SIZE: usize = 900;
+
+/// Signs `payload_merkle_root` with the verifiers at `positions`, packing them
+/// into a single batch proven by one Merkle multiproof.
+pub fn create_signing_verifier_set_batch(
+    secret_keys: &[libsecp256k1::SecretKey],
+    verifier_leaves: &[VerifierSetLeaf],
+    positions: &[usize],
+    payload_merkle_root: [u8; 32],
+    verifier_merkle_tree: &MerkleTree,
+    payload_type: PayloadType,
+) -> SigningVerifierSetBatch {
+    let hashed_message = solana_axelar_std::execute_data::prefixed_message_hash_payload_type(
+        payload_type,
+        &payload_merkle_root,
+    );
+    let message = libsecp256k1::Message::parse(&hashed_message);
+
+    let verifiers = positions
+        .iter()
+        .map(|position| SigningVerifier {
+            signature: sign_message(&message, &secret_keys[*position]),
+            leaf: verifier_leaves[*position],
+        })
+        .collect();
+
+    SigningVerifierSetBatch {
+        verifiers,
+        merkle_proof: verifier_merkle_tree.proof(positions).to_bytes(),
+        payload_type,
+    }
+}
+
+/// Builds the instructions sysvar account as the runtime would for a
+/// transaction made of `instructions`, currently executing `current_index`.
+///
+/// Mollusk processes instructions one at a time, so this lets programs read
+/// back other instructions of the simulated transaction (e.g. precompiles).
+pub fn instructions_sysvar_account(instructions: &[Instruction], current_index: u16) -> Account {
+    use solana_sdk::sysvar::instructions::{
+        construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction,
+    };
+
+    let borrowed_instructions = instructions
+        .iter()
+        .map(|ix| BorrowedInstruction {
+            program_id: &ix.program_id,
+            accounts: ix
+                .accounts
+                .iter()
+                .map(|meta| BorrowedAccountMeta {
+                    pubkey: &meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: &ix.data,
+        })
+        .collect::<Vec<_>>();
+
+    let mut data = construct_instructions_data(&borrowed_instructions);
+
+    // The current instruction index is stored in the last two bytes
+    let index_offset = data.len() - 2;
+    data[index_offset..].copy_from_slice(&current_index.to_le_bytes());
+
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: solana_sdk_ids::sysvar::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
 // -- Gateway harness info --
 
 #[derive(Default)]
@@ -192,6 +271,65 @@ pub trait GatewaySetup: TestHarness {
         msg!("Gas service initialized.");
     }
 
+    /// Initializes the gateway's destination chain registry if needed and
+    /// registers `chain_name` in it, signed by the operator.
+    fn ensure_destination_chain_registered(&self, chain_name: &str) {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+        let destination_chain_registry = DestinationChainRegistry::find_pda().0;
+
+        if !self.account_exists(&destination_chain_registry) {
+            let init_ix = Instruction {
+                program_id: solana_axelar_gateway::ID,
+                accounts: solana_axelar_gateway::accounts::InitializeDestinationChainRegistry {
+                    gateway_root_pda: self.gateway().root,
+                    operator_or_upgrade_authority: self.operator(),
+                    program_data,
+                    destination_chain_registry,
+                    system_program: solana_sdk_ids::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: solana_axelar_gateway::instruction::InitializeDestinationChainRegistry {}
+                    .data(),
+            };
+
+            self.ctx()
+                .process_and_validate_instruction_chain(&[(&init_ix, &[Check::success()])]);
+        }
+
+        let registry: DestinationChainRegistry = self
+            .get_account_as(&destination_chain_registry)
+            .expect("destination chain registry should exist");
+        if registry.is_known_chain(chain_name) {
+            return;
+        }
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let add_ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::AddDestinationChain {
+                gateway_root_pda: self.gateway().root,
+                operator_or_upgrade_authority: self.operator(),
+                program_data,
+                destination_chain_registry,
+                system_program: solana_sdk_ids::system_program::ID,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::AddDestinationChain {
+                chain_name: chain_name.to_owned(),
+            }
+            .data(),
+        };
+
+        self.ctx()
+            .process_and_validate_instruction_chain(&[(&add_ix, &[Check::success()])]);
+    }
+
     fn ensure_gateway_initialized(&mut self) {
         self.ensure_gas_service_initialized();
 
@@ -248,6 +386,7 @@ pub trait GatewaySetup: TestHarness {
         let verifier_set_hash = verifier_merkle_tree.root().unwrap();
 
         let verifier_set_tracker_pda = VerifierSetTracker::find_pda(&verifier_set_hash).0;
+        let verifier_set_epoch_pda = VerifierSetEpoch::find_pda(U256::ONE).0;
 
         let initial_verifier_set = InitialVerifierSet {
             hash: verifier_set_hash,
@@ -279,6 +418,7 @@ pub trait GatewaySetup: TestHarness {
                 program_data,
                 gateway_root_pda,
                 verifier_set_tracker_pda,
+                verifier_set_epoch_pda: Some(verifier_set_epoch_pda),
             }
             .to_account_metas(None),
             data: solana_axelar_gateway::instruction::InitializeConfig { params }.data(),
@@ -601,6 +741,63 @@ impl GatewayTestHarness {
         verification_session_account
     }
 
+    /// Extends a payload verification session to track the signatures of a
+    /// verifier set with `set_size` verifiers.
+    pub fn extend_verification_session(
+        &self,
+        payload_merkle_root: [u8; 32],
+        payload_type: PayloadType,
+        set_size: u16,
+    ) -> InstructionResult {
+        self.extend_verification_session_with_checks(
+            payload_merkle_root,
+            payload_type,
+            set_size,
+            &[Check::success()],
+        )
+    }
+
+    /// Like `extend_verification_session` but accepts custom checks.
+    pub fn extend_verification_session_with_checks(
+        &self,
+        payload_merkle_root: [u8; 32],
+        payload_type: PayloadType,
+        set_size: u16,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let VerifierSetTracker {
+            verifier_set_hash, ..
+        } = self
+            .get_account_as(&self.gateway.verifier_set_tracker)
+            .expect("verifier set tracker should exist");
+
+        let verification_session_account = SignatureVerificationSessionData::find_pda(
+            &payload_merkle_root,
+            payload_type,
+            &verifier_set_hash,
+        )
+        .0;
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::ExtendVerificationSession {
+                payer: self.payer,
+                verification_session_account,
+                system_program: solana_sdk_ids::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::ExtendVerificationSession {
+                payload_merkle_root,
+                payload_type,
+                set_size,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
     /// Verifies a single signature against a payload verification session.
     pub fn verify_signature(
         &self,
@@ -703,6 +900,251 @@ impl GatewayTestHarness {
         self.ctx.process_and_validate_instruction_chain(&ix_checks);
     }
 
+    /// Builds the verifier infos and secp256k1 precompile instruction data
+    /// for the signers at the given positions.
+    pub fn precompiled_verifier_infos(
+        &self,
+        payload_merkle_root: [u8; 32],
+        payload_type: PayloadType,
+        positions: &[usize],
+    ) -> (Vec<PrecompiledVerifierSetInfo>, Vec<u8>) {
+        let (verifier_infos, signatures): (Vec<_>, Vec<_>) = positions
+            .iter()
+            .map(|&position| {
+                let leaf = &self.gateway.verifier_set_leaves[position];
+                let info = create_verifier_info(
+                    &self.gateway.signers[position],
+                    payload_merkle_root,
+                    leaf,
+                    position,
+                    &self.gateway.verifier_merkle_tree,
+                    payload_type,
+                );
+                let eth_address =
+                    solana_axelar_gateway::secp256k1_precompile::eth_address(&leaf.signer_pubkey.0)
+                        .expect("valid secp256k1 pubkey");
+
+                (
+                    PrecompiledVerifierSetInfo {
+                        leaf: info.leaf,
+                        merkle_proof: info.merkle_proof,
+                    },
+                    (eth_address, info.signature.0),
+                )
+            })
+            .unzip();
+
+        let message = solana_axelar_std::execute_data::prefixed_message_payload_type(
+            payload_type,
+            &payload_merkle_root,
+        );
+        let precompile_data = solana_axelar_gateway::secp256k1_precompile::build_instruction_data(
+            0,
+            &signatures,
+            &message,
+        )
+        .expect("precompile instruction should fit");
+
+        (verifier_infos, precompile_data)
+    }
+
+    /// Verifies the signatures of the verifiers at `positions` with a single
+    /// `verify_signatures_batch` instruction.
+    pub fn verify_signatures_batch(
+        &self,
+        payload_merkle_root: [u8; 32],
+        payload_type: PayloadType,
+        positions: &[usize],
+    ) -> InstructionResult {
+        let batch = create_signing_verifier_set_batch(
+            &self.gateway.signers,
+            &self.gateway.verifier_set_leaves,
+            positions,
+            payload_merkle_root,
+            &self.gateway.verifier_merkle_tree,
+            payload_type,
+        );
+        self.verify_signatures_batch_with_checks(payload_merkle_root, batch, &[Check::success()])
+    }
+
+    /// Like `verify_signatures_batch` but accepts a custom batch and checks,
+    /// which apply to the `verify_signatures_batch` instruction.
+    pub fn verify_signatures_batch_with_checks(
+        &self,
+        payload_merkle_root: [u8; 32],
+        batch: SigningVerifierSetBatch,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let instructions = self.verify_signatures_batch_instructions(payload_merkle_root, &batch);
+        let (verify_ix, upload_ixs) = instructions
+            .split_last()
+            .expect("verify instruction should exist");
+
+        let mut chain = upload_ixs
+            .iter()
+            .map(|ix| (ix, [Check::success()].as_slice()))
+            .collect::<Vec<_>>();
+        chain.push((verify_ix, checks));
+
+        self.ctx.process_and_validate_instruction_chain(&chain)
+    }
+
+    /// Builds the instructions uploading `batch` to a new signature batch
+    /// buffer paid for by the operator, each small enough for its own
+    /// transaction, followed by the `verify_signatures_batch` instruction.
+    pub fn verify_signatures_batch_instructions(
+        &self,
+        payload_merkle_root: [u8; 32],
+        batch: &SigningVerifierSetBatch,
+    ) -> Vec<Instruction> {
+        let VerifierSetTracker {
+            verifier_set_hash, ..
+        } = self
+            .get_account_as(&self.gateway.verifier_set_tracker)
+            .expect("verifier set tracker should exist");
+
+        let verification_session_account = SignatureVerificationSessionData::find_pda(
+            &payload_merkle_root,
+            batch.payload_type,
+            &verifier_set_hash,
+        )
+        .0;
+
+        let buffer_id = rand::thread_rng().gen();
+        let signature_batch_buffer = SignatureBatchBuffer::find_pda(&self.operator, buffer_id).0;
+        let batch_bytes =
+            anchor_lang::prelude::borsh::to_vec(batch).expect("batch should serialize");
+
+        let mut instructions = vec![Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::InitializeSignatureBatchBuffer {
+                payer: self.operator,
+                signature_batch_buffer,
+                system_program: solana_sdk_ids::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::InitializeSignatureBatchBuffer {
+                buffer_id,
+                batch_size: u32::try_from(batch_bytes.len()).unwrap(),
+            }
+            .data(),
+        }];
+
+        for (index, chunk) in batch_bytes.chunks(SIGNATURE_BATCH_CHUNK_SIZE).enumerate() {
+            instructions.push(Instruction {
+                program_id: solana_axelar_gateway::ID,
+                accounts: solana_axelar_gateway::accounts::WriteSignatureBatchBuffer {
+                    payer: self.operator,
+                    signature_batch_buffer,
+                }
+                .to_account_metas(None),
+                data: solana_axelar_gateway::instruction::WriteSignatureBatchBuffer {
+                    buffer_id,
+                    offset: u32::try_from(index * SIGNATURE_BATCH_CHUNK_SIZE).unwrap(),
+                    bytes: chunk.to_vec(),
+                }
+                .data(),
+            });
+        }
+
+        instructions.push(Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::VerifySignaturesBatch {
+                gateway_root_pda: self.gateway.root,
+                verification_session_account,
+                verifier_set_tracker_pda: self.gateway.verifier_set_tracker,
+                signature_batch_buffer,
+                payer: self.operator,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::VerifySignaturesBatch {
+                payload_merkle_root,
+                payload_type: batch.payload_type,
+            }
+            .data(),
+        });
+
+        instructions
+    }
+
+    /// Verifies the signatures of all signers in a single instruction, relying
+    /// on the secp256k1 precompile.
+    pub fn verify_all_signatures_precompile(
+        &self,
+        payload_merkle_root: [u8; 32],
+        payload_type: PayloadType,
+    ) -> InstructionResult {
+        let positions = (0..self.gateway.signers.len()).collect::<Vec<_>>();
+        let (verifier_infos, precompile_data) =
+            self.precompiled_verifier_infos(payload_merkle_root, payload_type, &positions);
+
+        self.verify_signatures_precompile_with_checks(
+            payload_merkle_root,
+            payload_type,
+            verifier_infos,
+            precompile_data,
+            &[Check::success()],
+        )
+    }
+
+    /// Executes `verify_signatures_precompile`, preceded by a secp256k1
+    /// precompile instruction with the given data.
+    ///
+    /// Mollusk doesn't execute the precompile itself; it is only exposed to
+    /// the gateway through the instructions sysvar.
+    pub fn verify_signatures_precompile_with_checks(
+        &self,
+        payload_merkle_root: [u8; 32],
+        payload_type: PayloadType,
+        verifier_infos: Vec<PrecompiledVerifierSetInfo>,
+        precompile_data: Vec<u8>,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let VerifierSetTracker {
+            verifier_set_hash, ..
+        } = self
+            .get_account_as(&self.gateway.verifier_set_tracker)
+            .expect("verifier set tracker should exist");
+
+        let verification_session_account = SignatureVerificationSessionData::find_pda(
+            &payload_merkle_root,
+            payload_type,
+            &verifier_set_hash,
+        )
+        .0;
+
+        let precompile_ix = Instruction {
+            program_id: solana_sdk_ids::secp256k1_program::ID,
+            accounts: vec![],
+            data: precompile_data,
+        };
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::VerifySignaturesPrecompile {
+                gateway_root_pda: self.gateway.root,
+                verification_session_account,
+                verifier_set_tracker_pda: self.gateway.verifier_set_tracker,
+                instructions_sysvar: solana_sdk::sysvar::instructions::id(),
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::VerifySignaturesPrecompile {
+                payload_merkle_root,
+                payload_type,
+                verifier_infos,
+            }
+            .data(),
+        };
+
+        self.ctx.account_store.borrow_mut().insert(
+            solana_sdk::sysvar::instructions::id(),
+            instructions_sysvar_account(&[precompile_ix, ix.clone()], 1),
+        );
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
     /// Executes signer rotation with a new verifier set hash.
     pub fn rotate_signers(
         &self,
@@ -722,6 +1164,28 @@ impl GatewayTestHarness {
         new_verifier_set_hash: [u8; 32],
         verification_session_pda: Pubkey,
         checks: &[Check],
+    ) -> InstructionResult {
+        let new_verifier_set_epoch = self
+            .get_account_as::<GatewayConfig>(&self.gateway.root)
+            .and_then(|config| config.current_epoch.checked_add(U256::ONE))
+            .map(|epoch| VerifierSetEpoch::find_pda(epoch).0);
+
+        self.rotate_signers_with_epoch_record(
+            new_verifier_set_hash,
+            verification_session_pda,
+            new_verifier_set_epoch,
+            checks,
+        )
+    }
+
+    /// Like `rotate_signers_with_checks` but with the epoch record of the new
+    /// verifier set given explicitly, `None` leaving it out of the accounts.
+    pub fn rotate_signers_with_epoch_record(
+        &self,
+        new_verifier_set_hash: [u8; 32],
+        verification_session_pda: Pubkey,
+        new_verifier_set_epoch: Option<Pubkey>,
+        checks: &[Check],
     ) -> InstructionResult {
         let (new_verifier_set_tracker_pda, _) =
             VerifierSetTracker::find_pda(&new_verifier_set_hash);
@@ -736,6 +1200,7 @@ impl GatewayTestHarness {
                 verification_session_account: verification_session_pda,
                 verifier_set_tracker_pda: self.gateway.verifier_set_tracker,
                 new_verifier_set_tracker: new_verifier_set_tracker_pda,
+                new_verifier_set_epoch,
                 payer: self.payer,
                 system_program: solana_sdk_ids::system_program::ID,
                 operator: Some(self.operator),
@@ -805,31 +1270,533 @@ impl GatewayTestHarness {
             .process_and_validate_instruction_chain(&[(&ix, checks)])
     }
 
-    /// Transfers gateway operatorship to a new operator.
-    pub fn transfer_gateway_operatorship(&self, new_operator: Pubkey) -> InstructionResult {
-        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
-            &solana_axelar_gateway::ID,
-        );
+    /// Approves a batch of messages with a single multi-proof.
+    pub fn approve_messages(
+        &self,
+        merklized_batch: &solana_axelar_std::MerklizedMessageBatch,
+        payload_merkle_root: [u8; 32],
+        verification_session_pda: Pubkey,
+    ) -> InstructionResult {
+        self.approve_messages_with_checks(
+            merklized_batch,
+            payload_merkle_root,
+            verification_session_pda,
+            &[Check::success()],
+        )
+    }
 
+    /// Like `approve_messages` but with custom checks.
+    pub fn approve_messages_with_checks(
+        &self,
+        merklized_batch: &solana_axelar_std::MerklizedMessageBatch,
+        payload_merkle_root: [u8; 32],
+        verification_session_pda: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
         let (event_authority, _, _) =
             get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
 
-        let ix = Instruction {
-            program_id: solana_axelar_gateway::ID,
-            accounts: solana_axelar_gateway::accounts::TransferOperatorship {
-                gateway_root_pda: self.gateway.root,
-                operator_or_upgrade_authority: self.operator,
+        let mut accounts = solana_axelar_gateway::accounts::ApproveMessages {
+            gateway_root_pda: self.gateway.root,
+            funder: self.payer,
+            verification_session_account: verification_session_pda,
+            system_program: solana_sdk_ids::system_program::ID,
+            event_authority,
+            program: solana_axelar_gateway::ID,
+        }
+        .to_account_metas(None);
+        accounts.extend(merklized_batch.leaves.iter().map(|leaf| {
+            let command_id = leaf.message.command_id();
+            solana_sdk::instruction::AccountMeta::new(
+                solana_axelar_gateway::IncomingMessage::find_pda(&command_id).0,
+                false,
+            )
+        }));
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts,
+            data: solana_axelar_gateway::instruction::ApproveMessages {
+                merklized_batch: merklized_batch.clone(),
+                payload_merkle_root,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// The gateway operator currently stored in the config.
+    pub fn gateway_operator(&self) -> Pubkey {
+        self.get_account_as::<GatewayConfig>(&self.gateway.root)
+            .map_or(self.operator, |config| config.operator)
+    }
+
+    /// Transfers gateway operatorship to a new operator in two steps: the
+    /// upgrade authority proposes it and the new operator accepts it.
+    pub fn transfer_gateway_operatorship(&self, new_operator: Pubkey) -> InstructionResult {
+        let current_operator = self.gateway_operator();
+
+        // The harness operator is also the upgrade authority
+        self.propose_gateway_operatorship_with_checks(
+            self.operator,
+            new_operator,
+            &[Check::success()],
+        );
+        self.accept_gateway_operatorship_with_checks(
+            current_operator,
+            new_operator,
+            &[Check::success()],
+        )
+    }
+
+    /// Proposes a new gateway operator, signed by `operator_or_upgrade_authority`.
+    pub fn propose_gateway_operatorship_with_checks(
+        &self,
+        operator_or_upgrade_authority: Pubkey,
+        proposed_operator: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::ProposeOperatorship {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority,
+                program_data,
+                proposed_operator,
+                operatorship_proposal: OperatorshipProposal::find_pda(
+                    &self.gateway_operator(),
+                    &proposed_operator,
+                )
+                .0,
+                system_program: solana_sdk_ids::system_program::ID,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::ProposeOperatorship {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Accepts the gateway operatorship proposal made while `current_operator`
+    /// was the operator, signed by `proposed_operator`.
+    pub fn accept_gateway_operatorship_with_checks(
+        &self,
+        current_operator: Pubkey,
+        proposed_operator: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let operatorship_proposal =
+            OperatorshipProposal::find_pda(&current_operator, &proposed_operator).0;
+        let payer = self
+            .get_account_as::<OperatorshipProposal>(&operatorship_proposal)
+            .map_or(self.operator, |proposal| proposal.payer);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::AcceptOperatorship {
+                gateway_root_pda: self.gateway.root,
+                proposed_operator,
+                operatorship_proposal,
+                payer,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::AcceptOperatorship {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Cancels the gateway operatorship proposal made while `current_operator`
+    /// was the operator, signed by `operator_or_upgrade_authority`.
+    pub fn cancel_gateway_operatorship_proposal_with_checks(
+        &self,
+        operator_or_upgrade_authority: Pubkey,
+        current_operator: Pubkey,
+        proposed_operator: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let operatorship_proposal =
+            OperatorshipProposal::find_pda(&current_operator, &proposed_operator).0;
+        let payer = self
+            .get_account_as::<OperatorshipProposal>(&operatorship_proposal)
+            .map_or(self.operator, |proposal| proposal.payer);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::CancelOperatorshipProposal {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority,
+                program_data,
+                operatorship_proposal,
+                payer,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::CancelOperatorshipProposal {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Sets the gateway pause status, signed by the operator.
+    pub fn set_gateway_pause_status(&self, paused: bool) -> InstructionResult {
+        self.set_gateway_pause_status_with_checks(self.operator, paused, &[Check::success()])
+    }
+
+    /// Like `set_gateway_pause_status` but accepts a custom signer and checks.
+    pub fn set_gateway_pause_status_with_checks(
+        &self,
+        operator_or_upgrade_authority: Pubkey,
+        paused: bool,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::SetPauseStatus {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority,
+                program_data,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::SetPauseStatus { paused }.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Revokes the approval of a message, signed by the operator.
+    pub fn revoke_message(&self, command_id: [u8; 32]) -> InstructionResult {
+        self.revoke_message_with_checks(self.operator, command_id, &[Check::success()])
+    }
+
+    /// Like `revoke_message` but accepts a custom signer and checks.
+    pub fn revoke_message_with_checks(
+        &self,
+        operator_or_upgrade_authority: Pubkey,
+        command_id: [u8; 32],
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::RevokeMessage {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority,
+                program_data,
+                incoming_message_pda: solana_axelar_gateway::IncomingMessage::find_pda(&command_id)
+                    .0,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::RevokeMessage { command_id }.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Sets the message approval TTL, signed by the operator.
+    pub fn set_message_approval_ttl(&self, message_approval_ttl: u32) -> InstructionResult {
+        self.set_message_approval_ttl_with_checks(message_approval_ttl, &[Check::success()])
+    }
+
+    /// Sets the message approval TTL, signed by the operator.
+    pub fn set_message_approval_ttl_with_checks(
+        &self,
+        message_approval_ttl: u32,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::SetMessageApprovalTtl {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority: self.operator,
+                program_data,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::SetMessageApprovalTtl {
+                message_approval_ttl,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Updates the gateway config, signed by `upgrade_authority`.
+    pub fn update_config_with_checks(
+        &self,
+        upgrade_authority: Pubkey,
+        params: UpdateConfigParams,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::UpdateConfig {
+                gateway_root_pda: self.gateway.root,
+                upgrade_authority,
+                program_data,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::UpdateConfig { params }.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Migrates the gateway config to the current layout, signed and paid for
+    /// by `upgrade_authority`.
+    pub fn migrate_config_with_checks(
+        &self,
+        upgrade_authority: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::MigrateConfig {
+                gateway_root_pda: self.gateway.root,
+                upgrade_authority,
+                program_data,
+                system_program: solana_sdk_ids::system_program::ID,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::MigrateConfig {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Records the epoch of the verifier set tracked by `verifier_set_tracker_pda`.
+    pub fn record_verifier_set_epoch_with_checks(
+        &self,
+        verifier_set_tracker_pda: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let epoch = self
+            .get_account_as::<VerifierSetTracker>(&verifier_set_tracker_pda)
+            .map_or(U256::ZERO, |tracker| tracker.epoch);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::RecordVerifierSetEpoch {
+                payer: self.payer,
+                verifier_set_tracker_pda,
+                verifier_set_epoch_pda: VerifierSetEpoch::find_pda(epoch).0,
+                system_program: solana_sdk_ids::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::RecordVerifierSetEpoch {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Closes the expired verifier set tracker `verifier_set_tracker_pda` and
+    /// its epoch record, refunding their rent to `rent_recipient`.
+    pub fn close_expired_verifier_set_with_checks(
+        &self,
+        verifier_set_tracker_pda: Pubkey,
+        rent_recipient: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let epoch = self
+            .get_account_as::<VerifierSetTracker>(&verifier_set_tracker_pda)
+            .map_or(U256::ZERO, |tracker| tracker.epoch);
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::CloseExpiredVerifierSet {
+                gateway_root_pda: self.gateway.root,
+                verifier_set_tracker_pda,
+                rent_recipient,
+                verifier_set_epoch_pda: VerifierSetEpoch::find_pda(epoch).0,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::CloseExpiredVerifierSet {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Initializes the destination chain registry, signed by the operator.
+    pub fn init_destination_chain_registry(&self) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::InitializeDestinationChainRegistry {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority: self.operator,
+                program_data,
+                destination_chain_registry: DestinationChainRegistry::find_pda().0,
+                system_program: solana_sdk_ids::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::InitializeDestinationChainRegistry {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])])
+    }
+
+    /// Adds a chain to the destination chain registry, signed by the operator.
+    pub fn add_destination_chain(&self, chain_name: &str) -> InstructionResult {
+        self.add_destination_chain_with_checks(self.operator, chain_name, &[Check::success()])
+    }
+
+    /// Like `add_destination_chain` but accepts a custom signer and checks.
+    pub fn add_destination_chain_with_checks(
+        &self,
+        operator_or_upgrade_authority: Pubkey,
+        chain_name: &str,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::AddDestinationChain {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority,
                 program_data,
-                new_operator,
+                destination_chain_registry: DestinationChainRegistry::find_pda().0,
+                system_program: solana_sdk_ids::system_program::ID,
                 event_authority,
                 program: solana_axelar_gateway::ID,
             }
             .to_account_metas(None),
-            data: solana_axelar_gateway::instruction::TransferOperatorship {}.data(),
+            data: solana_axelar_gateway::instruction::AddDestinationChain {
+                chain_name: chain_name.to_owned(),
+            }
+            .data(),
         };
 
         self.ctx
-            .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])])
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Removes a chain from the destination chain registry, signed by the
+    /// operator.
+    pub fn remove_destination_chain_with_checks(
+        &self,
+        chain_name: &str,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+            &solana_axelar_gateway::ID,
+        );
+
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::RemoveDestinationChain {
+                gateway_root_pda: self.gateway.root,
+                operator_or_upgrade_authority: self.operator,
+                program_data,
+                destination_chain_registry: DestinationChainRegistry::find_pda().0,
+                system_program: solana_sdk_ids::system_program::ID,
+                event_authority,
+                program: solana_axelar_gateway::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::RemoveDestinationChain {
+                chain_name: chain_name.to_owned(),
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
     }
 
     /// Calls the gateway's `call_contract` instruction.
@@ -839,6 +1806,24 @@ impl GatewayTestHarness {
         destination_chain: String,
         destination_address: String,
         payload: Vec<u8>,
+    ) -> InstructionResult {
+        self.call_contract_with_checks(
+            caller,
+            destination_chain,
+            destination_address,
+            payload,
+            &[Check::success()],
+        )
+    }
+
+    /// Like `call_contract` but accepts custom checks.
+    pub fn call_contract_with_checks(
+        &self,
+        caller: Pubkey,
+        destination_chain: String,
+        destination_address: String,
+        payload: Vec<u8>,
+        checks: &[Check],
     ) -> InstructionResult {
         let (event_authority, _, _) =
             get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
@@ -854,12 +1839,21 @@ impl GatewayTestHarness {
             (None, 0)
         };
 
+        // Use the destination chain registry once it has been initialized
+        let destination_chain_registry = Some(DestinationChainRegistry::find_pda().0)
+            .filter(|registry| self.account_exists(registry));
+        // Count the caller's messages once its nonce has been initialized
+        let call_contract_nonce =
+            Some(CallContractNonce::find_pda(&caller).0).filter(|nonce| self.account_exists(nonce));
+
         let mut accounts = solana_axelar_gateway::accounts::CallContract {
             caller,
             signing_pda: signing_pda_option,
             gateway_root_pda: self.gateway.root,
+            destination_chain_registry,
             event_authority,
             program: solana_axelar_gateway::ID,
+            call_contract_nonce,
         }
         .to_account_metas(None);
 
@@ -883,6 +1877,363 @@ impl GatewayTestHarness {
         };
 
         self.ctx
-            .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])])
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Calls the gateway's `call_contract_with_hash` instruction for a direct
+    /// signer.
+    pub fn call_contract_with_hash_with_checks(
+        &self,
+        sender: Pubkey,
+        destination_chain: String,
+        destination_address: String,
+        payload_hash: [u8; 32],
+        payload_location: String,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        // Use the destination chain registry once it has been initialized
+        let destination_chain_registry = Some(DestinationChainRegistry::find_pda().0)
+            .filter(|registry| self.account_exists(registry));
+        // Count the sender's messages once its nonce has been initialized
+        let call_contract_nonce =
+            Some(CallContractNonce::find_pda(&sender).0).filter(|nonce| self.account_exists(nonce));
+
+        let mut accounts = solana_axelar_gateway::accounts::CallContract {
+            caller: sender,
+            signing_pda: None,
+            gateway_root_pda: self.gateway.root,
+            destination_chain_registry,
+            event_authority,
+            program: solana_axelar_gateway::ID,
+            call_contract_nonce,
+        }
+        .to_account_metas(None);
+
+        if let Some(caller) = accounts.first_mut() {
+            caller.is_signer = true;
+        }
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts,
+            data: solana_axelar_gateway::instruction::CallContractWithHash {
+                destination_chain,
+                destination_contract_address: destination_address,
+                payload_hash,
+                payload_location,
+                signing_pda_bump: 0,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Creates the outbound nonce of `caller`, paid for by the harness payer.
+    pub fn init_call_contract_nonce(&self, caller: Pubkey) -> InstructionResult {
+        self.init_call_contract_nonce_with_checks(caller, &[Check::success()])
+    }
+
+    /// Like `init_call_contract_nonce` but accepts custom checks.
+    pub fn init_call_contract_nonce_with_checks(
+        &self,
+        caller: Pubkey,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::InitializeCallContractNonce {
+                payer: self.payer,
+                caller,
+                call_contract_nonce: CallContractNonce::find_pda(&caller).0,
+                system_program: solana_sdk_ids::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::InitializeCallContractNonce {}.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Creates a payload buffer for a direct signer, which also pays for it.
+    pub fn init_call_contract_buffer_with_checks(
+        &self,
+        sender: Pubkey,
+        buffer_id: u64,
+        payload_size: u32,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::InitializeCallContractBuffer {
+                payer: sender,
+                caller: sender,
+                signing_pda: None,
+                payload_buffer: CallContractPayloadBuffer::find_pda(&sender, buffer_id).0,
+                system_program: solana_sdk_ids::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::InitializeCallContractBuffer {
+                buffer_id,
+                payload_size,
+                signing_pda_bump: 0,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Writes a chunk into the payload buffer of a direct signer.
+    pub fn write_call_contract_buffer_with_checks(
+        &self,
+        sender: Pubkey,
+        buffer_id: u64,
+        offset: u32,
+        bytes: Vec<u8>,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let mut accounts = solana_axelar_gateway::accounts::WriteCallContractBuffer {
+            caller: sender,
+            signing_pda: None,
+            payload_buffer: CallContractPayloadBuffer::find_pda(&sender, buffer_id).0,
+        }
+        .to_account_metas(None);
+
+        if let Some(caller) = accounts.first_mut() {
+            caller.is_signer = true;
+        }
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts,
+            data: solana_axelar_gateway::instruction::WriteCallContractBuffer {
+                buffer_id,
+                offset,
+                bytes,
+                signing_pda_bump: 0,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Uploads `payload` into a new buffer of a direct signer, in chunks of
+    /// `chunk_size` bytes.
+    pub fn upload_call_contract_buffer(
+        &self,
+        sender: Pubkey,
+        buffer_id: u64,
+        payload: &[u8],
+        chunk_size: usize,
+    ) {
+        let payload_size = u32::try_from(payload.len()).expect("payload too large");
+        self.init_call_contract_buffer_with_checks(
+            sender,
+            buffer_id,
+            payload_size,
+            &[Check::success()],
+        );
+
+        for (index, chunk) in payload.chunks(chunk_size).enumerate() {
+            let offset = u32::try_from(index * chunk_size).expect("offset too large");
+            self.write_call_contract_buffer_with_checks(
+                sender,
+                buffer_id,
+                offset,
+                chunk.to_vec(),
+                &[Check::success()],
+            );
+        }
+    }
+
+    /// Emits the payload of a direct signer's buffer, closing the buffer.
+    pub fn call_contract_with_buffer_with_checks(
+        &self,
+        sender: Pubkey,
+        buffer_id: u64,
+        destination_chain: String,
+        destination_address: String,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let (event_authority, _, _) =
+            get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+        let payload_buffer = CallContractPayloadBuffer::find_pda(&sender, buffer_id).0;
+        let payer = self
+            .get_account_as::<CallContractPayloadBuffer>(&payload_buffer)
+            .map_or(sender, |buffer| buffer.payer);
+
+        // Use the destination chain registry once it has been initialized
+        let destination_chain_registry = Some(DestinationChainRegistry::find_pda().0)
+            .filter(|registry| self.account_exists(registry));
+        // Count the sender's messages once its nonce has been initialized
+        let call_contract_nonce =
+            Some(CallContractNonce::find_pda(&sender).0).filter(|nonce| self.account_exists(nonce));
+
+        let mut accounts = solana_axelar_gateway::accounts::CallContractWithBuffer {
+            caller: sender,
+            signing_pda: None,
+            gateway_root_pda: self.gateway.root,
+            destination_chain_registry,
+            payload_buffer,
+            payer,
+            event_authority,
+            program: solana_axelar_gateway::ID,
+            call_contract_nonce,
+        }
+        .to_account_metas(None);
+
+        if let Some(caller) = accounts.first_mut() {
+            caller.is_signer = true;
+        }
+
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts,
+            data: solana_axelar_gateway::instruction::CallContractWithBuffer {
+                destination_chain,
+                destination_contract_address: destination_address,
+                buffer_id,
+                signing_pda_bump: 0,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Creates the payload buffer of an approved message, paid for by `payer`.
+    pub fn init_message_payload_with_checks(
+        &self,
+        payer: Pubkey,
+        command_id: [u8; 32],
+        payload_size: u32,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::InitializeMessagePayload {
+                payer,
+                incoming_message_pda: solana_axelar_gateway::IncomingMessage::find_pda(&command_id)
+                    .0,
+                message_payload: MessagePayload::find_pda(&command_id, &payer).0,
+                system_program: solana_sdk_ids::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::InitializeMessagePayload {
+                command_id,
+                payload_size,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Writes a chunk into the payload buffer of a message.
+    pub fn write_message_payload_with_checks(
+        &self,
+        payer: Pubkey,
+        command_id: [u8; 32],
+        offset: u32,
+        bytes: Vec<u8>,
+        checks: &[Check],
+    ) -> InstructionResult {
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::WriteMessagePayload {
+                payer,
+                message_payload: MessagePayload::find_pda(&command_id, &payer).0,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::WriteMessagePayload {
+                command_id,
+                offset,
+                bytes,
+            }
+            .data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Checks the payload buffer of a message against its payload hash.
+    pub fn commit_message_payload_with_checks(
+        &self,
+        payer: Pubkey,
+        command_id: [u8; 32],
+        checks: &[Check],
+    ) -> InstructionResult {
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::CommitMessagePayload {
+                payer,
+                incoming_message_pda: solana_axelar_gateway::IncomingMessage::find_pda(&command_id)
+                    .0,
+                message_payload: MessagePayload::find_pda(&command_id, &payer).0,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::CommitMessagePayload { command_id }.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Closes the payload buffer of a message, refunding its rent to `payer`.
+    pub fn close_message_payload_with_checks(
+        &self,
+        payer: Pubkey,
+        command_id: [u8; 32],
+        checks: &[Check],
+    ) -> InstructionResult {
+        let ix = Instruction {
+            program_id: solana_axelar_gateway::ID,
+            accounts: solana_axelar_gateway::accounts::CloseMessagePayload {
+                payer,
+                message_payload: MessagePayload::find_pda(&command_id, &payer).0,
+            }
+            .to_account_metas(None),
+            data: solana_axelar_gateway::instruction::CloseMessagePayload { command_id }.data(),
+        };
+
+        self.ctx
+            .process_and_validate_instruction_chain(&[(&ix, checks)])
+    }
+
+    /// Uploads `payload` into a new payload buffer of a message, in chunks of
+    /// `chunk_size` bytes, without committing it.
+    pub fn upload_message_payload(
+        &self,
+        payer: Pubkey,
+        command_id: [u8; 32],
+        payload: &[u8],
+        chunk_size: usize,
+    ) {
+        let payload_size = u32::try_from(payload.len()).expect("payload too large");
+        self.init_message_payload_with_checks(payer, command_id, payload_size, &[Check::success()]);
+
+        for (index, chunk) in payload.chunks(chunk_size).enumerate() {
+            let offset = u32::try_from(index * chunk_size).expect("offset too large");
+            self.write_message_payload_with_checks(
+                payer,
+                command_id,
+                offset,
+                chunk.to_vec(),
+                &[Check::success()],
+            );
+        }
     }
 }
@@ -304,6 +304,70 @@ impl ItsTestHarness {
         self.operator = new_operator;
     }
 
+    pub fn ensure_propose_operatorship(&self, new_operator: Pubkey) {
+        let ix = solana_axelar_its::instructions::make_propose_operatorship_instruction(
+            self.payer,
+            self.operator,
+            new_operator,
+        )
+        .0;
+
+        self.ctx
+            .process_and_validate_instruction(&ix, &[Check::success()]);
+
+        let proposal_account = solana_axelar_its::RoleProposal::find_pda(
+            &self.its_root,
+            &self.operator,
+            &new_operator,
+        )
+        .0;
+        let proposal: solana_axelar_its::RoleProposal = self
+            .get_account_as(&proposal_account)
+            .expect("proposal account should exist");
+
+        assert_eq!(
+            proposal.roles,
+            solana_axelar_its::roles::OPERATOR,
+            "must propose the operator role"
+        );
+    }
+
+    pub fn ensure_accept_operatorship(&mut self, new_operator: Pubkey) {
+        let ix = solana_axelar_its::instructions::make_accept_operatorship_instruction(
+            self.payer,
+            new_operator,
+            self.operator,
+        )
+        .0;
+
+        self.ctx
+            .process_and_validate_instruction(&ix, &[Check::success()]);
+
+        let proposal_account = solana_axelar_its::RoleProposal::find_pda(
+            &self.its_root,
+            &self.operator,
+            &new_operator,
+        )
+        .0;
+        assert!(
+            !self.account_exists(&proposal_account),
+            "proposal account should be closed"
+        );
+
+        let new_operator_account =
+            solana_axelar_its::UserRoles::find_pda(&self.its_root, &new_operator).0;
+        let its_roles: solana_axelar_its::UserRoles = self
+            .get_account_as(&new_operator_account)
+            .expect("new operator roles account should exist");
+        assert!(
+            its_roles.has_operator_role(),
+            "new operator must have operator role"
+        );
+
+        // Update operator in harness
+        self.operator = new_operator;
+    }
+
     pub fn ensure_deploy_local_interchain_token(
         &self,
         deployer: Pubkey,
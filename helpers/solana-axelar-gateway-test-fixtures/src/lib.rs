@@ -12,7 +12,8 @@ use solana_axelar_gateway::{
     CallContractSigner, ID as GATEWAY_PROGRAM_ID,
 };
 use solana_axelar_gateway::{
-    GatewayConfig, IncomingMessage, SignatureVerificationSessionData, VerifierSetTracker,
+    GatewayConfig, IncomingMessage, SignatureVerificationSessionData, VerifierSetEpoch,
+    VerifierSetTracker,
 };
 use solana_axelar_std::execute_data::{
     encode, hash_payload, prefixed_message_hash_payload_type, ExecuteData,
@@ -218,6 +219,8 @@ pub fn initialize_gateway(setup: &TestSetup) -> InstructionResult {
 
     let instruction_data = solana_axelar_gateway::instruction::InitializeConfig { params }.data();
 
+    let (verifier_set_epoch_pda, _) = VerifierSetEpoch::find_pda(U256::ONE);
+
     let program_data_state = UpgradeableLoaderState::ProgramData {
         slot: 0,
         upgrade_authority_address: Some(setup.upgrade_authority),
@@ -286,6 +289,16 @@ pub fn initialize_gateway(setup: &TestSetup) -> InstructionResult {
                 rent_epoch: 0,
             },
         ),
+        (
+            verifier_set_epoch_pda,
+            Account {
+                lamports: 0,
+                data: vec![],
+                owner: SYSTEM_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        ),
     ];
 
     let instruction = Instruction {
@@ -297,6 +310,7 @@ pub fn initialize_gateway(setup: &TestSetup) -> InstructionResult {
             AccountMeta::new(setup.gateway_root_pda, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
             AccountMeta::new(setup.verifier_set_tracker_pda, false),
+            AccountMeta::new(verifier_set_epoch_pda, false),
         ],
         data: instruction_data,
     };
@@ -507,6 +521,8 @@ pub fn call_contract_helper(
             caller: memo_program_id,
             signing_pda: Some(signing_pda),
             gateway_root_pda: setup.gateway_root_pda,
+            destination_chain_registry: None,
+            call_contract_nonce: None,
             event_authority: event_authority_pda,
             program: GATEWAY_PROGRAM_ID,
         }
@@ -564,6 +580,9 @@ pub fn rotate_signers_helper(
     .data();
 
     let (new_verifier_set_tracker_pda, _) = VerifierSetTracker::find_pda(&new_verifier_set_hash);
+    let config = GatewayConfig::try_deserialize(&mut gateway_account.data.as_slice()).unwrap();
+    let (new_verifier_set_epoch_pda, _) =
+        VerifierSetEpoch::find_pda(config.current_epoch.checked_add(U256::ONE).unwrap());
 
     let (event_authority_pda, _) =
         Pubkey::find_program_address(&[b"__event_authority"], &GATEWAY_PROGRAM_ID);
@@ -582,6 +601,16 @@ pub fn rotate_signers_helper(
                 rent_epoch: 0,
             },
         ),
+        (
+            new_verifier_set_epoch_pda,
+            Account {
+                lamports: 0,
+                data: vec![],
+                owner: SYSTEM_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        ),
         (
             setup.payer,
             Account {
@@ -649,6 +678,8 @@ pub fn rotate_signers_helper(
             // for event cpi
             AccountMeta::new_readonly(event_authority_pda, false),
             AccountMeta::new_readonly(GATEWAY_PROGRAM_ID, false),
+            // optional epoch record
+            AccountMeta::new(new_verifier_set_epoch_pda, false),
         ],
         data: instruction_data,
     };
@@ -671,7 +702,7 @@ pub fn transfer_operatorship_helper(
         (setup.gateway_root_pda, gateway_account),
         (setup.program_data_pda, program_data_account),
         (
-            setup.operator,
+            setup.upgrade_authority,
             Account {
                 lamports: LAMPORTS_PER_SOL,
                 data: vec![],
@@ -717,7 +748,7 @@ pub fn transfer_operatorship_helper(
         program_id: GATEWAY_PROGRAM_ID,
         accounts: vec![
             AccountMeta::new(setup.gateway_root_pda, false),
-            AccountMeta::new_readonly(setup.operator, true),
+            AccountMeta::new_readonly(setup.upgrade_authority, true),
             AccountMeta::new_readonly(setup.program_data_pda, false),
             AccountMeta::new_readonly(new_operator, false),
             // for CPI events
@@ -811,7 +842,7 @@ pub fn approve_message_helper_from_merklized(
         accounts: vec![
             AccountMeta::new_readonly(setup.gateway_root_pda, false),
             AccountMeta::new(setup.payer, true),
-            AccountMeta::new_readonly(verification_session.0, false),
+            AccountMeta::new(verification_session.0, false),
             AccountMeta::new(incoming_message_pda, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
             AccountMeta::new_readonly(event_authority_pda, false),
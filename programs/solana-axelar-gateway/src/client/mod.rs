@@ -0,0 +1,400 @@
+//! Off-chain helpers for relayers and applications built on the gateway.
+//!
+//! Derives the `IncomingMessage` PDA of a [`Message`], decodes the account
+//! found there and tells whether the message is waiting for approval, approved,
+//! expired, executed, revoked, or was approved with a different content.
+//!
+//! The [`planner`] turns `ExecuteData` into the gateway transactions processing
+//! it.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use solana_axelar_std::hasher::LeafHash;
+
+use crate::{IncomingMessage, IncomingMessageTombstone, Message};
+
+pub mod planner;
+pub use planner::*;
+
+/// Source of account data, such as an RPC client or an in-memory account
+/// store.
+pub trait AccountFetcher {
+    type Error;
+
+    /// Fetches the data of the accounts at `addresses`, in the same order.
+    /// Accounts that don't exist are returned as `None`.
+    fn fetch_accounts(&self, addresses: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>, Self::Error>;
+}
+
+/// Status of a message on the gateway.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncomingMessageStatus {
+    /// The message hasn't been approved yet.
+    Missing,
+    /// The message is approved and waiting to be executed.
+    Approved {
+        /// Timestamp (seconds) of when the message was approved
+        approved_at: i64,
+    },
+    /// The approval outlived its approval TTL before the message got executed.
+    /// The message can't be executed anymore.
+    Expired {
+        /// Timestamp (seconds) of when the message was approved
+        approved_at: i64,
+    },
+    /// The message was executed.
+    Executed,
+    /// The approval was revoked before the message got executed.
+    Revoked,
+    /// The message was executed or revoked, and its PDA was closed into a
+    /// tombstone. The message hash can't be checked anymore.
+    Closed,
+    /// A message with the same command id, but a different content, was
+    /// approved.
+    HashMismatch {
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+}
+
+/// Errors returned when querying message statuses.
+#[derive(Debug, thiserror::Error)]
+pub enum QueryError<E> {
+    /// The account fetcher failed.
+    #[error("Failed to fetch accounts: {0}")]
+    Fetch(E),
+
+    /// The account fetcher returned a different number of accounts than
+    /// requested.
+    #[error("Expected {expected} accounts, got {actual}")]
+    AccountCountMismatch { expected: usize, actual: usize },
+
+    /// The account at the PDA of a message isn't an `IncomingMessage`.
+    #[error("Invalid incoming message account {0}")]
+    InvalidAccountData(Pubkey),
+}
+
+/// Derives the `IncomingMessage` PDA of `message`.
+pub fn incoming_message_pda(message: &Message) -> Pubkey {
+    IncomingMessage::find_pda(&message.command_id()).0
+}
+
+/// Decodes the data of the account at the `IncomingMessage` PDA of `message`,
+/// `None` if there is no account.
+///
+/// Approvals are checked against their approval TTL at `now`, a timestamp in
+/// seconds such as the cluster's clock.
+///
+/// Returns `None` if the data is neither an `IncomingMessage` nor its
+/// tombstone.
+pub fn decode_status(
+    message: &Message,
+    data: Option<&[u8]>,
+    now: i64,
+) -> Option<IncomingMessageStatus> {
+    let Some(mut data) = data else {
+        return Some(IncomingMessageStatus::Missing);
+    };
+
+    if data.starts_with(IncomingMessageTombstone::DISCRIMINATOR) {
+        return Some(IncomingMessageStatus::Closed);
+    }
+
+    let incoming_message = IncomingMessage::try_deserialize(&mut data).ok()?;
+
+    let expected = message.hash();
+    if incoming_message.message_hash != expected {
+        return Some(IncomingMessageStatus::HashMismatch {
+            expected,
+            actual: incoming_message.message_hash,
+        });
+    }
+
+    let status = incoming_message.status;
+    let approved_at = incoming_message.approved_at;
+    if status.is_approved() {
+        if incoming_message.is_approval_expired(now) {
+            Some(IncomingMessageStatus::Expired { approved_at })
+        } else {
+            Some(IncomingMessageStatus::Approved { approved_at })
+        }
+    } else if status.is_executed() {
+        Some(IncomingMessageStatus::Executed)
+    } else if status.is_revoked() {
+        Some(IncomingMessageStatus::Revoked)
+    } else {
+        None
+    }
+}
+
+/// Fetches the statuses of `messages` at `now` with a single call to `fetcher`.
+pub fn fetch_statuses<F: AccountFetcher>(
+    fetcher: &F,
+    messages: &[Message],
+    now: i64,
+) -> Result<Vec<IncomingMessageStatus>, QueryError<F::Error>> {
+    let addresses = messages
+        .iter()
+        .map(incoming_message_pda)
+        .collect::<Vec<_>>();
+    let accounts = fetcher
+        .fetch_accounts(&addresses)
+        .map_err(QueryError::Fetch)?;
+
+    if accounts.len() != addresses.len() {
+        return Err(QueryError::AccountCountMismatch {
+            expected: addresses.len(),
+            actual: accounts.len(),
+        });
+    }
+
+    messages
+        .iter()
+        .zip(addresses)
+        .zip(accounts)
+        .map(|((message, address), data)| {
+            decode_status(message, data.as_deref(), now)
+                .ok_or(QueryError::InvalidAccountData(address))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bytemuck::Zeroable;
+    use solana_axelar_std::CrossChainId;
+
+    use super::*;
+    use crate::MessageStatus;
+
+    const APPROVED_AT: i64 = 1_700_000_000;
+    const NOW: i64 = APPROVED_AT + 100;
+
+    #[derive(Default)]
+    struct InMemoryFetcher(HashMap<Pubkey, Vec<u8>>);
+
+    impl AccountFetcher for InMemoryFetcher {
+        type Error = core::convert::Infallible;
+
+        fn fetch_accounts(
+            &self,
+            addresses: &[Pubkey],
+        ) -> Result<Vec<Option<Vec<u8>>>, Self::Error> {
+            Ok(addresses
+                .iter()
+                .map(|address| self.0.get(address).cloned())
+                .collect())
+        }
+    }
+
+    fn message(id: &str) -> Message {
+        Message {
+            cc_id: CrossChainId {
+                chain: "ethereum".to_owned(),
+                id: id.to_owned(),
+            },
+            source_address: "0xSourceContract".to_owned(),
+            destination_chain: "solana".to_owned(),
+            destination_address: Pubkey::new_unique().to_string(),
+            payload_hash: [1; 32],
+        }
+    }
+
+    fn incoming_message_data(message_hash: [u8; 32], status: MessageStatus) -> Vec<u8> {
+        incoming_message_data_with_ttl(message_hash, status, 0)
+    }
+
+    fn incoming_message_data_with_ttl(
+        message_hash: [u8; 32],
+        status: MessageStatus,
+        approval_ttl: u32,
+    ) -> Vec<u8> {
+        let mut incoming_message = IncomingMessage::zeroed();
+        incoming_message.status = status;
+        incoming_message.message_hash = message_hash;
+        incoming_message.approved_at = APPROVED_AT;
+        incoming_message.approval_ttl = approval_ttl;
+
+        let mut data = IncomingMessage::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&incoming_message));
+        data
+    }
+
+    fn tombstone_data() -> Vec<u8> {
+        let mut data = IncomingMessageTombstone::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&IncomingMessageTombstone { bump: 255 }));
+        data
+    }
+
+    #[test]
+    fn incoming_message_pda_is_derived_from_command_id() {
+        let message = message("1");
+
+        assert_eq!(
+            incoming_message_pda(&message),
+            IncomingMessage::find_pda(&message.command_id()).0
+        );
+    }
+
+    #[test]
+    fn decode_status_classifies_accounts() {
+        let message = message("1");
+        let hash = message.hash();
+
+        assert_eq!(
+            decode_status(&message, None, NOW),
+            Some(IncomingMessageStatus::Missing)
+        );
+        assert_eq!(
+            decode_status(
+                &message,
+                Some(&incoming_message_data(hash, MessageStatus::approved())),
+                NOW,
+            ),
+            Some(IncomingMessageStatus::Approved {
+                approved_at: APPROVED_AT
+            })
+        );
+        assert_eq!(
+            decode_status(
+                &message,
+                Some(&incoming_message_data(hash, MessageStatus::executed())),
+                NOW,
+            ),
+            Some(IncomingMessageStatus::Executed)
+        );
+        assert_eq!(
+            decode_status(
+                &message,
+                Some(&incoming_message_data(hash, MessageStatus::revoked())),
+                NOW,
+            ),
+            Some(IncomingMessageStatus::Revoked)
+        );
+        assert_eq!(
+            decode_status(&message, Some(&tombstone_data()), NOW),
+            Some(IncomingMessageStatus::Closed)
+        );
+    }
+
+    #[test]
+    fn decode_status_detects_expired_approvals() {
+        let message = message("1");
+        let approved = |approval_ttl| {
+            incoming_message_data_with_ttl(message.hash(), MessageStatus::approved(), approval_ttl)
+        };
+
+        assert_eq!(
+            decode_status(&message, Some(&approved(100)), NOW),
+            Some(IncomingMessageStatus::Approved {
+                approved_at: APPROVED_AT
+            })
+        );
+        assert_eq!(
+            decode_status(&message, Some(&approved(99)), NOW),
+            Some(IncomingMessageStatus::Expired {
+                approved_at: APPROVED_AT
+            })
+        );
+        // Executed messages don't expire
+        assert_eq!(
+            decode_status(
+                &message,
+                Some(&incoming_message_data_with_ttl(
+                    message.hash(),
+                    MessageStatus::executed(),
+                    99
+                )),
+                NOW
+            ),
+            Some(IncomingMessageStatus::Executed)
+        );
+    }
+
+    #[test]
+    fn decode_status_detects_hash_mismatch() {
+        let message = message("1");
+
+        assert_eq!(
+            decode_status(
+                &message,
+                Some(&incoming_message_data([7; 32], MessageStatus::approved())),
+                NOW,
+            ),
+            Some(IncomingMessageStatus::HashMismatch {
+                expected: message.hash(),
+                actual: [7; 32],
+            })
+        );
+    }
+
+    #[test]
+    fn decode_status_rejects_unknown_data() {
+        let message = message("1");
+
+        assert_eq!(decode_status(&message, Some(&[0; 16]), NOW), None);
+    }
+
+    #[test]
+    fn fetch_statuses_keeps_message_order() {
+        let approved = message("1");
+        let executed = message("2");
+        let missing = message("3");
+
+        let mut fetcher = InMemoryFetcher::default();
+        fetcher.0.insert(
+            incoming_message_pda(&approved),
+            incoming_message_data(approved.hash(), MessageStatus::approved()),
+        );
+        fetcher.0.insert(
+            incoming_message_pda(&executed),
+            incoming_message_data(executed.hash(), MessageStatus::executed()),
+        );
+
+        let statuses = fetch_statuses(&fetcher, &[missing, approved, executed], NOW).unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                IncomingMessageStatus::Missing,
+                IncomingMessageStatus::Approved {
+                    approved_at: APPROVED_AT
+                },
+                IncomingMessageStatus::Executed,
+            ]
+        );
+    }
+
+    #[test]
+    fn fetch_statuses_reports_invalid_accounts() {
+        let message = message("1");
+        let pda = incoming_message_pda(&message);
+
+        let mut fetcher = InMemoryFetcher::default();
+        fetcher.0.insert(pda, vec![0; 16]);
+
+        assert!(matches!(
+            fetch_statuses(&fetcher, &[message], NOW),
+            Err(QueryError::InvalidAccountData(address)) if address == pda
+        ));
+    }
+
+    #[test]
+    fn fetch_statuses_applies_approval_ttl() {
+        let message = message("1");
+
+        let mut fetcher = InMemoryFetcher::default();
+        fetcher.0.insert(
+            incoming_message_pda(&message),
+            incoming_message_data_with_ttl(message.hash(), MessageStatus::approved(), 99),
+        );
+
+        assert_eq!(
+            fetch_statuses(&fetcher, &[message], NOW).unwrap(),
+            vec![IncomingMessageStatus::Expired {
+                approved_at: APPROVED_AT
+            }]
+        );
+    }
+}
@@ -0,0 +1,960 @@
+//! Plans the gateway transactions needed to process [`ExecuteData`].
+//!
+//! A payload gets processed in stages: its verification session is initialized
+//! (and extended for large verifier sets), the signatures are verified until
+//! the quorum is reached, then the messages get approved or the signers
+//! rotated. The transactions of a stage can be sent in parallel, but only once
+//! every transaction of the previous stage landed.
+
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use bitvec::prelude::*;
+use solana_axelar_std::execute_data::{ExecuteData, MerklizedPayload};
+use solana_axelar_std::{MerklizedMessage, PayloadType, SigningVerifierSetInfo, U256};
+
+use super::{AccountFetcher, QueryError};
+use crate::{
+    GatewayConfig, IncomingMessage, SignatureVerificationSessionData, VerifierSetEpoch,
+    VerifierSetTracker,
+};
+
+/// Maximum size of a serialized transaction.
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Maximum compute units a transaction can request.
+pub const MAX_TRANSACTION_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Conservative compute unit estimates of the planned instructions.
+pub const INITIALIZE_SESSION_COMPUTE_UNITS: u32 = 20_000;
+pub const EXTEND_SESSION_COMPUTE_UNITS: u32 = 10_000;
+pub const VERIFY_SIGNATURE_COMPUTE_UNITS: u32 = 60_000;
+pub const APPROVE_MESSAGE_COMPUTE_UNITS: u32 = 60_000;
+pub const ROTATE_SIGNERS_COMPUTE_UNITS: u32 = 60_000;
+
+/// Errors returned when planning the transactions of [`ExecuteData`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum PlanError {
+    /// The signatures of the execute data don't reach the quorum.
+    #[error("Signed weight {signed_weight} doesn't reach the quorum {quorum}")]
+    InsufficientWeight { signed_weight: u128, quorum: u128 },
+
+    /// The payload items don't match the signed payload type.
+    #[error("Payload items don't match the signed payload type")]
+    PayloadTypeMismatch,
+
+    /// An instruction doesn't fit in a transaction by itself.
+    #[error("Instruction of {size} bytes doesn't fit in a transaction")]
+    InstructionTooLarge { size: usize },
+
+    /// The epoch of the new verifier set overflows.
+    #[error("Epoch overflow")]
+    EpochOverflow,
+}
+
+/// Progress of a verification session, as found on chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionState {
+    /// Account space of the session.
+    pub space: usize,
+
+    /// Signed weight accumulated so far, `None` once the quorum was reached.
+    pub accumulated_weight: Option<u128>,
+
+    /// Positions of the verifiers whose signature was already verified.
+    pub verified_positions: BTreeSet<u16>,
+}
+
+impl SessionState {
+    /// Decodes the data of a `SignatureVerificationSessionData` account.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let data = data.strip_prefix(SignatureVerificationSessionData::DISCRIMINATOR)?;
+        let (session, extended_slots) =
+            data.split_at_checked(core::mem::size_of::<SignatureVerificationSessionData>())?;
+        let session =
+            bytemuck::try_pod_read_unaligned::<SignatureVerificationSessionData>(session).ok()?;
+
+        let inline_positions = session
+            .signature_verification
+            .signature_slots
+            .view_bits::<Lsb0>()
+            .iter_ones();
+        let extended_positions = extended_slots
+            .view_bits::<Lsb0>()
+            .iter_ones()
+            .map(|position| position + SignatureVerificationSessionData::INLINE_SIGNATURE_SLOTS);
+        let verified_positions = inline_positions
+            .chain(extended_positions)
+            .filter_map(|position| u16::try_from(position).ok())
+            .collect();
+
+        let accumulated_weight = if session.is_valid() {
+            None
+        } else {
+            Some(session.signature_verification.accumulated_threshold.get())
+        };
+
+        Some(Self {
+            space: SignatureVerificationSessionData::DISCRIMINATOR.len() + data.len(),
+            accumulated_weight,
+            verified_positions,
+        })
+    }
+}
+
+/// Instructions to send in a single transaction.
+pub type TransactionInstructions = Vec<Instruction>;
+
+/// Transactions that can be sent in parallel.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stage {
+    pub transactions: Vec<TransactionInstructions>,
+}
+
+/// Plans the gateway transactions needed to process [`ExecuteData`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionPlanner {
+    /// Pays for the transactions and the rent of the created accounts.
+    pub payer: Pubkey,
+
+    /// Gateway operator, signing signer rotations to bypass the minimum
+    /// rotation delay.
+    pub operator: Option<Pubkey>,
+
+    /// Maximum size of a planned transaction.
+    pub max_transaction_size: usize,
+
+    /// Maximum compute units of a planned transaction, as estimated by the
+    /// `*_COMPUTE_UNITS` constants.
+    pub max_compute_units: u32,
+}
+
+impl TransactionPlanner {
+    pub const fn new(payer: Pubkey) -> Self {
+        Self {
+            payer,
+            operator: None,
+            max_transaction_size: MAX_TRANSACTION_SIZE,
+            max_compute_units: MAX_TRANSACTION_COMPUTE_UNITS,
+        }
+    }
+
+    /// Plans the stages processing `execute_data`, picking up from the
+    /// on-chain `session` if it already exists.
+    ///
+    /// Signatures are verified heaviest verifier first, until the quorum is
+    /// reached. `current_epoch` is the epoch of the gateway config, only used
+    /// when rotating signers.
+    ///
+    /// Messages whose command id is in `existing_messages`, as returned by
+    /// [`fetch_existing_messages`], were already approved and are skipped.
+    /// Nothing is planned if they all were.
+    pub fn plan(
+        &self,
+        execute_data: &ExecuteData,
+        session: Option<&SessionState>,
+        current_epoch: U256,
+        existing_messages: &BTreeSet<[u8; 32]>,
+    ) -> Result<Vec<Stage>, PlanError> {
+        let payload_type = match execute_data.payload_items {
+            MerklizedPayload::NewMessages { .. } => PayloadType::ApproveMessages,
+            MerklizedPayload::VerifierSetRotation { .. } => PayloadType::RotateSigners,
+        };
+        if let MerklizedPayload::NewMessages { messages } = &execute_data.payload_items {
+            if messages
+                .iter()
+                .all(|message| existing_messages.contains(&message.leaf.message.command_id()))
+            {
+                return Ok(Vec::new());
+            }
+        }
+        let signers = execute_data
+            .signing_verifier_set_leaves
+            .iter()
+            .map(|signer| {
+                if signer.payload_type == payload_type {
+                    Ok(signer)
+                } else {
+                    Err(PlanError::PayloadTypeMismatch)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let payload_merkle_root = execute_data.payload_merkle_root;
+        let verifier_set_hash = execute_data.signing_verifier_set_merkle_root;
+        let session_pda = SignatureVerificationSessionData::find_pda(
+            &payload_merkle_root,
+            payload_type,
+            &verifier_set_hash,
+        )
+        .0;
+
+        let mut stages = Vec::new();
+
+        // Initialize the session, making room for every signature slot
+        let set_size = signers.first().map_or(0, |signer| signer.leaf.set_size);
+        let mut session_instructions = Vec::new();
+        if session.is_none() {
+            session_instructions.push((
+                make_initialize_payload_verification_session_instruction(
+                    self.payer,
+                    payload_merkle_root,
+                    payload_type,
+                    verifier_set_hash,
+                ),
+                INITIALIZE_SESSION_COMPUTE_UNITS,
+            ));
+        }
+        let space = session.map_or(SignatureVerificationSessionData::SPACE, |session| {
+            session.space
+        });
+        if space < SignatureVerificationSessionData::space_for(set_size) {
+            session_instructions.push((
+                make_extend_verification_session_instruction(
+                    self.payer,
+                    session_pda,
+                    payload_merkle_root,
+                    payload_type,
+                    set_size,
+                ),
+                EXTEND_SESSION_COMPUTE_UNITS,
+            ));
+        }
+        self.push_stage(&mut stages, session_instructions)?;
+
+        // Verify just enough signatures to reach the quorum
+        let verify_instructions = select_signers(signers, session)?
+            .into_iter()
+            .map(|signer| {
+                (
+                    make_verify_signature_instruction(
+                        session_pda,
+                        verifier_set_hash,
+                        payload_merkle_root,
+                        signer.clone(),
+                    ),
+                    VERIFY_SIGNATURE_COMPUTE_UNITS,
+                )
+            })
+            .collect();
+        self.push_stage(&mut stages, verify_instructions)?;
+
+        // Act on the payload
+        let payload_instructions = match &execute_data.payload_items {
+            MerklizedPayload::NewMessages { messages } => messages
+                .iter()
+                .filter(|message| !existing_messages.contains(&message.leaf.message.command_id()))
+                .map(|message| {
+                    (
+                        make_approve_message_instruction(
+                            self.payer,
+                            session_pda,
+                            payload_merkle_root,
+                            message.clone(),
+                        ),
+                        APPROVE_MESSAGE_COMPUTE_UNITS,
+                    )
+                })
+                .collect(),
+            MerklizedPayload::VerifierSetRotation {
+                new_verifier_set_merkle_root,
+            } => {
+                let new_epoch = current_epoch
+                    .checked_add(U256::ONE)
+                    .ok_or(PlanError::EpochOverflow)?;
+                vec![(
+                    make_rotate_signers_instruction(
+                        self.payer,
+                        self.operator,
+                        session_pda,
+                        verifier_set_hash,
+                        *new_verifier_set_merkle_root,
+                        new_epoch,
+                    ),
+                    ROTATE_SIGNERS_COMPUTE_UNITS,
+                )]
+            }
+        };
+        self.push_stage(&mut stages, payload_instructions)?;
+
+        Ok(stages)
+    }
+
+    /// Packs `instructions` into as few transactions as the limits allow, and
+    /// appends them as a stage.
+    fn push_stage(
+        &self,
+        stages: &mut Vec<Stage>,
+        instructions: Vec<(Instruction, u32)>,
+    ) -> Result<(), PlanError> {
+        let mut transactions: Vec<TransactionInstructions> = Vec::new();
+        let mut compute_units = 0_u32;
+
+        for (instruction, instruction_compute_units) in instructions {
+            let total_compute_units = compute_units.saturating_add(instruction_compute_units);
+
+            // Append to the last transaction, unless that exceeds the limits
+            let overflow = match transactions.last_mut() {
+                Some(transaction) => {
+                    transaction.push(instruction);
+                    if transaction_size(&self.payer, transaction) <= self.max_transaction_size
+                        && total_compute_units <= self.max_compute_units
+                    {
+                        compute_units = total_compute_units;
+                        None
+                    } else {
+                        transaction.pop()
+                    }
+                }
+                None => Some(instruction),
+            };
+
+            if let Some(instruction) = overflow {
+                transactions.push(self.single_instruction_transaction(instruction)?);
+                compute_units = instruction_compute_units;
+            }
+        }
+
+        if !transactions.is_empty() {
+            stages.push(Stage { transactions });
+        }
+
+        Ok(())
+    }
+
+    fn single_instruction_transaction(
+        &self,
+        instruction: Instruction,
+    ) -> Result<TransactionInstructions, PlanError> {
+        let transaction = vec![instruction];
+        let size = transaction_size(&self.payer, &transaction);
+        if size > self.max_transaction_size {
+            return Err(PlanError::InstructionTooLarge { size });
+        }
+
+        Ok(transaction)
+    }
+}
+
+/// Fetches which messages of `execute_data` already have an `IncomingMessage`
+/// account, with a single call to `fetcher`.
+///
+/// Approving them again would fail whatever their status, so the returned
+/// command ids are meant to be passed to [`TransactionPlanner::plan`].
+pub fn fetch_existing_messages<F: AccountFetcher>(
+    fetcher: &F,
+    execute_data: &ExecuteData,
+) -> Result<BTreeSet<[u8; 32]>, QueryError<F::Error>> {
+    let MerklizedPayload::NewMessages { messages } = &execute_data.payload_items else {
+        return Ok(BTreeSet::new());
+    };
+
+    let command_ids = messages
+        .iter()
+        .map(|message| message.leaf.message.command_id())
+        .collect::<Vec<_>>();
+    let addresses = command_ids
+        .iter()
+        .map(|command_id| IncomingMessage::find_pda(command_id).0)
+        .collect::<Vec<_>>();
+    let accounts = fetcher
+        .fetch_accounts(&addresses)
+        .map_err(QueryError::Fetch)?;
+
+    if accounts.len() != addresses.len() {
+        return Err(QueryError::AccountCountMismatch {
+            expected: addresses.len(),
+            actual: accounts.len(),
+        });
+    }
+
+    Ok(command_ids
+        .into_iter()
+        .zip(accounts)
+        .filter_map(|(command_id, data)| data.map(|_| command_id))
+        .collect())
+}
+
+/// Picks the signers to verify so that the session reaches the quorum, heaviest
+/// first and skipping those already verified.
+fn select_signers<'a>(
+    mut signers: Vec<&'a SigningVerifierSetInfo>,
+    session: Option<&SessionState>,
+) -> Result<Vec<&'a SigningVerifierSetInfo>, PlanError> {
+    let (mut signed_weight, verified_positions) = match session {
+        Some(SessionState {
+            accumulated_weight: None,
+            ..
+        }) => return Ok(Vec::new()),
+        Some(SessionState {
+            accumulated_weight: Some(weight),
+            verified_positions,
+            ..
+        }) => (*weight, Some(verified_positions)),
+        None => (0, None),
+    };
+    let quorum = signers.first().map_or(0, |signer| signer.leaf.quorum);
+
+    signers.retain(|signer| {
+        verified_positions.is_none_or(|positions| !positions.contains(&signer.leaf.position))
+    });
+    signers.sort_by(|signer, other| {
+        other
+            .leaf
+            .signer_weight
+            .cmp(&signer.leaf.signer_weight)
+            .then(signer.leaf.position.cmp(&other.leaf.position))
+    });
+
+    let mut selected = Vec::new();
+    for signer in signers {
+        if signed_weight >= quorum && quorum != 0 {
+            break;
+        }
+        signed_weight = signed_weight.saturating_add(signer.leaf.signer_weight);
+        selected.push(signer);
+    }
+
+    if signed_weight < quorum || quorum == 0 {
+        return Err(PlanError::InsufficientWeight {
+            signed_weight,
+            quorum,
+        });
+    }
+
+    Ok(selected)
+}
+
+/// Size of a legacy transaction signed by `payer` and the other signers of
+/// `instructions`.
+pub fn transaction_size(payer: &Pubkey, instructions: &[Instruction]) -> usize {
+    let mut signers = BTreeSet::from([*payer]);
+    let mut accounts = BTreeSet::from([*payer]);
+    let mut instructions_size = compact_len_size(instructions.len());
+    for instruction in instructions {
+        accounts.insert(instruction.program_id);
+        for meta in &instruction.accounts {
+            accounts.insert(meta.pubkey);
+            if meta.is_signer {
+                signers.insert(meta.pubkey);
+            }
+        }
+        instructions_size += 1
+            + compact_len_size(instruction.accounts.len())
+            + instruction.accounts.len()
+            + compact_len_size(instruction.data.len())
+            + instruction.data.len();
+    }
+
+    let signatures_size = compact_len_size(signers.len()) + signers.len() * 64;
+    // Message header and recent blockhash
+    let message_size = 3 + compact_len_size(accounts.len()) + accounts.len() * 32 + 32;
+
+    signatures_size + message_size + instructions_size
+}
+
+/// Size of the compact-u16 encoding of `len`.
+fn compact_len_size(len: usize) -> usize {
+    match len {
+        0..=0x7f => 1,
+        0x80..=0x3fff => 2,
+        _ => 3,
+    }
+}
+
+pub fn make_initialize_payload_verification_session_instruction(
+    payer: Pubkey,
+    payload_merkle_root: [u8; 32],
+    payload_type: PayloadType,
+    verifier_set_hash: [u8; 32],
+) -> Instruction {
+    let verification_session_account = SignatureVerificationSessionData::find_pda(
+        &payload_merkle_root,
+        payload_type,
+        &verifier_set_hash,
+    )
+    .0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::InitializePayloadVerificationSession {
+            payer,
+            gateway_root_pda: GatewayConfig::find_pda().0,
+            verification_session_account,
+            verifier_set_tracker_pda: VerifierSetTracker::find_pda(&verifier_set_hash).0,
+            system_program: anchor_lang::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::InitializePayloadVerificationSession {
+            merkle_root: payload_merkle_root,
+            payload_type,
+        }
+        .data(),
+    }
+}
+
+pub fn make_extend_verification_session_instruction(
+    payer: Pubkey,
+    verification_session_account: Pubkey,
+    payload_merkle_root: [u8; 32],
+    payload_type: PayloadType,
+    set_size: u16,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::ExtendVerificationSession {
+            payer,
+            verification_session_account,
+            system_program: anchor_lang::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::ExtendVerificationSession {
+            payload_merkle_root,
+            payload_type,
+            set_size,
+        }
+        .data(),
+    }
+}
+
+pub fn make_verify_signature_instruction(
+    verification_session_account: Pubkey,
+    verifier_set_hash: [u8; 32],
+    payload_merkle_root: [u8; 32],
+    verifier_info: SigningVerifierSetInfo,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::VerifySignature {
+            gateway_root_pda: GatewayConfig::find_pda().0,
+            verification_session_account,
+            verifier_set_tracker_pda: VerifierSetTracker::find_pda(&verifier_set_hash).0,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::VerifySignature {
+            payload_merkle_root,
+            verifier_info,
+        }
+        .data(),
+    }
+}
+
+pub fn make_approve_message_instruction(
+    funder: Pubkey,
+    verification_session_account: Pubkey,
+    payload_merkle_root: [u8; 32],
+    merklized_message: MerklizedMessage,
+) -> Instruction {
+    let command_id = merklized_message.leaf.message.command_id();
+    let (event_authority, _) = crate::EVENT_AUTHORITY_AND_BUMP;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::ApproveMessage {
+            gateway_root_pda: GatewayConfig::find_pda().0,
+            funder,
+            verification_session_account,
+            incoming_message_pda: IncomingMessage::find_pda(&command_id).0,
+            system_program: anchor_lang::system_program::ID,
+            event_authority,
+            program: crate::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::ApproveMessage {
+            merklized_message,
+            payload_merkle_root,
+        }
+        .data(),
+    }
+}
+
+pub fn make_rotate_signers_instruction(
+    payer: Pubkey,
+    operator: Option<Pubkey>,
+    verification_session_account: Pubkey,
+    verifier_set_hash: [u8; 32],
+    new_verifier_set_merkle_root: [u8; 32],
+    new_epoch: U256,
+) -> Instruction {
+    let (event_authority, _) = crate::EVENT_AUTHORITY_AND_BUMP;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::RotateSigners {
+            gateway_root_pda: GatewayConfig::find_pda().0,
+            verification_session_account,
+            verifier_set_tracker_pda: VerifierSetTracker::find_pda(&verifier_set_hash).0,
+            new_verifier_set_tracker: VerifierSetTracker::find_pda(&new_verifier_set_merkle_root).0,
+            new_verifier_set_epoch: Some(VerifierSetEpoch::find_pda(new_epoch).0),
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            operator,
+            event_authority,
+            program: crate::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::RotateSigners {
+            new_verifier_set_merkle_root,
+        }
+        .data(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use core::convert::Infallible;
+    use std::collections::BTreeMap;
+
+    use solana_axelar_std::hasher::Hasher;
+    use solana_axelar_std::{
+        execute_data, CrossChainId, Message, Messages, Payload, PublicKey, Signature, VerifierSet,
+        ECDSA_RECOVERABLE_SIGNATURE_LEN, SECP256K1_COMPRESSED_PUBKEY_LEN,
+    };
+
+    use super::*;
+    use crate::SignatureVerification;
+
+    const DOMAIN_SEPARATOR: [u8; 32] = [5; 32];
+
+    /// A verifier set whose verifiers have the given `weights`.
+    fn verifier_set(weights: &[u128], quorum: u128) -> VerifierSet {
+        VerifierSet {
+            nonce: 1,
+            signers: weights
+                .iter()
+                .enumerate()
+                .map(|(index, weight)| {
+                    let mut pubkey = [2_u8; SECP256K1_COMPRESSED_PUBKEY_LEN];
+                    pubkey[1..3].copy_from_slice(&u16::try_from(index).unwrap().to_be_bytes());
+                    (PublicKey(pubkey), *weight)
+                })
+                .collect(),
+            quorum,
+        }
+    }
+
+    fn messages(count: u8) -> Messages {
+        Messages(
+            (0..count)
+                .map(|id| Message {
+                    cc_id: CrossChainId {
+                        chain: "ethereum".to_owned(),
+                        id: id.to_string(),
+                    },
+                    source_address: "0xSource".to_owned(),
+                    destination_chain: "solana".to_owned(),
+                    destination_address: Pubkey::new_unique().to_string(),
+                    payload_hash: [id; 32],
+                })
+                .collect(),
+        )
+    }
+
+    /// Execute data signed by every verifier, the signatures themselves are
+    /// irrelevant to the planner.
+    fn execute_data(verifier_set: &VerifierSet, payload: Payload) -> ExecuteData {
+        let signatures = verifier_set
+            .signers
+            .keys()
+            .map(|pubkey| (*pubkey, Signature([0; ECDSA_RECOVERABLE_SIGNATURE_LEN])))
+            .collect::<BTreeMap<_, _>>();
+        let encoded =
+            execute_data::encode(verifier_set, &signatures, DOMAIN_SEPARATOR, payload).unwrap();
+
+        ExecuteData::decode(&encoded).unwrap()
+    }
+
+    fn instructions(stage: &Stage) -> Vec<&Instruction> {
+        stage.transactions.iter().flatten().collect()
+    }
+
+    fn verified_positions(stage: &Stage) -> Vec<u16> {
+        instructions(stage)
+            .into_iter()
+            .map(|instruction| {
+                let data = instruction
+                    .data
+                    .strip_prefix(crate::instruction::VerifySignature::DISCRIMINATOR)
+                    .unwrap();
+                let verify_signature: crate::instruction::VerifySignature =
+                    anchor_lang::AnchorDeserialize::try_from_slice(data).unwrap();
+                verify_signature.verifier_info.leaf.position
+            })
+            .collect()
+    }
+
+    #[test]
+    fn plan_message_approval() {
+        let payer = Pubkey::new_unique();
+        let messages = messages(3);
+        let execute_data = execute_data(
+            &verifier_set(&[1, 5, 2, 5], 10),
+            Payload::Messages(messages.clone()),
+        );
+
+        let stages = TransactionPlanner::new(payer)
+            .plan(&execute_data, None, U256::ZERO, &BTreeSet::new())
+            .unwrap();
+        assert_eq!(stages.len(), 3);
+
+        // The session is initialized first
+        let session_instructions = instructions(&stages[0]);
+        assert_eq!(session_instructions.len(), 1);
+        assert!(session_instructions[0]
+            .data
+            .starts_with(crate::instruction::InitializePayloadVerificationSession::DISCRIMINATOR));
+
+        // Only the two heaviest verifiers sign
+        let mut positions = verified_positions(&stages[1]);
+        positions.sort_unstable();
+        let heaviest_positions = execute_data
+            .signing_verifier_set_leaves
+            .iter()
+            .filter(|signer| signer.leaf.signer_weight == 5)
+            .map(|signer| signer.leaf.position)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, heaviest_positions);
+
+        // Every message gets approved into its incoming message PDA
+        let approve_instructions = instructions(&stages[2]);
+        assert_eq!(approve_instructions.len(), messages.0.len());
+        for (instruction, message) in approve_instructions.iter().zip(&messages.0) {
+            let incoming_message_pda = IncomingMessage::find_pda(&message.command_id()).0;
+            assert!(instruction
+                .accounts
+                .iter()
+                .any(|meta| meta.pubkey == incoming_message_pda && meta.is_writable));
+        }
+
+        for stage in &stages {
+            for transaction in &stage.transactions {
+                assert!(transaction_size(&payer, transaction) <= MAX_TRANSACTION_SIZE);
+            }
+        }
+    }
+
+    #[test]
+    fn plan_packs_instructions_within_size_limit() {
+        let payer = Pubkey::new_unique();
+        let execute_data =
+            execute_data(&verifier_set(&[1; 16], 16), Payload::Messages(messages(1)));
+
+        let stages = TransactionPlanner::new(payer)
+            .plan(&execute_data, None, U256::ZERO, &BTreeSet::new())
+            .unwrap();
+
+        let verify_stage = &stages[1];
+        assert_eq!(verified_positions(verify_stage).len(), 16);
+        assert!(verify_stage.transactions.len() < 16);
+        for transaction in &verify_stage.transactions {
+            assert!(transaction_size(&payer, transaction) <= MAX_TRANSACTION_SIZE);
+        }
+    }
+
+    #[test]
+    fn plan_skips_verified_signatures() {
+        let execute_data = execute_data(
+            &verifier_set(&[1, 1, 1, 1], 3),
+            Payload::Messages(messages(1)),
+        );
+        let session = SessionState {
+            space: SignatureVerificationSessionData::SPACE,
+            accumulated_weight: Some(2),
+            verified_positions: BTreeSet::from([0, 2]),
+        };
+
+        let stages = TransactionPlanner::new(Pubkey::new_unique())
+            .plan(&execute_data, Some(&session), U256::ZERO, &BTreeSet::new())
+            .unwrap();
+
+        // No session instructions, and a single signature left to verify
+        assert_eq!(stages.len(), 2);
+        assert_eq!(verified_positions(&stages[0]), vec![1]);
+    }
+
+    #[test]
+    fn plan_skips_verification_of_valid_session() {
+        let execute_data = execute_data(&verifier_set(&[1, 1], 2), Payload::Messages(messages(2)));
+        let session = SessionState {
+            space: SignatureVerificationSessionData::SPACE,
+            accumulated_weight: None,
+            verified_positions: BTreeSet::from([0, 1]),
+        };
+
+        let stages = TransactionPlanner::new(Pubkey::new_unique())
+            .plan(&execute_data, Some(&session), U256::ZERO, &BTreeSet::new())
+            .unwrap();
+
+        assert_eq!(stages.len(), 1);
+        assert_eq!(instructions(&stages[0]).len(), 2);
+    }
+
+    #[test]
+    fn plan_skips_existing_messages() {
+        let messages = messages(3);
+        let execute_data = execute_data(
+            &verifier_set(&[1, 1], 2),
+            Payload::Messages(messages.clone()),
+        );
+        let planner = TransactionPlanner::new(Pubkey::new_unique());
+
+        let existing = BTreeSet::from([messages.0[1].command_id()]);
+        let stages = planner
+            .plan(&execute_data, None, U256::ZERO, &existing)
+            .unwrap();
+        let approve_instructions = instructions(&stages[2]);
+        assert_eq!(approve_instructions.len(), 2);
+        let existing_pda = IncomingMessage::find_pda(&messages.0[1].command_id()).0;
+        assert!(approve_instructions.iter().all(|instruction| instruction
+            .accounts
+            .iter()
+            .all(|meta| meta.pubkey != existing_pda)));
+
+        // Nothing left to do once every message exists
+        let existing = messages.0.iter().map(Message::command_id).collect();
+        assert_eq!(
+            planner.plan(&execute_data, None, U256::ZERO, &existing),
+            Ok(Vec::new())
+        );
+    }
+
+    #[test]
+    fn fetch_existing_messages_returns_messages_with_an_account() {
+        struct Fetcher(BTreeMap<Pubkey, Vec<u8>>);
+
+        impl AccountFetcher for Fetcher {
+            type Error = Infallible;
+
+            fn fetch_accounts(
+                &self,
+                addresses: &[Pubkey],
+            ) -> Result<Vec<Option<Vec<u8>>>, Self::Error> {
+                Ok(addresses
+                    .iter()
+                    .map(|address| self.0.get(address).cloned())
+                    .collect())
+            }
+        }
+
+        let messages = messages(3);
+        let execute_data = execute_data(
+            &verifier_set(&[1, 1], 2),
+            Payload::Messages(messages.clone()),
+        );
+        let fetcher = Fetcher(BTreeMap::from([
+            (
+                IncomingMessage::find_pda(&messages.0[0].command_id()).0,
+                vec![1],
+            ),
+            (
+                IncomingMessage::find_pda(&messages.0[2].command_id()).0,
+                vec![2],
+            ),
+        ]));
+
+        assert_eq!(
+            fetch_existing_messages(&fetcher, &execute_data).unwrap(),
+            BTreeSet::from([messages.0[0].command_id(), messages.0[2].command_id()])
+        );
+    }
+
+    #[test]
+    fn plan_rejects_insufficient_weight() {
+        let verifier_set = verifier_set(&[1, 1, 1], 3);
+        let mut execute_data = execute_data(&verifier_set, Payload::Messages(messages(1)));
+        execute_data.signing_verifier_set_leaves.pop();
+
+        assert_eq!(
+            TransactionPlanner::new(Pubkey::new_unique()).plan(
+                &execute_data,
+                None,
+                U256::ZERO,
+                &BTreeSet::new()
+            ),
+            Err(PlanError::InsufficientWeight {
+                signed_weight: 2,
+                quorum: 3
+            })
+        );
+    }
+
+    #[test]
+    fn plan_extends_session_of_large_verifier_set() {
+        let execute_data =
+            execute_data(&verifier_set(&[1; 300], 2), Payload::Messages(messages(1)));
+
+        let stages = TransactionPlanner::new(Pubkey::new_unique())
+            .plan(&execute_data, None, U256::ZERO, &BTreeSet::new())
+            .unwrap();
+
+        let session_instructions = instructions(&stages[0]);
+        assert_eq!(session_instructions.len(), 2);
+        assert!(session_instructions[1]
+            .data
+            .starts_with(crate::instruction::ExtendVerificationSession::DISCRIMINATOR));
+    }
+
+    #[test]
+    fn plan_signer_rotation() {
+        let payer = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let execute_data = execute_data(
+            &verifier_set(&[1, 1], 2),
+            Payload::NewVerifierSet(verifier_set(&[1, 1, 1], 2)),
+        );
+
+        let planner = TransactionPlanner {
+            operator: Some(operator),
+            ..TransactionPlanner::new(payer)
+        };
+        let stages = planner
+            .plan(&execute_data, None, U256::from_u64(4), &BTreeSet::new())
+            .unwrap();
+
+        let rotate_instructions = instructions(&stages[2]);
+        assert_eq!(rotate_instructions.len(), 1);
+        let accounts = &rotate_instructions[0].accounts;
+        let new_epoch_pda = VerifierSetEpoch::find_pda(U256::from_u64(5)).0;
+        assert!(accounts.iter().any(|meta| meta.pubkey == new_epoch_pda));
+        assert!(accounts
+            .iter()
+            .any(|meta| meta.pubkey == operator && meta.is_signer));
+    }
+
+    #[test]
+    fn session_state_decodes_signature_slots() {
+        let mut session = SignatureVerificationSessionData::new(
+            SignatureVerification {
+                accumulated_threshold: solana_axelar_std::U128::new(7),
+                ..Default::default()
+            },
+            255,
+        );
+        session.signature_verification.signature_slots[0] = 0b101;
+
+        let mut data = SignatureVerificationSessionData::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&session));
+        data.extend_from_slice(&[0b10, 0, 0, 0, 0, 0]);
+
+        let state = SessionState::decode(&data).unwrap();
+        assert_eq!(
+            state,
+            SessionState {
+                space: data.len(),
+                accumulated_weight: Some(7),
+                verified_positions: BTreeSet::from([0, 2, 257]),
+            }
+        );
+
+        session.signature_verification.accumulated_threshold = solana_axelar_std::U128::MAX;
+        let mut data = SignatureVerificationSessionData::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&session));
+        assert_eq!(
+            SessionState::decode(&data).unwrap().accumulated_weight,
+            None
+        );
+    }
+}
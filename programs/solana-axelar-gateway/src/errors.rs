@@ -1,3 +1,4 @@
+use crate::{GatewayErrorContext, GatewayErrorEvent};
 use anchor_lang::prelude::*;
 
 #[error_code]
@@ -66,4 +67,124 @@ pub enum GatewayError {
     BorshDeserializeError,
     #[msg("ABI error")]
     PayloadAbiError,
+    #[msg("Message not executed")]
+    MessageNotExecuted,
+    #[msg("Invalid funder account")]
+    InvalidFunder,
+    #[msg("Verification session still in use")]
+    VerificationSessionInUse,
+    #[msg("Empty message batch")]
+    EmptyMessageBatch,
+    #[msg("Invalid message batch")]
+    InvalidMessageBatch,
+    #[msg("Invalid incoming message PDA")]
+    InvalidIncomingMessagePDA,
+    #[msg("Invalid secp256k1 precompile instruction")]
+    InvalidPrecompileInstruction,
+    #[msg("Precompile signatures don't match the verifiers")]
+    PrecompileSignatureMismatch,
+    #[msg("The gateway is paused")]
+    GatewayPaused,
+    #[msg("Unknown destination chain")]
+    UnknownDestinationChain,
+    #[msg("Destination chain already registered")]
+    DestinationChainAlreadyRegistered,
+    #[msg("Destination chain not registered")]
+    DestinationChainNotRegistered,
+    #[msg("Message approval was revoked")]
+    MessageRevoked,
+    #[msg("Message approval expired")]
+    MessageApprovalExpired,
+    #[msg("Payload buffer exceeds the maximum payload size")]
+    PayloadBufferTooLarge,
+    #[msg("Write exceeds the payload buffer bounds")]
+    PayloadBufferOutOfBounds,
+    #[msg("Message payload was already committed")]
+    MessagePayloadAlreadyCommitted,
+    #[msg("Message payload doesn't match the approved payload hash")]
+    MessagePayloadHashMismatch,
+    #[msg("Only the proposed operator can accept the operatorship")]
+    InvalidProposedOperator,
+    #[msg("Gateway config is already at the current version")]
+    ConfigAlreadyMigrated,
+    #[msg("Verifier set is still within the retention window")]
+    VerifierSetNotExpired,
+    #[msg("Invalid rent recipient")]
+    InvalidRentRecipient,
+    #[msg("Signer batch is empty or not ordered by position")]
+    InvalidSignerBatch,
+    #[msg("Execution receipt was already recorded for this message")]
+    ExecutionReceiptAlreadyRecorded,
+    #[msg("Execution failure reason is too long")]
+    FailureReasonTooLong,
+    #[msg("Too many signatures or too much data for a precompile instruction")]
+    PrecompileInstructionTooLarge,
+    #[msg("Destination chain is empty or too long")]
+    InvalidDestinationChain,
+    #[msg("The destination chain registry must be provided")]
+    MissingDestinationChainRegistry,
+    #[msg("The call contract event doesn't fit in the event CPI")]
+    CallContractEventTooLarge,
+    #[msg("The operatorship proposal was made for a previous operator")]
+    StaleOperatorshipProposal,
+    #[msg("Invalid gateway config value")]
+    InvalidConfigValue,
+    #[msg("Invalid verifier set epoch provided")]
+    InvalidVerifierSetEpochProvided,
+    #[msg("The signer batch was signed for another payload type")]
+    SignerBatchPayloadTypeMismatch,
+    #[msg("Incoming message is already at the current layout")]
+    IncomingMessageAlreadyMigrated,
+    #[msg("Gateway pause status is unchanged")]
+    PauseStatusUnchanged,
+    #[msg("Payload location is empty or too long")]
+    InvalidPayloadLocation,
+    #[msg("Operatorship must be transferred with propose_operatorship and accept_operatorship")]
+    OperatorshipTransferDeprecated,
+}
+
+impl GatewayError {
+    /// Logs a [`GatewayErrorEvent`] describing why the gateway fails with this
+    /// error, and returns the error.
+    pub fn with_context(self, context: GatewayErrorContext) -> Error {
+        emit!(GatewayErrorEvent {
+            error_code: self.into(),
+            context,
+        });
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_axelar_std::U256;
+
+    #[test]
+    fn with_context_returns_the_error() {
+        let error = GatewayError::SlotAlreadyVerified.with_context(GatewayErrorContext::Verifier {
+            payload_merkle_root: [1; 32],
+            verifier_set_hash: [2; 32],
+            position: 3,
+            set_size: 4,
+        });
+        assert_eq!(error, GatewayError::SlotAlreadyVerified.into());
+    }
+
+    #[test]
+    fn error_event_round_trip() {
+        let event = GatewayErrorEvent {
+            error_code: GatewayError::VerifierSetTooOld.into(),
+            context: GatewayErrorContext::VerifierSetEpoch {
+                epoch: U256::from(1_u64),
+                current_epoch: U256::from(7_u64),
+                retention: U256::from(5_u64),
+            },
+        };
+
+        let data = anchor_lang::Event::data(&event);
+        let (discriminator, payload) = data.split_at(GatewayErrorEvent::DISCRIMINATOR.len());
+        assert_eq!(discriminator, GatewayErrorEvent::DISCRIMINATOR);
+        assert_eq!(GatewayErrorEvent::try_from_slice(payload).unwrap(), event);
+    }
 }
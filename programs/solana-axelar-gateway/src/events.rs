@@ -25,6 +25,29 @@ pub struct MessageExecutedEvent {
     pub destination_chain: String,
 }
 
+/// Execution receipt recorded by the destination program of a message, to be
+/// relayed back to the source chain as an acknowledgement.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MessageExecutionReceiptEvent {
+    pub command_id: [u8; 32],
+    pub destination_address: Pubkey,
+    pub source_chain: String,
+    pub cc_id: String,
+    pub source_address: String,
+    pub outcome: ExecutionOutcome,
+}
+
+/// Outcome of a message execution, as reported by its destination program.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize)]
+pub enum ExecutionOutcome {
+    Success,
+    /// The destination program handled the message, but couldn't act on it.
+    Failure {
+        reason: String,
+    },
+}
+
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct VerifierSetRotatedEvent {
@@ -40,6 +63,23 @@ pub struct CallContractEvent {
     pub destination_chain: String,
     pub destination_contract_address: String,
     pub payload: Vec<u8>,
+    /// The sender's outbound nonce, when its `CallContractNonce` was passed.
+    pub nonce: Option<u64>,
+}
+
+/// Emitted by `call_contract_with_hash`, for a payload that is too large to be
+/// emitted on-chain and is instead made available off-chain at
+/// `payload_location`.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CallContractWithHashEvent {
+    pub sender: Pubkey,
+    pub payload_hash: [u8; 32],
+    pub destination_chain: String,
+    pub destination_contract_address: String,
+    pub payload_location: String,
+    /// The sender's outbound nonce, when its `CallContractNonce` was passed.
+    pub nonce: Option<u64>,
 }
 
 #[event]
@@ -47,3 +87,145 @@ pub struct CallContractEvent {
 pub struct OperatorshipTransferredEvent {
     pub new_operator: [u8; 32],
 }
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GatewayPausedEvent {
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GatewayUnpausedEvent {
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DestinationChainAddedEvent {
+    pub chain_name: String,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DestinationChainRemovedEvent {
+    pub chain_name: String,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MessageRevokedEvent {
+    pub command_id: [u8; 32],
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MessageApprovalTtlUpdatedEvent {
+    pub message_approval_ttl: u32,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipProposedEvent {
+    pub current_operator: Pubkey,
+    pub proposed_operator: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipProposalCancelledEvent {
+    pub proposed_operator: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GatewayConfigUpdatedEvent {
+    pub minimum_rotation_delay: u64,
+    pub previous_verifier_set_retention: U256,
+    pub domain_separator: [u8; 32],
+    pub rent_recipient: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GatewayConfigMigratedEvent {
+    pub previous_version: u8,
+    pub version: u8,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VerifierSetClosedEvent {
+    pub verifier_set_hash: [u8; 32],
+    pub epoch: U256,
+    pub rent_recipient: Pubkey,
+}
+
+/// Diagnostic logged right before an instruction fails with `error_code`, so
+/// that relayers can tell why by decoding a structured event rather than
+/// matching free-form log messages.
+///
+/// Unlike the other events, it is emitted with `emit!` rather than through a
+/// self-CPI, as inner instructions of failed transactions aren't recorded. It
+/// can only be read from the `Program data:` entry of the transaction logs.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GatewayErrorEvent {
+    pub error_code: u32,
+    pub context: GatewayErrorContext,
+}
+
+/// What the gateway was checking when it failed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize)]
+pub enum GatewayErrorContext {
+    /// A verifier's signature slot, Merkle proof or signature got rejected.
+    Verifier {
+        payload_merkle_root: [u8; 32],
+        verifier_set_hash: [u8; 32],
+        position: u16,
+        set_size: u16,
+    },
+    /// The Merkle multiproof of a batch of verifiers got rejected.
+    VerifierBatch {
+        payload_merkle_root: [u8; 32],
+        verifier_set_hash: [u8; 32],
+        positions: Vec<u16>,
+        set_size: u16,
+    },
+    /// The verifier set's epoch is out of the retention window.
+    VerifierSetEpoch {
+        epoch: U256,
+        current_epoch: U256,
+        retention: U256,
+    },
+    /// The verifier set doesn't match the one the session was started with.
+    VerifierSetHash {
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+    /// A leaf's domain separator doesn't match the gateway's.
+    DomainSeparator {
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+    /// A message leaf isn't part of the payload Merkle root.
+    MessageLeaf {
+        payload_merkle_root: [u8; 32],
+        position: u16,
+        set_size: u16,
+    },
+    /// The Merkle multiproof of a batch of message leaves got rejected.
+    MessageBatch {
+        payload_merkle_root: [u8; 32],
+        positions: Vec<u16>,
+        set_size: u16,
+    },
+    /// The rotation wasn't signed by the latest verifier set.
+    LatestVerifierSet { epoch: U256, current_epoch: U256 },
+    /// The minimum delay since the last rotation hasn't passed yet.
+    RotationCooldown {
+        last_rotation_timestamp: u64,
+        minimum_rotation_delay: u64,
+        current_time: u64,
+    },
+}
@@ -6,8 +6,8 @@ use crate as solana_axelar_gateway;
 
 use crate::payload;
 
-// Re-export Message
-pub use crate::Message;
+// Re-export Message and the execution receipt outcome
+pub use crate::{ExecutionOutcome, Message};
 pub use payload::AxelarMessagePayload as ExecutablePayload;
 pub use payload::EncodingScheme as ExecutablePayloadEncodingScheme;
 
@@ -20,6 +20,8 @@ pub enum ExecutableError {
     InvalidPayloadHash,
     /// Provided accounts are invalid
     InvalidAccounts,
+    /// The message payload buffer is invalid or wasn't committed
+    InvalidMessagePayload,
 }
 
 impl From<ExecutableError> for anchor_lang::error::Error {
@@ -31,6 +33,9 @@ impl From<ExecutableError> for anchor_lang::error::Error {
             ExecutableError::InvalidAccounts => {
                 anchor_lang::error::Error::from(ProgramError::Custom(7001))
             }
+            ExecutableError::InvalidMessagePayload => {
+                anchor_lang::error::Error::from(ProgramError::Custom(7002))
+            }
         }
     }
 }
@@ -304,6 +309,144 @@ pub fn validate_message_raw(
     Ok(())
 }
 
+/// Records the outcome of executing a message that was validated with one of
+/// the `validate_message` functions, via CPI to the Axelar gateway.
+///
+/// Optional: the gateway relays the receipt to the source chain as an
+/// acknowledgement.
+///
+/// # Example
+///
+/// ```ignore
+/// validate_message_raw(&executable_accounts, message.clone(), &encoded_payload)?;
+/// let outcome = match process(&encoded_payload) {
+///     Ok(()) => ExecutionOutcome::Success,
+///     Err(err) => ExecutionOutcome::Failure { reason: err.to_string() },
+/// };
+/// record_execution_receipt(&executable_accounts, message, outcome)?;
+/// ```
+pub fn record_execution_receipt(
+    executable_accounts: &AxelarExecutableAccountRefs<'_, '_>,
+    message: solana_axelar_gateway::Message,
+    outcome: solana_axelar_gateway::ExecutionOutcome,
+) -> Result<()> {
+    use solana_axelar_gateway::__cpi_client_accounts_record_execution_receipt as receipt_accounts;
+
+    let command_id = message.command_id();
+    let signer_seeds = &[
+        solana_axelar_gateway::ValidateMessageSigner::SEED_PREFIX,
+        &command_id[..],
+        &[executable_accounts
+            .incoming_message_pda
+            .load()?
+            .signing_pda_bump],
+    ];
+    let signer_seeds = &[&signer_seeds[..]];
+
+    let cpi_accounts = receipt_accounts::RecordExecutionReceipt {
+        incoming_message_pda: executable_accounts.incoming_message_pda.to_account_info(),
+        caller: executable_accounts.signing_pda.to_account_info(),
+        event_authority: executable_accounts.event_authority.to_account_info(),
+        program: executable_accounts.axelar_gateway_program.to_account_info(),
+    };
+
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: executable_accounts.axelar_gateway_program.key(),
+        accounts: cpi_accounts.to_account_metas(None),
+        data: solana_axelar_gateway::instruction::RecordExecutionReceipt { message, outcome }
+            .data(),
+    };
+
+    invoke_signed(&ix, &cpi_accounts.to_account_infos(), signer_seeds)?;
+
+    Ok(())
+}
+
+/// Validates an Axelar message whose payload was uploaded to a
+/// [`MessagePayload`](solana_axelar_gateway::MessagePayload) buffer, returning
+/// the encoded payload.
+///
+/// Same as `validate_message`, except that the payload (including its account
+/// metadata) is read from the buffer instead of instruction data, so that
+/// payloads exceeding the transaction size can be executed.
+///
+/// # Example
+///
+/// ```ignore
+/// let message_payload = ctx.remaining_accounts.first().ok_or(ErrorCode::AccountNotEnoughKeys)?;
+/// let payload = validate_message_from_buffer(ctx.accounts, message, message_payload)?;
+/// let payload = ExecutablePayload::decode(&payload)?;
+/// ```
+///
+/// # Notes
+///
+/// The buffer account must not be part of the accounts struct implementing
+/// `ToAccountMetas`, as those are checked against the payload accounts. Pass it
+/// through the remaining accounts instead.
+pub fn validate_message_from_buffer<'info, T: HasAxelarExecutable<'info> + ToAccountMetas>(
+    accounts: &T,
+    message: solana_axelar_gateway::Message,
+    message_payload: &AccountInfo<'_>,
+) -> Result<Vec<u8>> {
+    let payload = read_message_payload(message_payload, &message.command_id())?;
+
+    // Verify that the accounts passed to the instruction are the ones the
+    // payload was sent with
+    let instruction_accounts = accounts
+        .to_account_metas(None)
+        .split_off(EXECUTE_PROGRAM_ACCOUNTS_START_INDEX);
+    let decoded = solana_axelar_gateway::executable::ExecutablePayload::decode(&payload)?;
+    if decoded.account_meta() != instruction_accounts {
+        return Err(solana_axelar_gateway::executable::ExecutableError::InvalidAccounts.into());
+    }
+
+    let executable_accounts = accounts.axelar_executable();
+    validate_message_raw(&executable_accounts, message, &payload)?;
+
+    Ok(payload)
+}
+
+/// Reads the payload of the message with the given command id from its
+/// committed [`MessagePayload`](solana_axelar_gateway::MessagePayload) buffer.
+///
+/// The payload isn't validated against the message, use
+/// `validate_message_from_buffer` or `validate_message_raw` for that.
+pub fn read_message_payload(
+    message_payload: &AccountInfo<'_>,
+    command_id: &[u8; 32],
+) -> Result<Vec<u8>> {
+    use solana_axelar_gateway::executable::ExecutableError;
+
+    if *message_payload.owner != solana_axelar_gateway::ID {
+        return Err(ExecutableError::InvalidMessagePayload.into());
+    }
+
+    let buffer = {
+        let data = message_payload.try_borrow_data()?;
+        solana_axelar_gateway::MessagePayload::try_deserialize(&mut &data[..])
+            .map_err(|_| ExecutableError::InvalidMessagePayload)?
+    };
+
+    let expected_pda = Pubkey::create_program_address(
+        &[
+            solana_axelar_gateway::MessagePayload::SEED_PREFIX,
+            command_id,
+            buffer.payer.as_ref(),
+            &[buffer.bump],
+        ],
+        &solana_axelar_gateway::ID,
+    )
+    .map_err(|_| ExecutableError::InvalidMessagePayload)?;
+
+    // Check: the buffer belongs to this message and was checked against its
+    // payload hash
+    if *message_payload.key != expected_pda || !buffer.committed {
+        return Err(ExecutableError::InvalidMessagePayload.into());
+    }
+
+    Ok(buffer.payload)
+}
+
 /// Relayer helpers for building the execute instruction
 /// for arbitrary programs.
 pub mod helpers {
@@ -0,0 +1,55 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayError, OperatorshipProposal, OperatorshipTransferredEvent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct AcceptOperatorship<'info> {
+    #[account(
+        mut,
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        // CHECK: Only the proposed operator can accept the operatorship
+        address = operatorship_proposal.proposed_operator @ GatewayError::InvalidProposedOperator,
+    )]
+    pub proposed_operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OperatorshipProposal::SEED_PREFIX,
+            operatorship_proposal.current_operator.as_ref(),
+            proposed_operator.key().as_ref(),
+        ],
+        bump = operatorship_proposal.bump,
+        // CHECK: The operator that the proposal was made for is still in place
+        constraint = operatorship_proposal.current_operator == gateway_root_pda.load()?.operator
+            @ GatewayError::StaleOperatorshipProposal,
+        close = payer,
+    )]
+    pub operatorship_proposal: Account<'info, OperatorshipProposal>,
+
+    /// CHECK: Must match the payer recorded when the operatorship was proposed
+    #[account(
+        mut,
+        address = operatorship_proposal.payer @ GatewayError::InvalidFunder,
+    )]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Second step of the two-step operatorship transfer: the proposed operator
+/// takes over the operatorship.
+pub fn accept_operatorship_handler(ctx: Context<AcceptOperatorship>) -> Result<()> {
+    let mut gateway_root_pda = ctx.accounts.gateway_root_pda.load_mut()?;
+    gateway_root_pda.operator = ctx.accounts.proposed_operator.key();
+
+    emit_cpi!(OperatorshipTransferredEvent {
+        new_operator: gateway_root_pda.operator.to_bytes()
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{DestinationChainAddedEvent, DestinationChainRegistry, GatewayConfig, GatewayError};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[event_cpi]
+#[instruction(chain_name: String)]
+pub struct AddDestinationChain<'info> {
+    #[account(
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        mut,
+    	// CHECK: This is either the current operator or the upgrade authority
+		constraint = gateway_root_pda.load()?.operator == *operator_or_upgrade_authority.key
+			|| program_data.upgrade_authority_address == Some(*operator_or_upgrade_authority.key)
+			@ GatewayError::InvalidOperatorOrAuthorityAccount
+    )]
+    pub operator_or_upgrade_authority: Signer<'info>,
+
+    #[account(
+	    seeds = [crate::ID.as_ref()],
+	    bump,
+	    seeds::program = bpf_loader_upgradeable::ID,
+	)]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        mut,
+        realloc = destination_chain_registry.space_with_chain_added(&chain_name),
+        realloc::payer = operator_or_upgrade_authority,
+        realloc::zero = false,
+        seeds = [DestinationChainRegistry::SEED_PREFIX],
+        bump = destination_chain_registry.bump,
+        // Check: the chain isn't registered yet
+        constraint = !destination_chain_registry.is_known_chain(&chain_name)
+            @ GatewayError::DestinationChainAlreadyRegistered,
+    )]
+    pub destination_chain_registry: Account<'info, DestinationChainRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds a chain to the destination chain registry.
+pub fn add_destination_chain_handler(
+    ctx: Context<AddDestinationChain>,
+    chain_name: String,
+) -> Result<()> {
+    ctx.accounts
+        .destination_chain_registry
+        .add_chain(chain_name.clone());
+
+    emit_cpi!(DestinationChainAddedEvent { chain_name });
+
+    Ok(())
+}
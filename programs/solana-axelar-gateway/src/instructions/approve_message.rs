@@ -1,9 +1,10 @@
 use crate::{
-    GatewayConfig, GatewayError, IncomingMessage, MessageApprovedEvent, MessageStatus,
-    SignatureVerificationSessionData, ValidateMessageSigner,
+    GatewayConfig, GatewayError, GatewayErrorContext, IncomingMessage, MessageApprovedEvent,
+    MessageStatus, SignatureVerificationSessionData, ValidateMessageSigner,
 };
 use anchor_lang::prelude::*;
 use solana_axelar_std::hasher::LeafHash;
+use solana_axelar_std::merkle::{verify_single_leaf_proof, ProofHashes};
 use solana_axelar_std::{MerklizedMessage, PayloadType};
 use std::str::FromStr;
 
@@ -13,7 +14,9 @@ use std::str::FromStr;
 pub struct ApproveMessage<'info> {
     #[account(
         seeds = [GatewayConfig::SEED_PREFIX],
-        bump = gateway_root_pda.load()?.bump
+        bump = gateway_root_pda.load()?.bump,
+        // Check: the gateway isn't paused
+        constraint = !gateway_root_pda.load()?.is_paused() @ GatewayError::GatewayPaused,
     )]
     pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
 
@@ -21,6 +24,7 @@ pub struct ApproveMessage<'info> {
     pub funder: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [
             SignatureVerificationSessionData::SEED_PREFIX,
             payload_merkle_root.as_ref(),
@@ -33,10 +37,12 @@ pub struct ApproveMessage<'info> {
     )]
     pub verification_session_account: AccountLoader<'info, SignatureVerificationSessionData>,
 
+    // NOTE: `init` also rejects command ids whose PDA was closed into an
+    // `IncomingMessageTombstone`, as the account stays allocated.
     #[account(
         init,
         payer = funder,
-        space = IncomingMessage::DISCRIMINATOR.len() + std::mem::size_of::<IncomingMessage>(),
+        space = IncomingMessage::SPACE,
         seeds = [IncomingMessage::SEED_PREFIX, merklized_message.leaf.message.command_id().as_ref()],
         bump
     )]
@@ -57,39 +63,49 @@ pub fn approve_message_handler(
 
     // Validate domain separator matches gateway config
     if merklized_message.leaf.domain_separator != gateway_config.domain_separator {
-        return err!(GatewayError::InvalidDomainSeparator);
+        return Err(GatewayError::InvalidDomainSeparator.with_context(
+            GatewayErrorContext::DomainSeparator {
+                expected: gateway_config.domain_separator,
+                actual: merklized_message.leaf.domain_separator,
+            },
+        ));
     }
 
     let leaf_hash = merklized_message.leaf.hash();
     let message_hash = merklized_message.leaf.message.hash();
-    let proof = solana_axelar_std::MerkleProof::from_bytes(&merklized_message.proof)
-        .map_err(|_err| GatewayError::InvalidMerkleProof)?;
+    if ProofHashes::new(&merklized_message.proof).is_none() {
+        return Err(GatewayError::InvalidMerkleProof.with_context(
+            GatewayErrorContext::MessageLeaf {
+                payload_merkle_root,
+                position: merklized_message.leaf.position,
+                set_size: merklized_message.leaf.set_size,
+            },
+        ));
+    }
 
     // Check: leaf node is part of the payload merkle root
-    if !proof.verify(
-        payload_merkle_root,
-        &[merklized_message.leaf.position.into()],
-        &[leaf_hash],
+    if !verify_single_leaf_proof(
+        &merklized_message.proof,
+        &payload_merkle_root,
+        merklized_message.leaf.position.into(),
         merklized_message.leaf.set_size.into(),
+        leaf_hash,
     ) {
-        return err!(GatewayError::LeafNodeNotPartOfMerkleRoot);
+        return Err(GatewayError::LeafNodeNotPartOfMerkleRoot.with_context(
+            GatewayErrorContext::MessageLeaf {
+                payload_merkle_root,
+                position: merklized_message.leaf.position,
+                set_size: merklized_message.leaf.set_size,
+            },
+        ));
     }
 
     let command_id = merklized_message.leaf.message.command_id();
 
-    // Calculate signing PDA bump
-    // If the destination address is an invalid pubkey, the message is
-    // practically invalid, so we default to bump 0 to not return an error
-    let signing_pda_bump = if let Ok(destination_address_pubkey) =
-        Pubkey::from_str(&merklized_message.leaf.message.destination_address)
-    {
-        // Create a new Signing PDA that is used for validating that a message has
-        // reached the destination program
-        let (_, bump) = ValidateMessageSigner::find_pda(&command_id, &destination_address_pubkey);
-        bump
-    } else {
-        0
-    };
+    let signing_pda_bump = signing_pda_bump(
+        &command_id,
+        &merklized_message.leaf.message.destination_address,
+    );
 
     // Store data in the PDA
     incoming_message_pda.bump = ctx.bumps.incoming_message_pda;
@@ -97,18 +113,45 @@ pub fn approve_message_handler(
     incoming_message_pda.status = MessageStatus::approved();
     incoming_message_pda.message_hash = message_hash;
     incoming_message_pda.payload_hash = merklized_message.leaf.message.payload_hash;
+    incoming_message_pda.funder = ctx.accounts.funder.key();
+    incoming_message_pda.approved_at = Clock::get()?.unix_timestamp;
+    incoming_message_pda.approval_ttl = gateway_config.message_approval_ttl;
 
-    let cc_id = &merklized_message.leaf.message.cc_id;
+    ctx.accounts
+        .verification_session_account
+        .load_mut()?
+        .record_approved_message(merklized_message.leaf.set_size);
 
-    emit_cpi!(MessageApprovedEvent {
+    emit_cpi!(message_approved_event(
         command_id,
-        destination_address: merklized_message.leaf.message.destination_address,
-        payload_hash: merklized_message.leaf.message.payload_hash,
-        source_chain: cc_id.chain.clone(),
-        cc_id: cc_id.id.clone(),
-        source_address: merklized_message.leaf.message.source_address.clone(),
-        destination_chain: merklized_message.leaf.message.destination_chain.clone(),
-    });
+        merklized_message.leaf.message
+    ));
 
     Ok(())
 }
+
+/// Calculates the bump of the signing PDA used for validating that a message
+/// has reached its destination program.
+///
+/// If the destination address is an invalid pubkey, the message is practically
+/// invalid, so we default to bump 0 to not return an error.
+pub(crate) fn signing_pda_bump(command_id: &[u8; 32], destination_address: &str) -> u8 {
+    Pubkey::from_str(destination_address).map_or(0, |destination_address_pubkey| {
+        ValidateMessageSigner::find_pda(command_id, &destination_address_pubkey).1
+    })
+}
+
+pub(crate) fn message_approved_event(
+    command_id: [u8; 32],
+    message: solana_axelar_std::Message,
+) -> MessageApprovedEvent {
+    MessageApprovedEvent {
+        command_id,
+        destination_address: message.destination_address,
+        payload_hash: message.payload_hash,
+        source_chain: message.cc_id.chain,
+        cc_id: message.cc_id.id,
+        source_address: message.source_address,
+        destination_chain: message.destination_chain,
+    }
+}
@@ -0,0 +1,238 @@
+use crate::instructions::approve_message::{message_approved_event, signing_pda_bump};
+use crate::{
+    GatewayConfig, GatewayError, GatewayErrorContext, IncomingMessage, MessageStatus,
+    SignatureVerificationSessionData,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use solana_axelar_std::hasher::LeafHash;
+use solana_axelar_std::{MerklizedMessageBatch, PayloadType};
+
+#[derive(Accounts)]
+#[event_cpi]
+#[instruction(merklized_batch: MerklizedMessageBatch, payload_merkle_root: [u8; 32])]
+pub struct ApproveMessages<'info> {
+    #[account(
+        seeds = [GatewayConfig::SEED_PREFIX],
+        bump = gateway_root_pda.load()?.bump,
+        // Check: the gateway isn't paused
+        constraint = !gateway_root_pda.load()?.is_paused() @ GatewayError::GatewayPaused,
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SignatureVerificationSessionData::SEED_PREFIX,
+            payload_merkle_root.as_ref(),
+            &[PayloadType::ApproveMessages.into()],
+            verification_session_account.load()?.signature_verification.signing_verifier_set_hash.as_ref()
+        ],
+        bump = verification_session_account.load()?.bump,
+        // CHECK: Validate signature verification session is complete
+        constraint = verification_session_account.load()?.is_valid() @ GatewayError::SigningSessionNotValid
+    )]
+    pub verification_session_account: AccountLoader<'info, SignatureVerificationSessionData>,
+
+    pub system_program: Program<'info, System>,
+    // Remaining accounts: one writable `IncomingMessage` PDA per leaf, in the
+    // same order as `merklized_batch.leaves`.
+}
+
+/// Approves several messages from the same signed payload at once.
+///
+/// All leaves are checked against `payload_merkle_root` with a single
+/// multi-proof, after which an `IncomingMessage` PDA gets created for each of
+/// them from the remaining accounts.
+pub fn approve_messages_handler(
+    ctx: Context<ApproveMessages>,
+    merklized_batch: MerklizedMessageBatch,
+    payload_merkle_root: [u8; 32],
+) -> Result<()> {
+    msg!("Approving messages!");
+
+    let MerklizedMessageBatch { leaves, proof } = merklized_batch;
+
+    let Some(first_leaf) = leaves.first() else {
+        return err!(GatewayError::EmptyMessageBatch);
+    };
+    let set_size = first_leaf.set_size;
+
+    // Check: one incoming message PDA was provided for every leaf
+    if ctx.remaining_accounts.len() != leaves.len() {
+        return err!(GatewayError::InvalidMessageBatch);
+    }
+
+    let (domain_separator, approval_ttl) = {
+        let gateway_config = ctx.accounts.gateway_root_pda.load()?;
+        (
+            gateway_config.domain_separator,
+            gateway_config.message_approval_ttl,
+        )
+    };
+
+    let mut leaf_indices = Vec::with_capacity(leaves.len());
+    let mut leaf_hashes = Vec::with_capacity(leaves.len());
+    for leaf in &leaves {
+        // Validate domain separator matches gateway config
+        if leaf.domain_separator != domain_separator {
+            return Err(GatewayError::InvalidDomainSeparator.with_context(
+                GatewayErrorContext::DomainSeparator {
+                    expected: domain_separator,
+                    actual: leaf.domain_separator,
+                },
+            ));
+        }
+
+        // Check: all leaves come from the same set, ordered by strictly
+        // increasing positions so that none of them is duplicated
+        if leaf.set_size != set_size
+            || leaf_indices
+                .last()
+                .is_some_and(|previous| *previous >= usize::from(leaf.position))
+        {
+            return err!(GatewayError::InvalidMessageBatch);
+        }
+
+        leaf_indices.push(usize::from(leaf.position));
+        leaf_hashes.push(leaf.hash());
+    }
+
+    let batch_context = || GatewayErrorContext::MessageBatch {
+        payload_merkle_root,
+        positions: leaves.iter().map(|leaf| leaf.position).collect(),
+        set_size,
+    };
+
+    let proof = solana_axelar_std::MerkleProof::from_bytes(&proof)
+        .map_err(|_err| GatewayError::InvalidMerkleProof.with_context(batch_context()))?;
+
+    // Check: all leaf nodes are part of the payload merkle root
+    if !proof.verify(
+        payload_merkle_root,
+        &leaf_indices,
+        &leaf_hashes,
+        set_size.into(),
+    ) {
+        return Err(GatewayError::LeafNodeNotPartOfMerkleRoot.with_context(batch_context()));
+    }
+
+    let approved_at = Clock::get()?.unix_timestamp;
+    for (leaf, incoming_message_pda) in leaves.into_iter().zip(ctx.remaining_accounts) {
+        let command_id = leaf.message.command_id();
+
+        let (expected_pda, bump) = IncomingMessage::find_pda(&command_id);
+        if incoming_message_pda.key() != expected_pda {
+            return err!(GatewayError::InvalidIncomingMessagePDA);
+        }
+
+        create_incoming_message_pda(
+            &ctx.accounts.funder,
+            incoming_message_pda,
+            &ctx.accounts.system_program,
+            &command_id,
+            bump,
+        )?;
+
+        {
+            let mut data = incoming_message_pda.try_borrow_mut_data()?;
+            let (discriminator, incoming_message) =
+                data.split_at_mut(IncomingMessage::DISCRIMINATOR.len());
+            discriminator.copy_from_slice(IncomingMessage::DISCRIMINATOR);
+
+            let incoming_message = bytemuck::from_bytes_mut::<IncomingMessage>(incoming_message);
+            incoming_message.bump = bump;
+            incoming_message.signing_pda_bump =
+                signing_pda_bump(&command_id, &leaf.message.destination_address);
+            incoming_message.status = MessageStatus::approved();
+            incoming_message.message_hash = leaf.message.hash();
+            incoming_message.payload_hash = leaf.message.payload_hash;
+            incoming_message.funder = ctx.accounts.funder.key();
+            incoming_message.approved_at = approved_at;
+            incoming_message.approval_ttl = approval_ttl;
+        }
+
+        ctx.accounts
+            .verification_session_account
+            .load_mut()?
+            .record_approved_message(set_size);
+
+        emit_cpi!(message_approved_event(command_id, leaf.message));
+    }
+
+    Ok(())
+}
+
+/// Allocates an `IncomingMessage` PDA owned by the gateway, paid for by the
+/// funder.
+///
+/// Mirrors what `#[account(init)]` does, including for PDAs which already hold
+/// lamports. PDAs which are already allocated (approved or closed messages)
+/// are rejected by the system program.
+fn create_incoming_message_pda<'info>(
+    funder: &Signer<'info>,
+    incoming_message_pda: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    command_id: &[u8; 32],
+    bump: u8,
+) -> Result<()> {
+    let space = IncomingMessage::SPACE;
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let signer_seeds: &[&[&[u8]]] = &[&[IncomingMessage::SEED_PREFIX, command_id, &[bump]]];
+
+    let current_lamports = incoming_message_pda.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.key(),
+                system_program::CreateAccount {
+                    from: funder.to_account_info(),
+                    to: incoming_message_pda.clone(),
+                },
+                signer_seeds,
+            ),
+            rent_exempt_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let required_lamports = rent_exempt_lamports.saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.key(),
+                system_program::Transfer {
+                    from: funder.to_account_info(),
+                    to: incoming_message_pda.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.key(),
+            system_program::Allocate {
+                account_to_allocate: incoming_message_pda.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.key(),
+            system_program::Assign {
+                account_to_assign: incoming_message_pda.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
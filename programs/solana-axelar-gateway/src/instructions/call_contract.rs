@@ -1,9 +1,14 @@
 use crate::seed_prefixes::GATEWAY_SEED;
-use crate::{CallContractEvent, CallContractSigner, GatewayConfig, GatewayError};
+use crate::{
+    CallContractEvent, CallContractNonce, CallContractSigner, DestinationChainRegistry,
+    GatewayConfig, GatewayError,
+};
 use anchor_lang::prelude::*;
 
+/// The longest chain name accepted by Amplifier.
+pub const MAX_DESTINATION_CHAIN_LEN: usize = 20;
+
 #[derive(Accounts)]
-#[event_cpi]
 pub struct CallContract<'info> {
     /// The program that wants to call us - can be a direct signer or program
     /// CHECK: We validate the caller using is_signer flag and signing PDA verification
@@ -15,9 +20,38 @@ pub struct CallContract<'info> {
     /// The gateway configuration PDA (read-only)
     #[account(
         seeds = [GATEWAY_SEED],
-        bump = gateway_root_pda.load()?.bump
+        bump = gateway_root_pda.load()?.bump,
+        // Check: the gateway isn't paused
+        constraint = !gateway_root_pda.load()?.is_paused() @ GatewayError::GatewayPaused,
     )]
     pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    // The event CPI accounts are declared by hand instead of with
+    // `#[event_cpi]`, which would place them after the accounts below and
+    // break the layout existing callers rely on.
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: AccountInfo<'info>,
+
+    /// The destination chain registry, required once it has been
+    /// initialized. Calls to chains that aren't registered are rejected.
+    #[account(
+        seeds = [DestinationChainRegistry::SEED_PREFIX],
+        bump = destination_chain_registry.bump,
+    )]
+    pub destination_chain_registry: Option<Account<'info, DestinationChainRegistry>>,
+
+    /// The caller's outbound nonce. When provided, the emitted event carries
+    /// the nonce of the message.
+    #[account(
+        mut,
+        seeds = [CallContractNonce::SEED_PREFIX, caller.key().as_ref()],
+        bump = call_contract_nonce.bump,
+    )]
+    pub call_contract_nonce: Option<Account<'info, CallContractNonce>>,
 }
 
 pub fn call_contract_handler(
@@ -28,8 +62,43 @@ pub fn call_contract_handler(
     signing_pda_bump: u8,
 ) -> Result<()> {
     let caller = &ctx.accounts.caller;
-    let signing_pda = &ctx.accounts.signing_pda;
 
+    validate_caller(caller, ctx.accounts.signing_pda.as_ref(), signing_pda_bump)?;
+    validate_destination_chain(
+        &*ctx.accounts.gateway_root_pda.load()?,
+        ctx.accounts.destination_chain_registry.as_ref(),
+        &destination_chain,
+    )?;
+
+    let payload_hash = solana_keccak_hasher::hash(&payload);
+
+    let nonce = ctx
+        .accounts
+        .call_contract_nonce
+        .as_mut()
+        .map(|call_contract_nonce| call_contract_nonce.advance())
+        .transpose()?;
+
+    emit_cpi!(CallContractEvent {
+        sender: caller.key(),
+        payload_hash: payload_hash.to_bytes(),
+        destination_chain,
+        destination_contract_address,
+        payload,
+        nonce,
+    });
+
+    Ok(())
+}
+
+/// Checks that the call was authorized by `caller`: either it signed the
+/// transaction directly, or it is a program that signed with its
+/// [`CallContractSigner`] PDA.
+pub(crate) fn validate_caller(
+    caller: &AccountInfo<'_>,
+    signing_pda: Option<&Signer<'_>>,
+    signing_pda_bump: u8,
+) -> Result<()> {
     if caller.is_signer {
         // Direct signer, so not a program, continue
     } else {
@@ -40,7 +109,7 @@ pub fn call_contract_handler(
             GatewayError::InvalidSigningPDABump
         })?;
 
-        let pda = signing_pda.as_ref().ok_or_else(|| {
+        let pda = signing_pda.ok_or_else(|| {
             msg!("Signing PDA must be provided when sender is a program");
             GatewayError::InvalidSigningPDA
         })?;
@@ -53,16 +122,29 @@ pub fn call_contract_handler(
     }
 
     // A valid signing PDA was provided and it's a signer, continue
+    Ok(())
+}
 
-    let payload_hash = solana_keccak_hasher::hash(&payload);
+/// Checks that the destination chain is a valid chain name and, once the
+/// registry has been initialized, that it is registered.
+pub(crate) fn validate_destination_chain(
+    config: &GatewayConfig,
+    destination_chain_registry: Option<&Account<'_, DestinationChainRegistry>>,
+    destination_chain: &str,
+) -> Result<()> {
+    require!(
+        !destination_chain.is_empty() && destination_chain.len() <= MAX_DESTINATION_CHAIN_LEN,
+        GatewayError::InvalidDestinationChain
+    );
 
-    emit_cpi!(CallContractEvent {
-        sender: caller.key(),
-        payload_hash: payload_hash.to_bytes(),
-        destination_chain,
-        destination_contract_address,
-        payload,
-    });
+    if config.has_destination_chain_registry() {
+        let destination_chain_registry = destination_chain_registry
+            .ok_or_else(|| error!(GatewayError::MissingDestinationChainRegistry))?;
+        require!(
+            destination_chain_registry.is_known_chain(destination_chain),
+            GatewayError::UnknownDestinationChain
+        );
+    }
 
     Ok(())
 }
@@ -0,0 +1,126 @@
+use crate::instructions::call_contract::{validate_caller, validate_destination_chain};
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{
+    CallContractEvent, CallContractNonce, CallContractPayloadBuffer, DestinationChainRegistry,
+    GatewayConfig, GatewayError,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(destination_chain: String, destination_contract_address: String, buffer_id: u64)]
+pub struct CallContractWithBuffer<'info> {
+    /// The program that wants to call us - can be a direct signer or program
+    /// CHECK: We validate the caller using is_signer flag and signing PDA verification
+    pub caller: UncheckedAccount<'info>,
+
+    /// The standardized PDA that must sign - derived from the calling program
+    pub signing_pda: Option<Signer<'info>>,
+
+    /// The gateway configuration PDA (read-only)
+    #[account(
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump,
+        // Check: the gateway isn't paused
+        constraint = !gateway_root_pda.load()?.is_paused() @ GatewayError::GatewayPaused,
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    /// The destination chain registry, required once it has been
+    /// initialized. Calls to chains that aren't registered are rejected.
+    #[account(
+        seeds = [DestinationChainRegistry::SEED_PREFIX],
+        bump = destination_chain_registry.bump,
+    )]
+    pub destination_chain_registry: Option<Account<'info, DestinationChainRegistry>>,
+
+    /// The buffer holding the payload, closed once it's emitted
+    #[account(
+        mut,
+        seeds = [
+            CallContractPayloadBuffer::SEED_PREFIX,
+            caller.key().as_ref(),
+            buffer_id.to_le_bytes().as_ref(),
+        ],
+        bump = payload_buffer.bump,
+        close = payer,
+    )]
+    pub payload_buffer: Account<'info, CallContractPayloadBuffer>,
+
+    /// The account that paid for the buffer, receiving its rent back
+    /// CHECK: Checked against the payer recorded in the buffer
+    #[account(
+        mut,
+        address = payload_buffer.payer @ GatewayError::InvalidFunder,
+    )]
+    pub payer: UncheckedAccount<'info>,
+
+    // The event CPI accounts are declared by hand instead of with
+    // `#[event_cpi]`, so that the accounts below come after them as they do
+    // in `call_contract`.
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: AccountInfo<'info>,
+
+    /// The caller's outbound nonce. When provided, the emitted event carries
+    /// the nonce of the message.
+    #[account(
+        mut,
+        seeds = [CallContractNonce::SEED_PREFIX, caller.key().as_ref()],
+        bump = call_contract_nonce.bump,
+    )]
+    pub call_contract_nonce: Option<Account<'info, CallContractNonce>>,
+}
+
+/// Same as `call_contract`, but with the payload read from a buffer that was
+/// uploaded beforehand with `write_call_contract_buffer`.
+pub fn call_contract_with_buffer_handler(
+    ctx: Context<CallContractWithBuffer>,
+    destination_chain: String,
+    destination_contract_address: String,
+    _buffer_id: u64,
+    signing_pda_bump: u8,
+) -> Result<()> {
+    let caller = &ctx.accounts.caller;
+
+    validate_caller(caller, ctx.accounts.signing_pda.as_ref(), signing_pda_bump)?;
+    validate_destination_chain(
+        &*ctx.accounts.gateway_root_pda.load()?,
+        ctx.accounts.destination_chain_registry.as_ref(),
+        &destination_chain,
+    )?;
+
+    // Check: the event carrying the payload fits in the event CPI
+    if ctx
+        .accounts
+        .payload_buffer
+        .event_data_len(&destination_chain, &destination_contract_address)
+        > CallContractPayloadBuffer::MAX_EVENT_DATA_LEN
+    {
+        return err!(GatewayError::CallContractEventTooLarge);
+    }
+
+    // The buffer gets closed, so its payload can be moved out of it
+    let payload = std::mem::take(&mut ctx.accounts.payload_buffer.payload);
+    let payload_hash = solana_keccak_hasher::hash(&payload);
+
+    let nonce = ctx
+        .accounts
+        .call_contract_nonce
+        .as_mut()
+        .map(|call_contract_nonce| call_contract_nonce.advance())
+        .transpose()?;
+
+    emit_cpi!(CallContractEvent {
+        sender: caller.key(),
+        payload_hash: payload_hash.to_bytes(),
+        destination_chain,
+        destination_contract_address,
+        payload,
+        nonce,
+    });
+
+    Ok(())
+}
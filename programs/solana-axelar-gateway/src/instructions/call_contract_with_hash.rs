@@ -0,0 +1,52 @@
+use crate::instructions::call_contract::{
+    validate_caller, validate_destination_chain, CallContract,
+};
+use crate::{CallContractWithHashEvent, GatewayError};
+use anchor_lang::prelude::*;
+
+/// The longest payload location accepted, e.g. a URI or a content identifier.
+pub const MAX_PAYLOAD_LOCATION_LEN: usize = 256;
+
+/// Same as `call_contract`, but for a payload of any size that isn't emitted
+/// on-chain. Only its hash is emitted, along with `payload_location`, where the
+/// relayer can retrieve the payload from and check it against the hash.
+pub fn call_contract_with_hash_handler(
+    ctx: Context<CallContract>,
+    destination_chain: String,
+    destination_contract_address: String,
+    payload_hash: [u8; 32],
+    payload_location: String,
+    signing_pda_bump: u8,
+) -> Result<()> {
+    let caller = &ctx.accounts.caller;
+
+    validate_caller(caller, ctx.accounts.signing_pda.as_ref(), signing_pda_bump)?;
+    validate_destination_chain(
+        &*ctx.accounts.gateway_root_pda.load()?,
+        ctx.accounts.destination_chain_registry.as_ref(),
+        &destination_chain,
+    )?;
+
+    require!(
+        !payload_location.is_empty() && payload_location.len() <= MAX_PAYLOAD_LOCATION_LEN,
+        GatewayError::InvalidPayloadLocation
+    );
+
+    let nonce = ctx
+        .accounts
+        .call_contract_nonce
+        .as_mut()
+        .map(|call_contract_nonce| call_contract_nonce.advance())
+        .transpose()?;
+
+    emit_cpi!(CallContractWithHashEvent {
+        sender: caller.key(),
+        payload_hash,
+        destination_chain,
+        destination_contract_address,
+        payload_location,
+        nonce,
+    });
+
+    Ok(())
+}
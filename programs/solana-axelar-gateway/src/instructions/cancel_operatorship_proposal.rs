@@ -0,0 +1,63 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{
+    GatewayConfig, GatewayError, OperatorshipProposal, OperatorshipProposalCancelledEvent,
+};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelOperatorshipProposal<'info> {
+    #[account(
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        // CHECK: This is either the current operator or the upgrade authority
+        constraint = gateway_root_pda.load()?.operator == *operator_or_upgrade_authority.key
+            || program_data.upgrade_authority_address == Some(*operator_or_upgrade_authority.key)
+            @ GatewayError::InvalidOperatorOrAuthorityAccount
+    )]
+    pub operator_or_upgrade_authority: Signer<'info>,
+
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        mut,
+        seeds = [
+            OperatorshipProposal::SEED_PREFIX,
+            operatorship_proposal.current_operator.as_ref(),
+            operatorship_proposal.proposed_operator.as_ref(),
+        ],
+        bump = operatorship_proposal.bump,
+        close = payer,
+    )]
+    pub operatorship_proposal: Account<'info, OperatorshipProposal>,
+
+    /// CHECK: Must match the payer recorded when the operatorship was proposed
+    #[account(
+        mut,
+        address = operatorship_proposal.payer @ GatewayError::InvalidFunder,
+    )]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Cancels a pending operatorship proposal, including stale ones made for a
+/// previous operator.
+pub fn cancel_operatorship_proposal_handler(
+    ctx: Context<CancelOperatorshipProposal>,
+) -> Result<()> {
+    emit_cpi!(OperatorshipProposalCancelledEvent {
+        proposed_operator: ctx.accounts.operatorship_proposal.proposed_operator,
+    });
+
+    Ok(())
+}
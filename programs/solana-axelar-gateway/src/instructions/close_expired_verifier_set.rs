@@ -0,0 +1,123 @@
+use crate::seed_prefixes::{GATEWAY_SEED, VERIFIER_SET_TRACKER_SEED};
+use crate::{
+    GatewayConfig, GatewayError, VerifierSetClosedEvent, VerifierSetEpoch, VerifierSetTracker,
+    VerifierSetTrackerTombstone,
+};
+use anchor_lang::prelude::*;
+use solana_axelar_std::transfer_lamports_anchor;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CloseExpiredVerifierSet<'info> {
+    #[account(
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    /// CHECK: Deserialized and validated in the handler. An `AccountLoader` can't be
+    /// used here, as it would restore the `VerifierSetTracker` discriminator on exit.
+    #[account(mut, owner = crate::ID)]
+    pub verifier_set_tracker_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Must match the rent recipient configured in the gateway config
+    #[account(
+        mut,
+        address = gateway_root_pda.load()?.rent_recipient() @ GatewayError::InvalidRentRecipient
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in the handler against the epoch of the tracker. It may
+    /// not exist for verifier sets rotated in before the epoch history existed.
+    #[account(mut)]
+    pub verifier_set_epoch_pda: UncheckedAccount<'info>,
+}
+
+/// Closes the `VerifierSetTracker` of a verifier set that can no longer sign,
+/// along with its `VerifierSetEpoch` record, refunding their rent to the
+/// configured rent recipient.
+///
+/// Anyone can close an expired tracker. The PDA is shrunk into a
+/// `VerifierSetTrackerTombstone` rather than fully closed, so that the same
+/// verifier set can never be rotated in again.
+pub fn close_expired_verifier_set_handler(ctx: Context<CloseExpiredVerifierSet>) -> Result<()> {
+    let verifier_set_tracker_pda = &ctx.accounts.verifier_set_tracker_pda;
+
+    let tracker = {
+        let data = verifier_set_tracker_pda.try_borrow_data()?;
+        VerifierSetTracker::try_deserialize(&mut &data[..])?
+    };
+
+    // Check: the account is the canonical tracker PDA of its verifier set
+    let expected_pda = Pubkey::create_program_address(
+        &[
+            VERIFIER_SET_TRACKER_SEED,
+            tracker.verifier_set_hash.as_ref(),
+            &[tracker.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| GatewayError::InvalidVerifierSetTrackerProvided)?;
+    if expected_pda != verifier_set_tracker_pda.key() {
+        return err!(GatewayError::InvalidVerifierSetTrackerProvided);
+    }
+
+    // Check: current_epoch - epoch >= previous_verifier_set_retention
+    if !ctx
+        .accounts
+        .gateway_root_pda
+        .load()?
+        .is_epoch_expired(tracker.epoch)
+    {
+        return err!(GatewayError::VerifierSetNotExpired);
+    }
+
+    // Check: the account is the epoch record of the verifier set
+    let verifier_set_epoch_pda = &ctx.accounts.verifier_set_epoch_pda;
+    if verifier_set_epoch_pda.key() != VerifierSetEpoch::find_pda(tracker.epoch).0 {
+        return err!(GatewayError::InvalidVerifierSetEpochProvided);
+    }
+
+    // Close the epoch record, if it was ever recorded
+    if verifier_set_epoch_pda.owner == &crate::ID {
+        let lamports = verifier_set_epoch_pda.get_lamports();
+        transfer_lamports_anchor!(
+            verifier_set_epoch_pda,
+            ctx.accounts.rent_recipient,
+            lamports
+        );
+        verifier_set_epoch_pda.assign(&System::id());
+        verifier_set_epoch_pda.resize(0)?;
+    }
+
+    // Rewrite the account into a tombstone
+    verifier_set_tracker_pda.resize(VerifierSetTrackerTombstone::SPACE)?;
+    {
+        let mut data = verifier_set_tracker_pda.try_borrow_mut_data()?;
+        let (discriminator, tombstone) =
+            data.split_at_mut(VerifierSetTrackerTombstone::DISCRIMINATOR.len());
+        discriminator.copy_from_slice(VerifierSetTrackerTombstone::DISCRIMINATOR);
+        *bytemuck::from_bytes_mut::<VerifierSetTrackerTombstone>(tombstone) =
+            VerifierSetTrackerTombstone { bump: tracker.bump };
+    }
+
+    // Refund everything above the tombstone's rent exemption
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(VerifierSetTrackerTombstone::SPACE);
+    let refund = verifier_set_tracker_pda
+        .get_lamports()
+        .saturating_sub(rent_exempt_lamports);
+
+    transfer_lamports_anchor!(
+        verifier_set_tracker_pda,
+        ctx.accounts.rent_recipient,
+        refund
+    );
+
+    emit_cpi!(VerifierSetClosedEvent {
+        verifier_set_hash: tracker.verifier_set_hash,
+        epoch: tracker.epoch,
+        rent_recipient: ctx.accounts.rent_recipient.key(),
+    });
+
+    Ok(())
+}
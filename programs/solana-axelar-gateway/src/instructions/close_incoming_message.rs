@@ -0,0 +1,69 @@
+use crate::{GatewayError, IncomingMessage, IncomingMessageTombstone};
+use anchor_lang::prelude::*;
+use solana_axelar_std::transfer_lamports_anchor;
+
+#[derive(Accounts)]
+pub struct CloseIncomingMessage<'info> {
+    /// CHECK: Deserialized and validated in the handler. An `AccountLoader` can't be
+    /// used here, as it would restore the `IncomingMessage` discriminator on exit.
+    #[account(mut, owner = crate::ID)]
+    pub incoming_message_pda: UncheckedAccount<'info>,
+
+    /// The funder recorded when the message was approved. It must sign, so
+    /// that nobody else can close the PDA before its execution receipt is
+    /// recorded.
+    #[account(mut)]
+    pub funder: Signer<'info>,
+}
+
+/// Closes an executed, revoked or expired `IncomingMessage` PDA, refunding its
+/// rent to the account that paid for the approval. Only that account can
+/// close it.
+///
+/// The PDA is shrunk into an `IncomingMessageTombstone` rather than fully
+/// closed, so that the same command id can never be approved again.
+pub fn close_incoming_message_handler(ctx: Context<CloseIncomingMessage>) -> Result<()> {
+    let incoming_message_pda = &ctx.accounts.incoming_message_pda;
+
+    let bump = {
+        let data = incoming_message_pda.try_borrow_data()?;
+        let incoming_message = IncomingMessage::try_deserialize(&mut &data[..])?;
+
+        // Check: message was already executed, or its approval was revoked or
+        // has expired
+        if !incoming_message.status.is_executed()
+            && !incoming_message.status.is_revoked()
+            && !incoming_message.is_approval_expired(Clock::get()?.unix_timestamp)
+        {
+            return err!(GatewayError::MessageNotExecuted);
+        }
+
+        // Check: rent goes back to whoever paid for the approval
+        if incoming_message.funder != ctx.accounts.funder.key() {
+            return err!(GatewayError::InvalidFunder);
+        }
+
+        incoming_message.bump
+    };
+
+    // Rewrite the account into a tombstone
+    incoming_message_pda.resize(IncomingMessageTombstone::SPACE)?;
+    {
+        let mut data = incoming_message_pda.try_borrow_mut_data()?;
+        let (discriminator, tombstone) =
+            data.split_at_mut(IncomingMessageTombstone::DISCRIMINATOR.len());
+        discriminator.copy_from_slice(IncomingMessageTombstone::DISCRIMINATOR);
+        *bytemuck::from_bytes_mut::<IncomingMessageTombstone>(tombstone) =
+            IncomingMessageTombstone { bump };
+    }
+
+    // Refund everything above the tombstone's rent exemption
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(IncomingMessageTombstone::SPACE);
+    let refund = incoming_message_pda
+        .get_lamports()
+        .saturating_sub(rent_exempt_lamports);
+
+    transfer_lamports_anchor!(incoming_message_pda, ctx.accounts.funder, refund);
+
+    Ok(())
+}
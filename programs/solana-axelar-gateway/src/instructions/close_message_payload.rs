@@ -0,0 +1,34 @@
+use crate::{GatewayError, MessagePayload};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(command_id: [u8; 32])]
+pub struct CloseMessagePayload<'info> {
+    #[account(
+        mut,
+        // CHECK: rent goes back to whoever paid for the buffer
+        address = message_payload.payer @ GatewayError::InvalidFunder,
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MessagePayload::SEED_PREFIX,
+            command_id.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = message_payload.bump,
+        close = payer,
+    )]
+    pub message_payload: Account<'info, MessagePayload>,
+}
+
+/// Closes a payload buffer, refunding its rent to the account that paid for
+/// it. Meant to be called once the message was executed.
+pub fn close_message_payload_handler(
+    _ctx: Context<CloseMessagePayload>,
+    _command_id: [u8; 32],
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::{
+    GatewayConfig, GatewayError, SignatureVerificationSessionData, VerifierSetTracker,
+    VerifierSetTrackerTombstone,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseVerificationSession<'info> {
+    #[account(
+        seeds = [GatewayConfig::SEED_PREFIX],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        mut,
+        close = payer,
+        // CHECK: Only completed sessions can be closed
+        constraint = verification_session_account.load()?.is_valid()
+            @ GatewayError::SigningSessionNotValid,
+        // CHECK: Rent goes back to whoever paid for the session
+        constraint = verification_session_account.load()?.payer == payer.key()
+            @ GatewayError::InvalidFunder,
+    )]
+    pub verification_session_account: AccountLoader<'info, SignatureVerificationSessionData>,
+
+    /// CHECK: Either the tracker of the signing verifier set, or its tombstone
+    /// once the expired tracker was closed. Deserialized in the handler.
+    #[account(
+        seeds = [
+            VerifierSetTracker::SEED_PREFIX,
+            verification_session_account.load()?.signature_verification
+                .signing_verifier_set_hash.as_ref()
+        ],
+        bump,
+        owner = crate::ID,
+    )]
+    pub verifier_set_tracker_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Must match the payer recorded when the session was initialized
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Closes a completed signature verification session, refunding its rent to
+/// the account that initialized it.
+///
+/// A session can only be closed once it can no longer be of use, which is
+/// when every message from the signed batch has been approved, or when the
+/// signing verifier set has expired, its tracker possibly being closed already.
+pub fn close_verification_session_handler(ctx: Context<CloseVerificationSession>) -> Result<()> {
+    let gateway_config = ctx.accounts.gateway_root_pda.load()?;
+    let verification_session = ctx.accounts.verification_session_account.load()?;
+    let verifier_set_tracker_data = ctx.accounts.verifier_set_tracker_pda.try_borrow_data()?;
+
+    let all_messages_approved = verification_session.all_messages_approved();
+    // Trackers are only closed once their verifier set expired
+    let verifier_set_expired =
+        if verifier_set_tracker_data.starts_with(VerifierSetTrackerTombstone::DISCRIMINATOR) {
+            true
+        } else {
+            let verifier_set_tracker =
+                VerifierSetTracker::try_deserialize(&mut &verifier_set_tracker_data[..])?;
+            gateway_config.is_epoch_expired(verifier_set_tracker.epoch)
+        };
+
+    if !all_messages_approved && !verifier_set_expired {
+        return err!(GatewayError::VerificationSessionInUse);
+    }
+
+    Ok(())
+}
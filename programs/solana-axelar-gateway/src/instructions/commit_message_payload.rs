@@ -0,0 +1,49 @@
+use crate::{GatewayError, IncomingMessage, MessagePayload};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(command_id: [u8; 32])]
+pub struct CommitMessagePayload<'info> {
+    #[account(
+        // CHECK: only the account that paid for the buffer can commit it
+        address = message_payload.payer @ GatewayError::InvalidFunder,
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [IncomingMessage::SEED_PREFIX, command_id.as_ref()],
+        bump = incoming_message_pda.load()?.bump,
+    )]
+    pub incoming_message_pda: AccountLoader<'info, IncomingMessage>,
+
+    #[account(
+        mut,
+        seeds = [
+            MessagePayload::SEED_PREFIX,
+            command_id.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = message_payload.bump,
+        // CHECK: committed payloads are final
+        constraint = !message_payload.committed @ GatewayError::MessagePayloadAlreadyCommitted,
+    )]
+    pub message_payload: Account<'info, MessagePayload>,
+}
+
+/// Marks a fully uploaded payload as committed, after checking it against the
+/// payload hash of the approved message.
+pub fn commit_message_payload_handler(
+    ctx: Context<CommitMessagePayload>,
+    _command_id: [u8; 32],
+) -> Result<()> {
+    let message_payload = &mut ctx.accounts.message_payload;
+
+    let payload_hash = solana_keccak_hasher::hash(&message_payload.payload).to_bytes();
+    if payload_hash != ctx.accounts.incoming_message_pda.load()?.payload_hash {
+        return err!(GatewayError::MessagePayloadHashMismatch);
+    }
+
+    message_payload.committed = true;
+
+    Ok(())
+}
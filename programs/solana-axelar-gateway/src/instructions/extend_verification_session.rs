@@ -0,0 +1,46 @@
+#![allow(clippy::empty_structs_with_brackets)]
+use crate::SignatureVerificationSessionData;
+use anchor_lang::prelude::*;
+use solana_axelar_std::PayloadType;
+
+#[derive(Accounts)]
+#[instruction(payload_merkle_root: [u8; 32], payload_type: PayloadType, set_size: u16)]
+pub struct ExtendVerificationSession<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Sessions never shrink, so the signature slots that were already
+    /// verified are kept.
+    #[account(
+        mut,
+        seeds = [
+            SignatureVerificationSessionData::SEED_PREFIX,
+            payload_merkle_root.as_ref(),
+            &[payload_type.into()],
+            verification_session_account.load()?.signature_verification.signing_verifier_set_hash.as_ref(),
+        ],
+        bump = verification_session_account.load()?.bump,
+        realloc = SignatureVerificationSessionData::space_for(set_size)
+            .max(verification_session_account.to_account_info().data_len()),
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub verification_session_account: AccountLoader<'info, SignatureVerificationSessionData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Makes room in a verification session for the signature slots of a
+/// verifier set with `set_size` verifiers.
+///
+/// Only needed for verifier sets larger than
+/// [`SignatureVerificationSessionData::INLINE_SIGNATURE_SLOTS`]. The additional
+/// rent is refunded to the session's payer once the session gets closed.
+pub fn extend_verification_session_handler(
+    _ctx: Context<ExtendVerificationSession>,
+    _payload_merkle_root: [u8; 32],
+    _payload_type: PayloadType,
+    _set_size: u16,
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::instructions::call_contract::validate_caller;
+use crate::{CallContractPayloadBuffer, GatewayError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(buffer_id: u64, payload_size: u32)]
+pub struct InitializeCallContractBuffer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The sender the payload will be emitted for - can be a direct signer or program
+    /// CHECK: We validate the caller using is_signer flag and signing PDA verification
+    pub caller: UncheckedAccount<'info>,
+
+    /// The standardized PDA that must sign - derived from the calling program
+    pub signing_pda: Option<Signer<'info>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CallContractPayloadBuffer::space_for(payload_size as usize),
+        seeds = [
+            CallContractPayloadBuffer::SEED_PREFIX,
+            caller.key().as_ref(),
+            buffer_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub payload_buffer: Account<'info, CallContractPayloadBuffer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a zeroed payload buffer of `payload_size` bytes for the caller.
+pub fn initialize_call_contract_buffer_handler(
+    ctx: Context<InitializeCallContractBuffer>,
+    _buffer_id: u64,
+    payload_size: u32,
+    signing_pda_bump: u8,
+) -> Result<()> {
+    validate_caller(
+        &ctx.accounts.caller,
+        ctx.accounts.signing_pda.as_ref(),
+        signing_pda_bump,
+    )?;
+
+    let payload_size = payload_size as usize;
+    if payload_size > CallContractPayloadBuffer::MAX_PAYLOAD_SIZE {
+        return err!(GatewayError::PayloadBufferTooLarge);
+    }
+
+    ctx.accounts
+        .payload_buffer
+        .set_inner(CallContractPayloadBuffer {
+            sender: ctx.accounts.caller.key(),
+            payer: ctx.accounts.payer.key(),
+            bump: ctx.bumps.payload_buffer,
+            payload: vec![0; payload_size],
+        });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::CallContractNonce;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeCallContractNonce<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The sender the nonce is kept for - can be a direct signer or program
+    /// CHECK: Any account can have its messages counted, the counter only
+    /// ever gets incremented by `call_contract` calls it authorized.
+    pub caller: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CallContractNonce::DISCRIMINATOR.len() + CallContractNonce::INIT_SPACE,
+        seeds = [CallContractNonce::SEED_PREFIX, caller.key().as_ref()],
+        bump,
+    )]
+    pub call_contract_nonce: Account<'info, CallContractNonce>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the outbound nonce of the caller, starting at zero.
+pub fn initialize_call_contract_nonce_handler(
+    ctx: Context<InitializeCallContractNonce>,
+) -> Result<()> {
+    ctx.accounts
+        .call_contract_nonce
+        .set_inner(CallContractNonce {
+            next_nonce: 0,
+            bump: ctx.bumps.call_contract_nonce,
+        });
+
+    Ok(())
+}
@@ -1,8 +1,8 @@
 #![allow(clippy::missing_asserts_for_indexing)]
-use crate::seed_prefixes::{GATEWAY_SEED, VERIFIER_SET_TRACKER_SEED};
+use crate::seed_prefixes::{GATEWAY_SEED, VERIFIER_SET_EPOCH_SEED, VERIFIER_SET_TRACKER_SEED};
 use crate::{
     state::config::{GatewayConfig, InitializeConfigParams},
-    GatewayError, VerifierSetTracker,
+    GatewayError, VerifierSetEpoch, VerifierSetTracker,
 };
 use anchor_lang::prelude::*;
 use solana_axelar_std::U256;
@@ -29,7 +29,7 @@ pub struct InitializeConfig<'info> {
     #[account(
         init,
         payer = payer,
-        space = GatewayConfig::DISCRIMINATOR.len() + std::mem::size_of::<GatewayConfig>(),
+        space = GatewayConfig::SPACE,
         seeds = [GATEWAY_SEED],
         bump
     )]
@@ -48,6 +48,17 @@ pub struct InitializeConfig<'info> {
 	    bump
 	)]
     pub verifier_set_tracker_pda: AccountLoader<'info, VerifierSetTracker>,
+
+    /// The epoch record of the initial verifier set. When it isn't provided,
+    /// the record can be created afterwards with `record_verifier_set_epoch`.
+    #[account(
+        init,
+        payer = payer,
+        space = VerifierSetEpoch::DISCRIMINATOR.len() + std::mem::size_of::<VerifierSetEpoch>(),
+        seeds = [VERIFIER_SET_EPOCH_SEED, &U256::ONE.to_le_bytes()],
+        bump
+    )]
+    pub verifier_set_epoch_pda: Option<AccountLoader<'info, VerifierSetEpoch>>,
 }
 
 #[allow(clippy::cast_sign_loss)]
@@ -68,6 +79,7 @@ pub fn initialize_config_handler(
     config.operator = params.operator;
     config.domain_separator = params.domain_separator;
     config.bump = ctx.bumps.gateway_root_pda;
+    config.version = GatewayConfig::VERSION;
 
     let set_tracker = &mut ctx.accounts.verifier_set_tracker_pda.load_init()?;
 
@@ -76,5 +88,16 @@ pub fn initialize_config_handler(
     set_tracker.epoch = U256::from(1u64);
     set_tracker.verifier_set_hash = params.initial_verifier_set.hash;
 
+    // Record the initial verifier set as the one of the first epoch, if requested
+    if let (Some(verifier_set_epoch_pda), Some(bump)) = (
+        ctx.accounts.verifier_set_epoch_pda.as_ref(),
+        ctx.bumps.verifier_set_epoch_pda,
+    ) {
+        let set_epoch = &mut verifier_set_epoch_pda.load_init()?;
+        set_epoch.bump = bump;
+        set_epoch.epoch = U256::ONE;
+        set_epoch.verifier_set_hash = params.initial_verifier_set.hash;
+    }
+
     Ok(())
 }
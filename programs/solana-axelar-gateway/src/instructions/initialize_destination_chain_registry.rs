@@ -0,0 +1,63 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{DestinationChainRegistry, GatewayConfig, GatewayError};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+pub struct InitializeDestinationChainRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        mut,
+    	// CHECK: This is either the current operator or the upgrade authority
+		constraint = gateway_root_pda.load()?.operator == *operator_or_upgrade_authority.key
+			|| program_data.upgrade_authority_address == Some(*operator_or_upgrade_authority.key)
+			@ GatewayError::InvalidOperatorOrAuthorityAccount
+    )]
+    pub operator_or_upgrade_authority: Signer<'info>,
+
+    #[account(
+	    seeds = [crate::ID.as_ref()],
+	    bump,
+	    seeds::program = bpf_loader_upgradeable::ID,
+	)]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        init,
+        payer = operator_or_upgrade_authority,
+        space = DestinationChainRegistry::space_for(0),
+        seeds = [DestinationChainRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub destination_chain_registry: Account<'info, DestinationChainRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the empty destination chain registry.
+///
+/// From then on `call_contract` requires the registry, so until chains are
+/// added to it every call is rejected.
+pub fn initialize_destination_chain_registry_handler(
+    ctx: Context<InitializeDestinationChainRegistry>,
+) -> Result<()> {
+    ctx.accounts
+        .destination_chain_registry
+        .set_inner(DestinationChainRegistry {
+            chains: Vec::new(),
+            bump: ctx.bumps.destination_chain_registry,
+        });
+    ctx.accounts
+        .gateway_root_pda
+        .load_mut()?
+        .destination_chain_registry = 1;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::{GatewayError, IncomingMessage, MessagePayload};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(command_id: [u8; 32], payload_size: u32)]
+pub struct InitializeMessagePayload<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [IncomingMessage::SEED_PREFIX, command_id.as_ref()],
+        bump = incoming_message_pda.load()?.bump,
+        // CHECK: payloads are only uploaded for messages awaiting execution
+        constraint = incoming_message_pda.load()?.status.is_approved()
+            @ GatewayError::MessageNotApproved,
+    )]
+    pub incoming_message_pda: AccountLoader<'info, IncomingMessage>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MessagePayload::space_for(payload_size as usize),
+        seeds = [
+            MessagePayload::SEED_PREFIX,
+            command_id.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub message_payload: Account<'info, MessagePayload>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a zeroed payload buffer of `payload_size` bytes for an approved
+/// message.
+pub fn initialize_message_payload_handler(
+    ctx: Context<InitializeMessagePayload>,
+    _command_id: [u8; 32],
+    payload_size: u32,
+) -> Result<()> {
+    let payload_size = payload_size as usize;
+    if payload_size > MessagePayload::MAX_PAYLOAD_SIZE {
+        return err!(GatewayError::PayloadBufferTooLarge);
+    }
+
+    ctx.accounts.message_payload.set_inner(MessagePayload {
+        payer: ctx.accounts.payer.key(),
+        bump: ctx.bumps.message_payload,
+        committed: false,
+        payload: vec![0; payload_size],
+    });
+
+    Ok(())
+}
@@ -20,7 +20,7 @@ pub struct InitializePayloadVerificationSession<'info> {
     #[account(
         init,
         payer = payer,
-        space = SignatureVerificationSessionData::DISCRIMINATOR.len() + std::mem::size_of::<SignatureVerificationSessionData>(),
+        space = SignatureVerificationSessionData::SPACE,
         seeds = [
             SignatureVerificationSessionData::SEED_PREFIX,
             merkle_root.as_ref(),
@@ -58,6 +58,7 @@ pub fn initialize_payload_verification_session_handler(
         .verifier_set_hash;
 
     verification_session_account.bump = ctx.bumps.verification_session_account;
+    verification_session_account.payer = ctx.accounts.payer.key();
     verification_session_account
         .signature_verification
         .signing_verifier_set_hash = signing_verifier_set_hash;
@@ -0,0 +1,42 @@
+use crate::SignatureBatchBuffer;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(buffer_id: u64, batch_size: u32)]
+pub struct InitializeSignatureBatchBuffer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        // Check: the batch fits, before the account gets allocated
+        space = SignatureBatchBuffer::checked_space_for(batch_size)?,
+        seeds = [
+            SignatureBatchBuffer::SEED_PREFIX,
+            payer.key().as_ref(),
+            buffer_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub signature_batch_buffer: Account<'info, SignatureBatchBuffer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a zeroed buffer of `batch_size` bytes for a signature batch.
+pub fn initialize_signature_batch_buffer_handler(
+    ctx: Context<InitializeSignatureBatchBuffer>,
+    _buffer_id: u64,
+    batch_size: u32,
+) -> Result<()> {
+    ctx.accounts
+        .signature_batch_buffer
+        .set_inner(SignatureBatchBuffer {
+            payer: ctx.accounts.payer.key(),
+            bump: ctx.bumps.signature_batch_buffer,
+            batch: vec![0; batch_size as usize],
+        });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayConfigMigratedEvent, GatewayError};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct MigrateConfig<'info> {
+    /// The bump is not read from the account, as older layouts can't be loaded
+    /// before they are reallocated.
+    #[account(
+        mut,
+        seeds = [GATEWAY_SEED],
+        bump,
+        realloc = GatewayConfig::SPACE,
+        realloc::payer = upgrade_authority,
+        realloc::zero = true,
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        // CHECK: upgrade authority in program_data matches the one passed as signer
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ GatewayError::InvalidUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates the `GatewayConfig` account to the current layout version.
+///
+/// The account is reallocated to the current size, with the upgrade authority
+/// paying for the additional rent. New fields start out zeroed.
+pub fn migrate_config_handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.gateway_root_pda.load_mut()?;
+
+    let previous_version = config.version;
+    if previous_version >= GatewayConfig::VERSION {
+        return err!(GatewayError::ConfigAlreadyMigrated);
+    }
+    config.version = GatewayConfig::VERSION;
+
+    emit_cpi!(GatewayConfigMigratedEvent {
+        previous_version,
+        version: GatewayConfig::VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayError, IncomingMessage};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(command_id: [u8; 32])]
+pub struct MigrateIncomingMessage<'info> {
+    /// CHECK: Validated in the handler. An `AccountLoader` can't be used here,
+    /// as version 0 accounts are too short to be loaded.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [IncomingMessage::SEED_PREFIX, command_id.as_ref()],
+        bump,
+    )]
+    pub incoming_message_pda: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump,
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates an `IncomingMessage` approved before `funder` and the following fields
+/// were appended to the current layout.
+///
+/// The account is reallocated to the current size, with the payer covering the
+/// additional rent. The funder of the original approval isn't known, so the
+/// rent is refunded to the gateway's rent recipient once the message is
+/// closed. The approval is timed from the migration, with the gateway's current
+/// approval TTL.
+pub fn migrate_incoming_message_handler(
+    ctx: Context<MigrateIncomingMessage>,
+    _command_id: [u8; 32],
+) -> Result<()> {
+    let incoming_message_pda = &ctx.accounts.incoming_message_pda;
+
+    // Check: the account is a version 0 `IncomingMessage`
+    {
+        let data = incoming_message_pda.try_borrow_data()?;
+        if !data.starts_with(IncomingMessage::DISCRIMINATOR) {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+        if data.len() != IncomingMessage::V0_SPACE {
+            return err!(GatewayError::IncomingMessageAlreadyMigrated);
+        }
+    }
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(IncomingMessage::SPACE);
+    let required_lamports = rent_exempt_lamports.saturating_sub(incoming_message_pda.lamports());
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.key(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: incoming_message_pda.to_account_info(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    let (rent_recipient, approval_ttl) = {
+        let gateway_config = ctx.accounts.gateway_root_pda.load()?;
+        (
+            gateway_config.rent_recipient(),
+            gateway_config.message_approval_ttl,
+        )
+    };
+    incoming_message_pda.resize(IncomingMessage::SPACE)?;
+    {
+        let mut data = incoming_message_pda.try_borrow_mut_data()?;
+        let (_, appended) = data.split_at_mut(IncomingMessage::V0_SPACE);
+        appended.fill(0);
+
+        let (_, incoming_message) = data.split_at_mut(IncomingMessage::DISCRIMINATOR.len());
+        let incoming_message = bytemuck::from_bytes_mut::<IncomingMessage>(incoming_message);
+        incoming_message.funder = rent_recipient;
+        incoming_message.approved_at = Clock::get()?.unix_timestamp;
+        incoming_message.approval_ttl = approval_ttl;
+    }
+
+    Ok(())
+}
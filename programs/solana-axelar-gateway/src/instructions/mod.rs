@@ -10,14 +10,110 @@ pub use initialize_payload_verification_session::*;
 pub mod verify_signature;
 pub use verify_signature::*;
 
+pub mod verify_signatures_precompile;
+pub use verify_signatures_precompile::*;
+
+pub mod close_verification_session;
+pub use close_verification_session::*;
+
 pub mod approve_message;
 pub use approve_message::*;
 
+pub mod approve_messages;
+pub use approve_messages::*;
+
 pub mod validate_message;
 pub use validate_message::*;
 
+pub mod close_incoming_message;
+pub use close_incoming_message::*;
+
+pub mod migrate_incoming_message;
+pub use migrate_incoming_message::*;
+
 pub mod rotate_signers;
 pub use rotate_signers::*;
 
 pub mod transfer_operatorship;
 pub use transfer_operatorship::*;
+
+pub mod set_pause_status;
+pub use set_pause_status::*;
+
+pub mod initialize_destination_chain_registry;
+pub use initialize_destination_chain_registry::*;
+
+pub mod add_destination_chain;
+pub use add_destination_chain::*;
+
+pub mod remove_destination_chain;
+pub use remove_destination_chain::*;
+
+pub mod revoke_message;
+pub use revoke_message::*;
+
+pub mod set_message_approval_ttl;
+pub use set_message_approval_ttl::*;
+
+pub mod initialize_call_contract_buffer;
+pub use initialize_call_contract_buffer::*;
+
+pub mod write_call_contract_buffer;
+pub use write_call_contract_buffer::*;
+
+pub mod call_contract_with_buffer;
+pub use call_contract_with_buffer::*;
+
+pub mod call_contract_with_hash;
+pub use call_contract_with_hash::*;
+
+pub mod initialize_message_payload;
+pub use initialize_message_payload::*;
+
+pub mod write_message_payload;
+pub use write_message_payload::*;
+
+pub mod commit_message_payload;
+pub use commit_message_payload::*;
+
+pub mod close_message_payload;
+pub use close_message_payload::*;
+
+pub mod propose_operatorship;
+pub use propose_operatorship::*;
+
+pub mod accept_operatorship;
+pub use accept_operatorship::*;
+
+pub mod cancel_operatorship_proposal;
+pub use cancel_operatorship_proposal::*;
+
+pub mod update_config;
+pub use update_config::*;
+
+pub mod migrate_config;
+pub use migrate_config::*;
+
+pub mod record_verifier_set_epoch;
+pub use record_verifier_set_epoch::*;
+
+pub mod close_expired_verifier_set;
+pub use close_expired_verifier_set::*;
+
+pub mod verify_signatures_batch;
+pub use verify_signatures_batch::*;
+
+pub mod initialize_signature_batch_buffer;
+pub use initialize_signature_batch_buffer::*;
+
+pub mod write_signature_batch_buffer;
+pub use write_signature_batch_buffer::*;
+
+pub mod extend_verification_session;
+pub use extend_verification_session::*;
+
+pub mod initialize_call_contract_nonce;
+pub use initialize_call_contract_nonce::*;
+
+pub mod record_execution_receipt;
+pub use record_execution_receipt::*;
@@ -0,0 +1,76 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayError, OperatorshipProposal, OperatorshipProposedEvent};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeOperatorship<'info> {
+    #[account(
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        mut,
+        // CHECK: This is either the current operator or the upgrade authority
+        constraint = gateway_root_pda.load()?.operator == *operator_or_upgrade_authority.key
+            || program_data.upgrade_authority_address == Some(*operator_or_upgrade_authority.key)
+            @ GatewayError::InvalidOperatorOrAuthorityAccount
+    )]
+    pub operator_or_upgrade_authority: Signer<'info>,
+
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// CHECK: The proposed operator must be different
+    #[account(
+        constraint = proposed_operator.key() != gateway_root_pda.load()?.operator.key()
+            @ ProgramError::InvalidInstructionData
+    )]
+    pub proposed_operator: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = operator_or_upgrade_authority,
+        space = OperatorshipProposal::DISCRIMINATOR.len() + OperatorshipProposal::INIT_SPACE,
+        seeds = [
+            OperatorshipProposal::SEED_PREFIX,
+            gateway_root_pda.load()?.operator.as_ref(),
+            proposed_operator.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub operatorship_proposal: Account<'info, OperatorshipProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// First step of the two-step operatorship transfer: proposes a new operator,
+/// which only takes over once it signs `accept_operatorship`.
+pub fn propose_operatorship_handler(ctx: Context<ProposeOperatorship>) -> Result<()> {
+    let current_operator = ctx.accounts.gateway_root_pda.load()?.operator;
+    let proposed_operator = ctx.accounts.proposed_operator.key();
+
+    ctx.accounts
+        .operatorship_proposal
+        .set_inner(OperatorshipProposal {
+            current_operator,
+            proposed_operator,
+            payer: ctx.accounts.operator_or_upgrade_authority.key(),
+            bump: ctx.bumps.operatorship_proposal,
+        });
+
+    emit_cpi!(OperatorshipProposedEvent {
+        current_operator,
+        proposed_operator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::instructions::validate_message::validate_caller_pda;
+use crate::{
+    ExecutionOutcome, ExecutionReceipt, GatewayError, IncomingMessage, MessageExecutionReceiptEvent,
+};
+use anchor_lang::prelude::*;
+use solana_axelar_std::hasher::LeafHash;
+use solana_axelar_std::Message;
+use std::str::FromStr;
+
+/// Maximum length, in bytes, of the reason of a failed execution.
+pub const MAX_FAILURE_REASON_LEN: usize = 256;
+
+#[derive(Accounts)]
+#[event_cpi]
+#[instruction(message: Message)]
+pub struct RecordExecutionReceipt<'info> {
+    #[account(
+        mut,
+        seeds = [IncomingMessage::SEED_PREFIX, message.command_id().as_ref()],
+        bump = incoming_message_pda.load()?.bump,
+        // CHECK: message must be already executed
+        constraint = incoming_message_pda.load()?.status.is_executed()
+            @ GatewayError::MessageNotExecuted,
+        // CHECK: message hash must match
+        constraint = incoming_message_pda.load()?.message_hash == message.hash()
+            @ GatewayError::InvalidMessageHash,
+        // CHECK: a single receipt can be recorded per message
+        constraint = !incoming_message_pda.load()?.execution_receipt.is_recorded()
+            @ GatewayError::ExecutionReceiptAlreadyRecorded,
+    )]
+    pub incoming_message_pda: AccountLoader<'info, IncomingMessage>,
+
+    /// CHECK: The caller must be the signing PDA that validated the message
+    #[account(
+        signer,
+        constraint = validate_caller_pda(&caller, &message, &incoming_message_pda)?
+            @ GatewayError::InvalidSigningPDA
+    )]
+    pub caller: AccountInfo<'info>,
+}
+
+/// Records whether the destination program managed to act on a message it
+/// validated, so that the outcome can be acknowledged to the source chain.
+///
+/// Recording a receipt is optional, and must happen before the
+/// `IncomingMessage` PDA is closed.
+pub fn record_execution_receipt_handler(
+    ctx: Context<RecordExecutionReceipt>,
+    message: Message,
+    outcome: ExecutionOutcome,
+) -> Result<()> {
+    let execution_receipt = match &outcome {
+        ExecutionOutcome::Success => ExecutionReceipt::succeeded(),
+        ExecutionOutcome::Failure { reason } => {
+            if reason.len() > MAX_FAILURE_REASON_LEN {
+                return err!(GatewayError::FailureReasonTooLong);
+            }
+            ExecutionReceipt::failed()
+        }
+    };
+
+    ctx.accounts
+        .incoming_message_pda
+        .load_mut()?
+        .execution_receipt = execution_receipt;
+
+    let destination_address = Pubkey::from_str(&message.destination_address)
+        .map_err(|_| GatewayError::InvalidDestinationAddress)?;
+
+    emit_cpi!(MessageExecutionReceiptEvent {
+        command_id: message.command_id(),
+        destination_address,
+        source_chain: message.cc_id.chain,
+        cc_id: message.cc_id.id,
+        source_address: message.source_address,
+        outcome,
+    });
+
+    Ok(())
+}
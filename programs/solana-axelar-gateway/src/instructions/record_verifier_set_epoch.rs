@@ -0,0 +1,49 @@
+use crate::seed_prefixes::{VERIFIER_SET_EPOCH_SEED, VERIFIER_SET_TRACKER_SEED};
+use crate::{VerifierSetEpoch, VerifierSetTracker};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RecordVerifierSetEpoch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            VERIFIER_SET_TRACKER_SEED,
+            verifier_set_tracker_pda.load()?.verifier_set_hash.as_ref()
+        ],
+        bump = verifier_set_tracker_pda.load()?.bump,
+    )]
+    pub verifier_set_tracker_pda: AccountLoader<'info, VerifierSetTracker>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = VerifierSetEpoch::DISCRIMINATOR.len() + std::mem::size_of::<VerifierSetEpoch>(),
+        seeds = [
+            VERIFIER_SET_EPOCH_SEED,
+            &verifier_set_tracker_pda.load()?.epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub verifier_set_epoch_pda: AccountLoader<'info, VerifierSetEpoch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Records the epoch of an existing `VerifierSetTracker` in its
+/// `VerifierSetEpoch` PDA.
+///
+/// New verifier sets are recorded when they are rotated in with their epoch
+/// account, so this is only needed to backfill the history of sets rotated in
+/// without it. Anyone can call this, as the record is copied from the tracker.
+pub fn record_verifier_set_epoch_handler(ctx: Context<RecordVerifierSetEpoch>) -> Result<()> {
+    let tracker = ctx.accounts.verifier_set_tracker_pda.load()?;
+
+    let set_epoch = &mut ctx.accounts.verifier_set_epoch_pda.load_init()?;
+    set_epoch.bump = ctx.bumps.verifier_set_epoch_pda;
+    set_epoch.epoch = tracker.epoch;
+    set_epoch.verifier_set_hash = tracker.verifier_set_hash;
+
+    Ok(())
+}
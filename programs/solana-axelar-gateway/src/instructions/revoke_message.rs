@@ -0,0 +1,53 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayError, IncomingMessage, MessageRevokedEvent, MessageStatus};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[event_cpi]
+#[instruction(command_id: [u8; 32])]
+pub struct RevokeMessage<'info> {
+    #[account(
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+    	// CHECK: This is either the current operator or the upgrade authority
+		constraint = gateway_root_pda.load()?.operator == *operator_or_upgrade_authority.key
+			|| program_data.upgrade_authority_address == Some(*operator_or_upgrade_authority.key)
+			@ GatewayError::InvalidOperatorOrAuthorityAccount
+    )]
+    pub operator_or_upgrade_authority: Signer<'info>,
+
+    #[account(
+	    seeds = [crate::ID.as_ref()],
+	    bump,
+	    seeds::program = bpf_loader_upgradeable::ID,
+	)]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        mut,
+        seeds = [IncomingMessage::SEED_PREFIX, command_id.as_ref()],
+        bump = incoming_message_pda.load()?.bump,
+        // CHECK: only approved messages that weren't executed yet can be revoked
+        constraint = incoming_message_pda.load()?.status.is_approved()
+            @ GatewayError::MessageNotApproved,
+    )]
+    pub incoming_message_pda: AccountLoader<'info, IncomingMessage>,
+}
+
+/// Revokes the approval of a message that wasn't executed yet, e.g. because it
+/// was approved by a compromised verifier set.
+///
+/// The message can't be executed nor approved again afterwards.
+pub fn revoke_message_handler(ctx: Context<RevokeMessage>, command_id: [u8; 32]) -> Result<()> {
+    ctx.accounts.incoming_message_pda.load_mut()?.status = MessageStatus::revoked();
+
+    emit_cpi!(MessageRevokedEvent { command_id });
+
+    Ok(())
+}
@@ -1,14 +1,12 @@
 use crate::{
-    GatewayConfig, GatewayError, SignatureVerificationSessionData, VerifierSetRotatedEvent,
-    VerifierSetTracker,
+    GatewayConfig, GatewayError, GatewayErrorContext, SignatureVerificationSessionData,
+    VerifierSetEpoch, VerifierSetRotatedEvent, VerifierSetTracker,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 use solana_axelar_std::PayloadType;
-use solana_axelar_std::U256;
 
 #[derive(Accounts)]
-#[event_cpi]
 #[instruction(new_verifier_set_merkle_root: [u8; 32])]
 pub struct RotateSigners<'info> {
     #[account(
@@ -68,6 +66,30 @@ pub struct RotateSigners<'info> {
     pub system_program: Program<'info, System>,
 
     pub operator: Option<Signer<'info>>,
+
+    // The event CPI accounts are declared by hand instead of with
+    // `#[event_cpi]`, which would place them after the account below and
+    // break the layout existing callers rely on.
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: AccountInfo<'info>,
+
+    /// The epoch record of the new verifier set. When it isn't provided, the
+    /// record can be created afterwards with `record_verifier_set_epoch`.
+    #[account(
+        init,
+        payer = payer,
+        space = VerifierSetEpoch::DISCRIMINATOR.len() + std::mem::size_of::<VerifierSetEpoch>(),
+        seeds = [
+            VerifierSetEpoch::SEED_PREFIX,
+            &gateway_root_pda.load()?.next_epoch()?.to_le_bytes()
+        ],
+        bump
+    )]
+    pub new_verifier_set_epoch: Option<AccountLoader<'info, VerifierSetEpoch>>,
 }
 
 pub fn rotate_signers_handler(
@@ -97,7 +119,14 @@ pub fn rotate_signers_handler(
 
     // Check: proof is signed by latest verifiers
     if enforce_rotation_delay && !is_latest {
-        return err!(GatewayError::ProofNotSignedByLatestVerifierSet);
+        return Err(
+            GatewayError::ProofNotSignedByLatestVerifierSet.with_context(
+                GatewayErrorContext::LatestVerifierSet {
+                    epoch: verifier_set_tracker_pda.epoch,
+                    current_epoch: gateway_root_pda.current_epoch,
+                },
+            ),
+        );
     }
 
     let current_time: u64 = solana_program::clock::Clock::get()?
@@ -110,7 +139,13 @@ pub fn rotate_signers_handler(
 
     // Check: enough time has passed since last rotation (if enforced)
     if enforce_rotation_delay && !enough_time_till_next_rotation(current_time, gateway_root_pda) {
-        return err!(GatewayError::RotationCooldownNotDone);
+        return Err(GatewayError::RotationCooldownNotDone.with_context(
+            GatewayErrorContext::RotationCooldown {
+                last_rotation_timestamp: gateway_root_pda.last_rotation_timestamp,
+                minimum_rotation_delay: gateway_root_pda.minimum_rotation_delay,
+                current_time,
+            },
+        ));
     }
 
     // Update Gateway config:
@@ -118,10 +153,7 @@ pub fn rotate_signers_handler(
     // Update the last rotation timestamp
     gateway_root_pda.last_rotation_timestamp = current_time;
     // Increment the current epoch
-    gateway_root_pda.current_epoch = gateway_root_pda
-        .current_epoch
-        .checked_add(U256::ONE)
-        .ok_or(GatewayError::EpochCalculationOverflow)?;
+    gateway_root_pda.current_epoch = gateway_root_pda.next_epoch()?;
 
     // Initialize the new verifier set tracker
     let new_verifier_set_tracker = &mut ctx.accounts.new_verifier_set_tracker.load_init()?;
@@ -129,6 +161,17 @@ pub fn rotate_signers_handler(
     new_verifier_set_tracker.epoch = gateway_root_pda.current_epoch;
     new_verifier_set_tracker.verifier_set_hash = new_verifier_set_merkle_root;
 
+    // Record the new verifier set in the epoch history, if requested
+    if let (Some(new_verifier_set_epoch), Some(bump)) = (
+        ctx.accounts.new_verifier_set_epoch.as_ref(),
+        ctx.bumps.new_verifier_set_epoch,
+    ) {
+        let new_verifier_set_epoch = &mut new_verifier_set_epoch.load_init()?;
+        new_verifier_set_epoch.bump = bump;
+        new_verifier_set_epoch.epoch = gateway_root_pda.current_epoch;
+        new_verifier_set_epoch.verifier_set_hash = new_verifier_set_merkle_root;
+    }
+
     // Emit event
     emit_cpi!(VerifierSetRotatedEvent {
         verifier_set_hash: new_verifier_set_merkle_root,
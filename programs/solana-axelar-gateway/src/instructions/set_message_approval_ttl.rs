@@ -0,0 +1,52 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayError, MessageApprovalTtlUpdatedEvent};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct SetMessageApprovalTtl<'info> {
+    #[account(
+        mut,
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+    	// CHECK: This is either the current operator or the upgrade authority
+		constraint = gateway_root_pda.load()?.operator == *operator_or_upgrade_authority.key
+			|| program_data.upgrade_authority_address == Some(*operator_or_upgrade_authority.key)
+			@ GatewayError::InvalidOperatorOrAuthorityAccount
+    )]
+    pub operator_or_upgrade_authority: Signer<'info>,
+
+    #[account(
+	    seeds = [crate::ID.as_ref()],
+	    bump,
+	    seeds::program = bpf_loader_upgradeable::ID,
+	)]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+/// Sets how long (in seconds) approved messages can be executed for, 0 for
+/// approvals that don't expire.
+///
+/// Each message keeps the TTL it was approved with, so the update only applies
+/// to the messages approved afterwards.
+pub fn set_message_approval_ttl_handler(
+    ctx: Context<SetMessageApprovalTtl>,
+    message_approval_ttl: u32,
+) -> Result<()> {
+    ctx.accounts
+        .gateway_root_pda
+        .load_mut()?
+        .message_approval_ttl = message_approval_ttl;
+
+    emit_cpi!(MessageApprovalTtlUpdatedEvent {
+        message_approval_ttl
+    });
+
+    Ok(())
+}
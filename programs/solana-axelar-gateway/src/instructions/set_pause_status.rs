@@ -0,0 +1,54 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayError, GatewayPausedEvent, GatewayUnpausedEvent};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+#[derive(Accounts)]
+#[event_cpi]
+#[instruction(paused: bool)]
+pub struct SetPauseStatus<'info> {
+    #[account(
+        mut,
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump,
+        // Check: the pause status changes
+        constraint = gateway_root_pda.load()?.is_paused() != paused
+            @ GatewayError::PauseStatusUnchanged,
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+    	// CHECK: This is either the current operator or the upgrade authority
+		constraint = gateway_root_pda.load()?.operator == *operator_or_upgrade_authority.key
+			|| program_data.upgrade_authority_address == Some(*operator_or_upgrade_authority.key)
+			@ GatewayError::InvalidOperatorOrAuthorityAccount
+    )]
+    pub operator_or_upgrade_authority: Signer<'info>,
+
+    #[account(
+	    seeds = [crate::ID.as_ref()],
+	    bump,
+	    seeds::program = bpf_loader_upgradeable::ID,
+	)]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+/// Pauses or unpauses the gateway.
+///
+/// While paused, message approval, validation and outgoing contract calls are
+/// rejected. Signer rotation keeps working so that the verifier set can still
+/// be replaced during an incident.
+pub fn set_pause_status_handler(ctx: Context<SetPauseStatus>, paused: bool) -> Result<()> {
+    let mut gateway_root_pda = ctx.accounts.gateway_root_pda.load_mut()?;
+    gateway_root_pda.paused = u8::from(paused);
+
+    let authority = ctx.accounts.operator_or_upgrade_authority.key();
+    if paused {
+        emit_cpi!(GatewayPausedEvent { authority });
+    } else {
+        emit_cpi!(GatewayUnpausedEvent { authority });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::seed_prefixes::GATEWAY_SEED;
+use crate::{GatewayConfig, GatewayConfigUpdatedEvent, GatewayError, UpdateConfigParams};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use solana_axelar_std::U256;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GATEWAY_SEED],
+        bump = gateway_root_pda.load()?.bump
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        // CHECK: upgrade authority in program_data matches the one passed as signer
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ GatewayError::InvalidUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+/// Updates the gateway configuration set at `initialize_config`.
+///
+/// Only the upgrade authority can update the configuration, which is the
+/// governance program once the gateway is handed over to it. This includes the
+/// domain separator, which has to match the one of the Amplifier chain: proofs
+/// signed with the previous one can't be verified after the update.
+pub fn update_config_handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.gateway_root_pda.load_mut()?;
+
+    if let Some(minimum_rotation_delay) = params.minimum_rotation_delay {
+        config.minimum_rotation_delay = minimum_rotation_delay;
+    }
+    if let Some(previous_verifier_set_retention) = params.previous_verifier_set_retention {
+        // A zero retention would invalidate the current verifier set
+        require!(
+            previous_verifier_set_retention != U256::ZERO,
+            GatewayError::InvalidConfigValue
+        );
+        config.previous_verifier_set_retention = previous_verifier_set_retention;
+    }
+    if let Some(domain_separator) = params.domain_separator {
+        config.domain_separator = domain_separator;
+    }
+    if let Some(rent_recipient) = params.rent_recipient {
+        config.rent_recipient = rent_recipient;
+    }
+
+    emit_cpi!(GatewayConfigUpdatedEvent {
+        minimum_rotation_delay: config.minimum_rotation_delay,
+        previous_verifier_set_retention: config.previous_verifier_set_retention,
+        domain_separator: config.domain_separator,
+        rent_recipient: config.rent_recipient,
+    });
+
+    Ok(())
+}
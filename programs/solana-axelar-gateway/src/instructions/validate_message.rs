@@ -15,6 +15,9 @@ pub struct ValidateMessage<'info> {
         mut,
         seeds = [IncomingMessage::SEED_PREFIX, message.command_id().as_ref()],
         bump = incoming_message_pda.load()?.bump,
+        // CHECK: message approval wasn't revoked
+        constraint = !incoming_message_pda.load()?.status.is_revoked()
+            @ GatewayError::MessageRevoked,
         // CHECK: message must be already approved
         constraint = incoming_message_pda.load()?.status.is_approved()
             @ GatewayError::MessageNotApproved,
@@ -34,13 +37,21 @@ pub struct ValidateMessage<'info> {
 
     #[account(
         seeds = [GatewayConfig::SEED_PREFIX],
-        bump = gateway_root_pda.load()?.bump
+        bump = gateway_root_pda.load()?.bump,
+        // Check: the gateway isn't paused
+        constraint = !gateway_root_pda.load()?.is_paused() @ GatewayError::GatewayPaused,
     )]
     pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
 }
 
 pub fn validate_message_handler(ctx: Context<ValidateMessage>, message: Message) -> Result<()> {
     let incoming_message_pda = &mut ctx.accounts.incoming_message_pda.load_mut()?;
+
+    // Check: message approval hasn't expired
+    if incoming_message_pda.is_approval_expired(Clock::get()?.unix_timestamp) {
+        return err!(GatewayError::MessageApprovalExpired);
+    }
+
     incoming_message_pda.status = MessageStatus::executed();
 
     // Parse destination address
@@ -63,7 +74,7 @@ pub fn validate_message_handler(ctx: Context<ValidateMessage>, message: Message)
     Ok(())
 }
 
-fn validate_caller_pda(
+pub(crate) fn validate_caller_pda(
     caller: &AccountInfo,
     message: &Message,
     incoming_message: &AccountLoader<'_, IncomingMessage>,
@@ -1,5 +1,8 @@
 #![allow(clippy::empty_structs_with_brackets)]
-use crate::{GatewayConfig, GatewayError, SignatureVerificationSessionData, VerifierSetTracker};
+use crate::{
+    GatewayConfig, GatewayError, GatewayErrorContext, SignatureVerificationSessionData,
+    VerifierSetTracker,
+};
 use anchor_lang::prelude::*;
 use solana_axelar_std::SigningVerifierSetInfo;
 
@@ -9,9 +12,6 @@ pub struct VerifySignature<'info> {
     #[account(
         seeds = [GatewayConfig::SEED_PREFIX],
         bump = gateway_root_pda.load()?.bump,
-        // Check: Verifier domain separator matches the gateway's domain separator
-        constraint = gateway_root_pda.load()?.domain_separator == verifier_info.leaf.domain_separator
-            @ GatewayError::InvalidDomainSeparator,
         // Check: Verifier set isn't expired
         constraint = gateway_root_pda.load()?.assert_valid_epoch(verifier_set_tracker_pda.load()?.epoch).is_ok()
             @ GatewayError::VerifierSetTooOld,
@@ -46,17 +46,31 @@ pub fn verify_signature_handler(
     payload_merkle_root: [u8; 32],
     verifier_info: SigningVerifierSetInfo,
 ) -> Result<()> {
+    let domain_separator = ctx.accounts.gateway_root_pda.load()?.domain_separator;
     let verifier_set_tracker_pda = ctx.accounts.verifier_set_tracker_pda.load()?;
 
+    // Check: Verifier domain separator matches the gateway's domain separator
+    if verifier_info.leaf.domain_separator != domain_separator {
+        return Err(GatewayError::InvalidDomainSeparator.with_context(
+            GatewayErrorContext::DomainSeparator {
+                expected: domain_separator,
+                actual: verifier_info.leaf.domain_separator,
+            },
+        ));
+    }
+
+    let verification_session_account = ctx.accounts.verification_session_account.to_account_info();
+    let mut data = verification_session_account.try_borrow_mut_data()?;
+    let (verification_session, extended_slots) =
+        SignatureVerificationSessionData::split_extended_slots(&mut data)?;
+
     // Verify signature
-    ctx.accounts
-        .verification_session_account
-        .load_mut()?
-        .process_signature(
-            payload_merkle_root,
-            &verifier_set_tracker_pda.verifier_set_hash,
-            verifier_info,
-        )?;
+    verification_session.process_signature(
+        extended_slots,
+        payload_merkle_root,
+        &verifier_set_tracker_pda.verifier_set_hash,
+        verifier_info,
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,107 @@
+#![allow(clippy::empty_structs_with_brackets)]
+use crate::{
+    GatewayConfig, GatewayError, GatewayErrorContext, SignatureBatchBuffer,
+    SignatureVerificationSessionData, VerifierSetTracker,
+};
+use anchor_lang::prelude::*;
+use solana_axelar_std::{PayloadType, SigningVerifierSetBatch};
+
+#[derive(Accounts)]
+#[instruction(payload_merkle_root: [u8; 32], payload_type: PayloadType)]
+pub struct VerifySignaturesBatch<'info> {
+    #[account(
+        seeds = [GatewayConfig::SEED_PREFIX],
+        bump = gateway_root_pda.load()?.bump,
+        // Check: Verifier set isn't expired
+        constraint = gateway_root_pda.load()?.assert_valid_epoch(verifier_set_tracker_pda.load()?.epoch).is_ok()
+            @ GatewayError::VerifierSetTooOld,
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SignatureVerificationSessionData::SEED_PREFIX,
+            payload_merkle_root.as_ref(),
+            &[payload_type.into()],
+            verification_session_account.load()?.signature_verification.signing_verifier_set_hash.as_ref(),
+        ],
+        bump = verification_session_account.load()?.bump
+    )]
+    pub verification_session_account: AccountLoader<'info, SignatureVerificationSessionData>,
+
+    #[account(
+        // The verifier set tracker PDA is derived from the verifier set hash
+        seeds = [
+            VerifierSetTracker::SEED_PREFIX,
+            verifier_set_tracker_pda.load()?.verifier_set_hash.as_ref(),
+        ],
+        bump,
+    )]
+    pub verifier_set_tracker_pda: AccountLoader<'info, VerifierSetTracker>,
+
+    /// Any buffer of the gateway, as the signatures it holds are verified
+    /// below. It's closed once the batch is verified.
+    #[account(mut, close = payer)]
+    pub signature_batch_buffer: Account<'info, SignatureBatchBuffer>,
+
+    /// CHECK: Must match the payer recorded when the buffer was initialized
+    #[account(
+        mut,
+        address = signature_batch_buffer.payer @ GatewayError::InvalidFunder,
+    )]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Verifies the signatures of several verifiers at once, whose inclusion in
+/// the signing verifier set is proven by a single Merkle multiproof.
+///
+/// The batch is read from a `SignatureBatchBuffer`, uploaded beforehand with
+/// `initialize_signature_batch_buffer` and `write_signature_batch_buffer`, as
+/// only a handful of signatures would fit in the instruction data. The buffer
+/// is closed once the batch is verified.
+pub fn verify_signatures_batch_handler(
+    ctx: Context<VerifySignaturesBatch>,
+    payload_merkle_root: [u8; 32],
+    payload_type: PayloadType,
+) -> Result<()> {
+    let batch = SigningVerifierSetBatch::try_from_slice(&ctx.accounts.signature_batch_buffer.batch)
+        .map_err(|_err| GatewayError::InvalidSignerBatch)?;
+
+    // Check: the batch was signed for the session's payload type
+    if batch.payload_type != payload_type {
+        return err!(GatewayError::SignerBatchPayloadTypeMismatch);
+    }
+
+    let domain_separator = ctx.accounts.gateway_root_pda.load()?.domain_separator;
+    let verifier_set_tracker_pda = ctx.accounts.verifier_set_tracker_pda.load()?;
+
+    // Check: Verifier domain separator matches the gateway's domain separator
+    if let Some(verifier) = batch
+        .verifiers
+        .iter()
+        .find(|verifier| verifier.leaf.domain_separator != domain_separator)
+    {
+        return Err(GatewayError::InvalidDomainSeparator.with_context(
+            GatewayErrorContext::DomainSeparator {
+                expected: domain_separator,
+                actual: verifier.leaf.domain_separator,
+            },
+        ));
+    }
+
+    let verification_session_account = ctx.accounts.verification_session_account.to_account_info();
+    let mut data = verification_session_account.try_borrow_mut_data()?;
+    let (verification_session, extended_slots) =
+        SignatureVerificationSessionData::split_extended_slots(&mut data)?;
+
+    // Verify signatures
+    verification_session.process_signature_batch(
+        extended_slots,
+        payload_merkle_root,
+        &verifier_set_tracker_pda.verifier_set_hash,
+        &batch,
+    )?;
+
+    Ok(())
+}
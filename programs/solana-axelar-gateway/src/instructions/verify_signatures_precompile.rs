@@ -0,0 +1,120 @@
+#![allow(clippy::empty_structs_with_brackets)]
+use crate::{
+    secp256k1_precompile, GatewayConfig, GatewayError, GatewayErrorContext,
+    SignatureVerificationSessionData, VerifierSetTracker,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::secp256k1_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use solana_axelar_std::execute_data::prefixed_message_payload_type;
+use solana_axelar_std::{PayloadType, PrecompiledVerifierSetInfo};
+
+#[derive(Accounts)]
+#[instruction(payload_merkle_root: [u8; 32], payload_type: PayloadType)]
+pub struct VerifySignaturesPrecompile<'info> {
+    #[account(
+        seeds = [GatewayConfig::SEED_PREFIX],
+        bump = gateway_root_pda.load()?.bump,
+        // Check: Verifier set isn't expired
+        constraint = gateway_root_pda.load()?.assert_valid_epoch(verifier_set_tracker_pda.load()?.epoch).is_ok()
+            @ GatewayError::VerifierSetTooOld,
+    )]
+    pub gateway_root_pda: AccountLoader<'info, GatewayConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SignatureVerificationSessionData::SEED_PREFIX,
+            payload_merkle_root.as_ref(),
+            &[payload_type.into()],
+            verification_session_account.load()?.signature_verification.signing_verifier_set_hash.as_ref(),
+        ],
+        bump = verification_session_account.load()?.bump
+    )]
+    pub verification_session_account: AccountLoader<'info, SignatureVerificationSessionData>,
+
+    #[account(
+        // The verifier set tracker PDA is derived from the verifier set hash
+        seeds = [
+            VerifierSetTracker::SEED_PREFIX,
+            verifier_set_tracker_pda.load()?.verifier_set_hash.as_ref(),
+        ],
+        bump,
+    )]
+    pub verifier_set_tracker_pda: AccountLoader<'info, VerifierSetTracker>,
+
+    /// CHECK: The instructions sysvar, used to read back the precompile instruction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Verifies several verifier signatures at once, using the secp256k1
+/// precompile instruction placed right before this one in the transaction.
+///
+/// The precompile must verify exactly one signature per entry of
+/// `verifier_infos`, in the same order, each over the prefixed
+/// `payload_merkle_root`.
+pub fn verify_signatures_precompile_handler(
+    ctx: Context<VerifySignaturesPrecompile>,
+    payload_merkle_root: [u8; 32],
+    payload_type: PayloadType,
+    verifier_infos: Vec<PrecompiledVerifierSetInfo>,
+) -> Result<()> {
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+
+    // Check: the previous instruction is a secp256k1 precompile instruction
+    let precompile_index = load_current_index_checked(&instructions_sysvar)?
+        .checked_sub(1)
+        .ok_or(GatewayError::InvalidPrecompileInstruction)?;
+    let precompile_ix =
+        load_instruction_at_checked(usize::from(precompile_index), &instructions_sysvar)?;
+    if precompile_ix.program_id != secp256k1_program::ID {
+        return err!(GatewayError::InvalidPrecompileInstruction);
+    }
+
+    // Check: the precompile verified each verifier's signature over the payload
+    let signatures = secp256k1_precompile::parse_signatures(precompile_index, &precompile_ix.data)?;
+    if signatures.len() != verifier_infos.len() {
+        return err!(GatewayError::PrecompileSignatureMismatch);
+    }
+
+    let expected_message = prefixed_message_payload_type(payload_type, &payload_merkle_root);
+    for (verifier_info, signature) in verifier_infos.iter().zip(signatures) {
+        if signature.message != expected_message.as_slice()
+            || *signature.eth_address
+                != secp256k1_precompile::eth_address(&verifier_info.leaf.signer_pubkey.0)?
+        {
+            return err!(GatewayError::PrecompileSignatureMismatch);
+        }
+    }
+
+    let domain_separator = ctx.accounts.gateway_root_pda.load()?.domain_separator;
+    let verifier_set_tracker_pda = ctx.accounts.verifier_set_tracker_pda.load()?;
+    let verification_session_account = ctx.accounts.verification_session_account.to_account_info();
+    let mut data = verification_session_account.try_borrow_mut_data()?;
+    let (verification_session, extended_slots) =
+        SignatureVerificationSessionData::split_extended_slots(&mut data)?;
+
+    for verifier_info in &verifier_infos {
+        // Check: Verifier domain separator matches the gateway's domain separator
+        if verifier_info.leaf.domain_separator != domain_separator {
+            return Err(GatewayError::InvalidDomainSeparator.with_context(
+                GatewayErrorContext::DomainSeparator {
+                    expected: domain_separator,
+                    actual: verifier_info.leaf.domain_separator,
+                },
+            ));
+        }
+
+        verification_session.process_precompiled_signature(
+            extended_slots,
+            payload_merkle_root,
+            &verifier_set_tracker_pda.verifier_set_hash,
+            verifier_info,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::instructions::call_contract::validate_caller;
+use crate::{CallContractPayloadBuffer, GatewayError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(buffer_id: u64)]
+pub struct WriteCallContractBuffer<'info> {
+    /// The sender the payload will be emitted for - can be a direct signer or program
+    /// CHECK: We validate the caller using is_signer flag and signing PDA verification
+    pub caller: UncheckedAccount<'info>,
+
+    /// The standardized PDA that must sign - derived from the calling program
+    pub signing_pda: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        seeds = [
+            CallContractPayloadBuffer::SEED_PREFIX,
+            caller.key().as_ref(),
+            buffer_id.to_le_bytes().as_ref(),
+        ],
+        bump = payload_buffer.bump,
+    )]
+    pub payload_buffer: Account<'info, CallContractPayloadBuffer>,
+}
+
+/// Writes a chunk of the payload at `offset`.
+pub fn write_call_contract_buffer_handler(
+    ctx: Context<WriteCallContractBuffer>,
+    _buffer_id: u64,
+    offset: u32,
+    bytes: Vec<u8>,
+    signing_pda_bump: u8,
+) -> Result<()> {
+    validate_caller(
+        &ctx.accounts.caller,
+        ctx.accounts.signing_pda.as_ref(),
+        signing_pda_bump,
+    )?;
+
+    ctx.accounts
+        .payload_buffer
+        .write(offset as usize, &bytes)
+        .ok_or(GatewayError::PayloadBufferOutOfBounds)?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::{GatewayError, MessagePayload};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(command_id: [u8; 32])]
+pub struct WriteMessagePayload<'info> {
+    #[account(
+        // CHECK: only the account that paid for the buffer can write to it
+        address = message_payload.payer @ GatewayError::InvalidFunder,
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MessagePayload::SEED_PREFIX,
+            command_id.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = message_payload.bump,
+        // CHECK: committed payloads are final
+        constraint = !message_payload.committed @ GatewayError::MessagePayloadAlreadyCommitted,
+    )]
+    pub message_payload: Account<'info, MessagePayload>,
+}
+
+/// Writes a chunk of the payload at `offset`.
+pub fn write_message_payload_handler(
+    ctx: Context<WriteMessagePayload>,
+    _command_id: [u8; 32],
+    offset: u32,
+    bytes: Vec<u8>,
+) -> Result<()> {
+    ctx.accounts
+        .message_payload
+        .write(offset as usize, &bytes)
+        .ok_or(GatewayError::PayloadBufferOutOfBounds)?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::{GatewayError, SignatureBatchBuffer};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(buffer_id: u64)]
+pub struct WriteSignatureBatchBuffer<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SignatureBatchBuffer::SEED_PREFIX,
+            payer.key().as_ref(),
+            buffer_id.to_le_bytes().as_ref(),
+        ],
+        bump = signature_batch_buffer.bump,
+    )]
+    pub signature_batch_buffer: Account<'info, SignatureBatchBuffer>,
+}
+
+/// Writes a chunk of the serialized signature batch at `offset`.
+pub fn write_signature_batch_buffer_handler(
+    ctx: Context<WriteSignatureBatchBuffer>,
+    _buffer_id: u64,
+    offset: u32,
+    bytes: Vec<u8>,
+) -> Result<()> {
+    ctx.accounts
+        .signature_batch_buffer
+        .write(offset as usize, &bytes)
+        .ok_or(GatewayError::PayloadBufferOutOfBounds)?;
+
+    Ok(())
+}
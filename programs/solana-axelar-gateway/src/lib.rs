@@ -27,6 +27,11 @@ pub mod executable;
 
 pub mod payload;
 
+pub mod secp256k1_precompile;
+
+#[cfg(feature = "client")]
+pub mod client;
+
 use solana_axelar_std::ensure_single_feature;
 
 pub use solana_axelar_std::Message;
@@ -62,6 +67,19 @@ pub mod seed_prefixes {
     pub const INCOMING_MESSAGE_SEED: &[u8] = state::IncomingMessage::SEED_PREFIX;
     /// The seed prefix for deriving validate message signing PDAs
     pub const VALIDATE_MESSAGE_SIGNING_SEED: &[u8] = state::ValidateMessageSigner::SEED_PREFIX;
+    /// The seed prefix for deriving the destination chain registry PDA
+    pub const DESTINATION_CHAIN_REGISTRY_SEED: &[u8] = state::DestinationChainRegistry::SEED_PREFIX;
+    /// The seed prefix for deriving call contract payload buffer PDAs
+    pub const CALL_CONTRACT_PAYLOAD_BUFFER_SEED: &[u8] =
+        state::CallContractPayloadBuffer::SEED_PREFIX;
+    /// The seed prefix for deriving inbound message payload buffer PDAs
+    pub const MESSAGE_PAYLOAD_SEED: &[u8] = state::MessagePayload::SEED_PREFIX;
+    /// The seed prefix for deriving the operatorship proposal PDA
+    pub const OPERATORSHIP_PROPOSAL_SEED: &[u8] = state::OperatorshipProposal::SEED_PREFIX;
+    /// The seed prefix for deriving `VerifierSetEpoch` PDAs
+    pub const VERIFIER_SET_EPOCH_SEED: &[u8] = state::VerifierSetEpoch::SEED_PREFIX;
+    /// The seed prefix for deriving `CallContractNonce` PDAs
+    pub const CALL_CONTRACT_NONCE_SEED: &[u8] = state::CallContractNonce::SEED_PREFIX;
 }
 
 #[program]
@@ -111,6 +129,63 @@ pub mod solana_axelar_gateway {
         instructions::verify_signature_handler(ctx, payload_merkle_root, verifier_info)
     }
 
+    pub fn verify_signatures_precompile(
+        ctx: Context<VerifySignaturesPrecompile>,
+        payload_merkle_root: [u8; 32],
+        payload_type: solana_axelar_std::PayloadType,
+        verifier_infos: Vec<solana_axelar_std::PrecompiledVerifierSetInfo>,
+    ) -> Result<()> {
+        instructions::verify_signatures_precompile_handler(
+            ctx,
+            payload_merkle_root,
+            payload_type,
+            verifier_infos,
+        )
+    }
+
+    pub fn initialize_signature_batch_buffer(
+        ctx: Context<InitializeSignatureBatchBuffer>,
+        buffer_id: u64,
+        batch_size: u32,
+    ) -> Result<()> {
+        instructions::initialize_signature_batch_buffer_handler(ctx, buffer_id, batch_size)
+    }
+
+    pub fn write_signature_batch_buffer(
+        ctx: Context<WriteSignatureBatchBuffer>,
+        buffer_id: u64,
+        offset: u32,
+        bytes: Vec<u8>,
+    ) -> Result<()> {
+        instructions::write_signature_batch_buffer_handler(ctx, buffer_id, offset, bytes)
+    }
+
+    pub fn verify_signatures_batch(
+        ctx: Context<VerifySignaturesBatch>,
+        payload_merkle_root: [u8; 32],
+        payload_type: solana_axelar_std::PayloadType,
+    ) -> Result<()> {
+        instructions::verify_signatures_batch_handler(ctx, payload_merkle_root, payload_type)
+    }
+
+    pub fn extend_verification_session(
+        ctx: Context<ExtendVerificationSession>,
+        payload_merkle_root: [u8; 32],
+        payload_type: solana_axelar_std::PayloadType,
+        set_size: u16,
+    ) -> Result<()> {
+        instructions::extend_verification_session_handler(
+            ctx,
+            payload_merkle_root,
+            payload_type,
+            set_size,
+        )
+    }
+
+    pub fn close_verification_session(ctx: Context<CloseVerificationSession>) -> Result<()> {
+        instructions::close_verification_session_handler(ctx)
+    }
+
     pub fn approve_message(
         ctx: Context<ApproveMessage>,
         merklized_message: solana_axelar_std::MerklizedMessage,
@@ -119,6 +194,14 @@ pub mod solana_axelar_gateway {
         instructions::approve_message_handler(ctx, merklized_message, payload_merkle_root)
     }
 
+    pub fn approve_messages(
+        ctx: Context<ApproveMessages>,
+        merklized_batch: solana_axelar_std::MerklizedMessageBatch,
+        payload_merkle_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::approve_messages_handler(ctx, merklized_batch, payload_merkle_root)
+    }
+
     pub fn validate_message(
         ctx: Context<ValidateMessage>,
         message: solana_axelar_std::Message,
@@ -126,6 +209,25 @@ pub mod solana_axelar_gateway {
         instructions::validate_message_handler(ctx, message)
     }
 
+    pub fn record_execution_receipt(
+        ctx: Context<RecordExecutionReceipt>,
+        message: solana_axelar_std::Message,
+        outcome: ExecutionOutcome,
+    ) -> Result<()> {
+        instructions::record_execution_receipt_handler(ctx, message, outcome)
+    }
+
+    pub fn close_incoming_message(ctx: Context<CloseIncomingMessage>) -> Result<()> {
+        instructions::close_incoming_message_handler(ctx)
+    }
+
+    pub fn migrate_incoming_message(
+        ctx: Context<MigrateIncomingMessage>,
+        command_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::migrate_incoming_message_handler(ctx, command_id)
+    }
+
     pub fn rotate_signers(
         ctx: Context<RotateSigners>,
         new_verifier_set_merkle_root: [u8; 32],
@@ -136,4 +238,166 @@ pub mod solana_axelar_gateway {
     pub fn transfer_operatorship(ctx: Context<TransferOperatorship>) -> Result<()> {
         instructions::transfer_operatorship_handler(ctx)
     }
+
+    pub fn propose_operatorship(ctx: Context<ProposeOperatorship>) -> Result<()> {
+        instructions::propose_operatorship_handler(ctx)
+    }
+
+    pub fn accept_operatorship(ctx: Context<AcceptOperatorship>) -> Result<()> {
+        instructions::accept_operatorship_handler(ctx)
+    }
+
+    pub fn cancel_operatorship_proposal(ctx: Context<CancelOperatorshipProposal>) -> Result<()> {
+        instructions::cancel_operatorship_proposal_handler(ctx)
+    }
+
+    pub fn set_pause_status(ctx: Context<SetPauseStatus>, paused: bool) -> Result<()> {
+        instructions::set_pause_status_handler(ctx, paused)
+    }
+
+    pub fn initialize_destination_chain_registry(
+        ctx: Context<InitializeDestinationChainRegistry>,
+    ) -> Result<()> {
+        instructions::initialize_destination_chain_registry_handler(ctx)
+    }
+
+    pub fn add_destination_chain(
+        ctx: Context<AddDestinationChain>,
+        chain_name: String,
+    ) -> Result<()> {
+        instructions::add_destination_chain_handler(ctx, chain_name)
+    }
+
+    pub fn remove_destination_chain(
+        ctx: Context<RemoveDestinationChain>,
+        chain_name: String,
+    ) -> Result<()> {
+        instructions::remove_destination_chain_handler(ctx, chain_name)
+    }
+
+    pub fn revoke_message(ctx: Context<RevokeMessage>, command_id: [u8; 32]) -> Result<()> {
+        instructions::revoke_message_handler(ctx, command_id)
+    }
+
+    pub fn set_message_approval_ttl(
+        ctx: Context<SetMessageApprovalTtl>,
+        message_approval_ttl: u32,
+    ) -> Result<()> {
+        instructions::set_message_approval_ttl_handler(ctx, message_approval_ttl)
+    }
+
+    pub fn initialize_call_contract_nonce(ctx: Context<InitializeCallContractNonce>) -> Result<()> {
+        instructions::initialize_call_contract_nonce_handler(ctx)
+    }
+
+    pub fn initialize_call_contract_buffer(
+        ctx: Context<InitializeCallContractBuffer>,
+        buffer_id: u64,
+        payload_size: u32,
+        signing_pda_bump: u8,
+    ) -> Result<()> {
+        instructions::initialize_call_contract_buffer_handler(
+            ctx,
+            buffer_id,
+            payload_size,
+            signing_pda_bump,
+        )
+    }
+
+    pub fn write_call_contract_buffer(
+        ctx: Context<WriteCallContractBuffer>,
+        buffer_id: u64,
+        offset: u32,
+        bytes: Vec<u8>,
+        signing_pda_bump: u8,
+    ) -> Result<()> {
+        instructions::write_call_contract_buffer_handler(
+            ctx,
+            buffer_id,
+            offset,
+            bytes,
+            signing_pda_bump,
+        )
+    }
+
+    pub fn call_contract_with_buffer(
+        ctx: Context<CallContractWithBuffer>,
+        destination_chain: String,
+        destination_contract_address: String,
+        buffer_id: u64,
+        signing_pda_bump: u8,
+    ) -> Result<()> {
+        instructions::call_contract_with_buffer_handler(
+            ctx,
+            destination_chain,
+            destination_contract_address,
+            buffer_id,
+            signing_pda_bump,
+        )
+    }
+
+    pub fn call_contract_with_hash(
+        ctx: Context<CallContract>,
+        destination_chain: String,
+        destination_contract_address: String,
+        payload_hash: [u8; 32],
+        payload_location: String,
+        signing_pda_bump: u8,
+    ) -> Result<()> {
+        instructions::call_contract_with_hash_handler(
+            ctx,
+            destination_chain,
+            destination_contract_address,
+            payload_hash,
+            payload_location,
+            signing_pda_bump,
+        )
+    }
+
+    pub fn initialize_message_payload(
+        ctx: Context<InitializeMessagePayload>,
+        command_id: [u8; 32],
+        payload_size: u32,
+    ) -> Result<()> {
+        instructions::initialize_message_payload_handler(ctx, command_id, payload_size)
+    }
+
+    pub fn write_message_payload(
+        ctx: Context<WriteMessagePayload>,
+        command_id: [u8; 32],
+        offset: u32,
+        bytes: Vec<u8>,
+    ) -> Result<()> {
+        instructions::write_message_payload_handler(ctx, command_id, offset, bytes)
+    }
+
+    pub fn commit_message_payload(
+        ctx: Context<CommitMessagePayload>,
+        command_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_message_payload_handler(ctx, command_id)
+    }
+
+    pub fn close_message_payload(
+        ctx: Context<CloseMessagePayload>,
+        command_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::close_message_payload_handler(ctx, command_id)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        instructions::update_config_handler(ctx, params)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config_handler(ctx)
+    }
+
+    pub fn record_verifier_set_epoch(ctx: Context<RecordVerifierSetEpoch>) -> Result<()> {
+        instructions::record_verifier_set_epoch_handler(ctx)
+    }
+
+    pub fn close_expired_verifier_set(ctx: Context<CloseExpiredVerifierSet>) -> Result<()> {
+        instructions::close_expired_verifier_set_handler(ctx)
+    }
 }
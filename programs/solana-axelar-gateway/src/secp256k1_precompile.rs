@@ -0,0 +1,227 @@
+//! Reading back signatures checked by Solana's native secp256k1 precompile.
+//!
+//! The precompile instruction verifies any number of signatures before the
+//! transaction's program instructions get executed, failing the whole
+//! transaction on an invalid signature. The gateway then only needs to check
+//! *what* was verified: which Ethereum address signed which message.
+//!
+//! The precompile instruction data is laid out as follows:
+//!
+//! ```text
+//! [u8 count] [SecpSignatureOffsets; count] [signatures, addresses and messages]
+//! ```
+
+use anchor_lang::prelude::*;
+use solana_axelar_std::{EcdsaRecoverableSignature, Secp256k1Pubkey};
+
+use crate::GatewayError;
+
+/// Size of a serialized `SecpSignatureOffsets` entry.
+pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
+
+/// Offset of the first `SecpSignatureOffsets` entry, after the count byte.
+pub const DATA_START: usize = 1;
+
+/// Size of an Ethereum address, as checked by the precompile.
+pub const ETH_ADDRESS_SIZE: usize = 20;
+
+/// A signature verified by the secp256k1 precompile.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PrecompiledSignature<'a> {
+    /// The Ethereum address of the signer.
+    pub eth_address: &'a [u8; ETH_ADDRESS_SIZE],
+    /// The signed message, before hashing.
+    pub message: &'a [u8],
+}
+
+/// Parses the signatures verified by a secp256k1 precompile instruction.
+///
+/// All offsets must point into the precompile instruction itself, located at
+/// `instruction_index` within the transaction.
+pub fn parse_signatures(
+    instruction_index: u16,
+    data: &[u8],
+) -> Result<Vec<PrecompiledSignature<'_>>> {
+    let (&count, _) = data
+        .split_first()
+        .ok_or(GatewayError::InvalidPrecompileInstruction)?;
+
+    (0..usize::from(count))
+        .map(|index| {
+            let start = DATA_START + index * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+            let offsets: &[u8; SIGNATURE_OFFSETS_SERIALIZED_SIZE] = data
+                .get(start..start + SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+                .and_then(|offsets| offsets.try_into().ok())
+                .ok_or(GatewayError::InvalidPrecompileInstruction)?;
+
+            // Layout of `SecpSignatureOffsets`, all integers being little endian
+            let [
+                _signature_offset_lo,
+                _signature_offset_hi,
+                signature_ix,
+                eth_address_offset_lo,
+                eth_address_offset_hi,
+                eth_address_ix,
+                message_offset_lo,
+                message_offset_hi,
+                message_size_lo,
+                message_size_hi,
+                message_ix,
+            ] = *offsets;
+
+            // Check: all the data was taken from the precompile instruction
+            if [signature_ix, eth_address_ix, message_ix]
+                .into_iter()
+                .any(|ix| u16::from(ix) != instruction_index)
+            {
+                return err!(GatewayError::InvalidPrecompileInstruction);
+            }
+
+            let eth_address_offset = usize::from(u16::from_le_bytes([
+                eth_address_offset_lo,
+                eth_address_offset_hi,
+            ]));
+            let message_offset =
+                usize::from(u16::from_le_bytes([message_offset_lo, message_offset_hi]));
+            let message_size = usize::from(u16::from_le_bytes([message_size_lo, message_size_hi]));
+
+            let eth_address = data
+                .get(eth_address_offset..eth_address_offset + ETH_ADDRESS_SIZE)
+                .and_then(|eth_address| eth_address.try_into().ok())
+                .ok_or(GatewayError::InvalidPrecompileInstruction)?;
+            let message = data
+                .get(message_offset..message_offset + message_size)
+                .ok_or(GatewayError::InvalidPrecompileInstruction)?;
+
+            Ok(PrecompiledSignature {
+                eth_address,
+                message,
+            })
+        })
+        .collect()
+}
+
+/// Builds the data of a secp256k1 precompile instruction verifying that each
+/// signer signed the same `message`.
+///
+/// `instruction_index` is the position the precompile instruction will have
+/// within the transaction.
+///
+/// # Errors
+/// - More than 255 signatures, or offsets that don't fit in 16 bits
+pub fn build_instruction_data(
+    instruction_index: u8,
+    signatures: &[([u8; ETH_ADDRESS_SIZE], EcdsaRecoverableSignature)],
+    message: &[u8],
+) -> Result<Vec<u8>> {
+    let too_large = |_err| error!(GatewayError::PrecompileInstructionTooLarge);
+    let count = u8::try_from(signatures.len()).map_err(too_large)?;
+    let offsets_end = DATA_START + signatures.len() * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    let entry_size = ETH_ADDRESS_SIZE + size_of::<EcdsaRecoverableSignature>();
+    let message_offset = offsets_end + signatures.len() * entry_size;
+
+    let to_u16 = |value: usize| u16::try_from(value).map_err(too_large);
+
+    let mut data = Vec::with_capacity(message_offset + message.len());
+    data.push(count);
+    for index in 0..signatures.len() {
+        let eth_address_offset = offsets_end + index * entry_size;
+        let signature_offset = eth_address_offset + ETH_ADDRESS_SIZE;
+
+        data.extend_from_slice(&to_u16(signature_offset)?.to_le_bytes());
+        data.push(instruction_index);
+        data.extend_from_slice(&to_u16(eth_address_offset)?.to_le_bytes());
+        data.push(instruction_index);
+        data.extend_from_slice(&to_u16(message_offset)?.to_le_bytes());
+        data.extend_from_slice(&to_u16(message.len())?.to_le_bytes());
+        data.push(instruction_index);
+    }
+    for (eth_address, signature) in signatures {
+        data.extend_from_slice(eth_address);
+        data.extend_from_slice(signature);
+    }
+    data.extend_from_slice(message);
+
+    Ok(data)
+}
+
+/// Derives the Ethereum address of a compressed secp256k1 public key.
+pub fn eth_address(pubkey: &Secp256k1Pubkey) -> Result<[u8; ETH_ADDRESS_SIZE]> {
+    let pubkey = libsecp256k1::PublicKey::parse_compressed(pubkey)
+        .map_err(|_err| GatewayError::InvalidDigitalSignature)?
+        .serialize();
+
+    // we drop the const prefix byte that indicates that this is an uncompressed
+    // pubkey
+    let full_pubkey = match pubkey {
+        [_tag, pubkey @ ..] => pubkey,
+    };
+    let hash = solana_keccak_hasher::hash(&full_pubkey).to_bytes();
+
+    // The address is made of the last 20 bytes of the hash
+    let eth_address = hash
+        .get(hash.len() - ETH_ADDRESS_SIZE..)
+        .and_then(|eth_address| eth_address.try_into().ok())
+        .ok_or(GatewayError::InvalidDigitalSignature)?;
+    Ok(eth_address)
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_and_parse_round_trip() {
+        let message = b"signed message";
+        let signatures = [([1_u8; 20], [2_u8; 65]), ([3_u8; 20], [4_u8; 65])];
+
+        let data = build_instruction_data(2, &signatures, message).unwrap();
+        let parsed = parse_signatures(2, &data).unwrap();
+
+        assert_eq!(parsed.len(), 2);
+        for (parsed, (eth_address, _)) in parsed.iter().zip(signatures.iter()) {
+            assert_eq!(parsed.eth_address, eth_address);
+            assert_eq!(parsed.message, message);
+        }
+    }
+
+    #[test]
+    fn build_rejects_oversized_instructions() {
+        let signatures = vec![([1_u8; 20], [2_u8; 65]); 256];
+        assert!(build_instruction_data(0, &signatures, b"message").is_err());
+
+        let message = vec![0_u8; usize::from(u16::MAX)];
+        assert!(build_instruction_data(0, &signatures[..1], &message).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_foreign_instruction_index() {
+        let data = build_instruction_data(0, &[([1_u8; 20], [2_u8; 65])], b"message").unwrap();
+
+        assert!(parse_signatures(1, &data).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_truncated_data() {
+        let data = build_instruction_data(0, &[([1_u8; 20], [2_u8; 65])], b"message").unwrap();
+
+        assert!(parse_signatures(0, &[]).is_err());
+        assert!(parse_signatures(0, &data[..data.len() - 1]).is_err());
+        assert!(parse_signatures(0, &data[..DATA_START + 4]).is_err());
+    }
+
+    #[test]
+    fn eth_address_matches_uncompressed_key_hash() {
+        let secret_key = libsecp256k1::SecretKey::parse(&[7_u8; 32]).unwrap();
+        let public_key = libsecp256k1::PublicKey::from_secret_key(&secret_key);
+
+        let uncompressed = public_key.serialize();
+        let hash = solana_keccak_hasher::hash(&uncompressed[1..]).to_bytes();
+
+        assert_eq!(
+            eth_address(&public_key.serialize_compressed()).unwrap(),
+            hash[12..]
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+/// Counter of the messages a sender emitted through `call_contract`,
+/// `call_contract_with_buffer` and `call_contract_with_hash`.
+///
+/// When the counter is passed along, the emitted event carries
+/// its current value before it gets incremented. Indexers can thus derive
+/// deterministic message ids, and detect gaps between them.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct CallContractNonce {
+    /// The nonce of the next message emitted for the sender.
+    pub next_nonce: u64,
+
+    /// The canonical bump for this account.
+    pub bump: u8,
+}
+
+impl CallContractNonce {
+    pub const SEED_PREFIX: &'static [u8] = b"call contract nonce";
+
+    pub fn pda_seeds<'a>(sender: &'a Pubkey) -> [&'a [u8]; 2] {
+        [Self::SEED_PREFIX, sender.as_ref()]
+    }
+
+    pub fn try_find_pda(sender: &Pubkey) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(&Self::pda_seeds(sender), &crate::ID)
+    }
+
+    pub fn find_pda(sender: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::pda_seeds(sender), &crate::ID)
+    }
+
+    /// Returns the nonce of the message being emitted, and moves on to the
+    /// next one.
+    pub fn advance(&mut self) -> Result<u64> {
+        let nonce = self.next_nonce;
+        self.next_nonce = nonce
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_returns_consecutive_nonces() {
+        let mut nonce = CallContractNonce {
+            next_nonce: 0,
+            bump: 255,
+        };
+
+        assert_eq!(nonce.advance().unwrap(), 0);
+        assert_eq!(nonce.advance().unwrap(), 1);
+        assert_eq!(nonce.next_nonce, 2);
+    }
+
+    #[test]
+    fn advance_fails_on_overflow() {
+        let mut nonce = CallContractNonce {
+            next_nonce: u64::MAX,
+            bump: 255,
+        };
+
+        assert!(nonce.advance().is_err());
+        assert_eq!(nonce.next_nonce, u64::MAX);
+    }
+}
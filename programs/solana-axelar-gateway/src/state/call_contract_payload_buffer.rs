@@ -0,0 +1,163 @@
+use crate::CallContractEvent;
+use anchor_lang::prelude::*;
+
+/// Holds an outbound payload that is too large to fit in a single
+/// `call_contract` instruction.
+///
+/// The payload gets uploaded in chunks over several transactions and is
+/// emitted by `call_contract_with_buffer`, which closes the buffer.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct CallContractPayloadBuffer {
+    /// The sender the payload will be emitted for. Either a direct signer or
+    /// a program signing with its `CallContractSigner` PDA.
+    pub sender: Pubkey,
+
+    /// The account that paid the rent for this PDA. Rent is refunded to it
+    /// once the buffer is closed.
+    pub payer: Pubkey,
+
+    /// The canonical bump for this account.
+    pub bump: u8,
+
+    /// The payload, allocated at its full size on initialization.
+    pub payload: Vec<u8>,
+}
+
+impl CallContractPayloadBuffer {
+    pub const SEED_PREFIX: &'static [u8] = b"call contract payload buffer";
+
+    /// The most instruction data a CPI can carry, which bounds the emitted
+    /// `CallContractEvent`.
+    pub const MAX_EVENT_DATA_LEN: usize = 10 * 1024;
+
+    /// Upper bound on the payload size, leaving 3 KiB of the event CPI
+    /// instruction data to the other `CallContractEvent` fields. Larger
+    /// payloads are sent with `call_contract_with_hash`.
+    pub const MAX_PAYLOAD_SIZE: usize = 7 * 1024;
+
+    pub fn pda_seeds<'a>(sender: &'a Pubkey, buffer_id: &'a [u8; 8]) -> [&'a [u8]; 3] {
+        [Self::SEED_PREFIX, sender.as_ref(), buffer_id]
+    }
+
+    pub fn try_find_pda(sender: &Pubkey, buffer_id: u64) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &Self::pda_seeds(sender, &buffer_id.to_le_bytes()),
+            &crate::ID,
+        )
+    }
+
+    pub fn find_pda(sender: &Pubkey, buffer_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &Self::pda_seeds(sender, &buffer_id.to_le_bytes()),
+            &crate::ID,
+        )
+    }
+
+    /// Calculates the space required for a `CallContractPayloadBuffer` account
+    /// with the Anchor discriminator included.
+    pub fn space_for(payload_size: usize) -> usize {
+        Self::DISCRIMINATOR.len() + // Anchor account discriminator
+            32 + // sender
+            32 + // payer
+            1 + // bump (u8)
+            4 + payload_size // payload
+    }
+
+    /// The length of the event CPI instruction data that emits this payload
+    /// as a `CallContractEvent`.
+    pub fn event_data_len(
+        &self,
+        destination_chain: &str,
+        destination_contract_address: &str,
+    ) -> usize {
+        8 + // event CPI tag
+            CallContractEvent::DISCRIMINATOR.len() + // event discriminator
+            32 + // sender
+            32 + // payload hash
+            4 + destination_chain.len() + // destination chain
+            4 + destination_contract_address.len() + // destination contract address
+            4 + self.payload.len() + // payload
+            1 + 8 // nonce, when the sender's `CallContractNonce` is passed
+    }
+
+    /// Copies `bytes` into the payload at `offset`, returning `None` if they
+    /// don't fit.
+    pub fn write(&mut self, offset: usize, bytes: &[u8]) -> Option<()> {
+        let end = offset.checked_add(bytes.len())?;
+        self.payload.get_mut(offset..end)?.copy_from_slice(bytes);
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(payload_size: usize) -> CallContractPayloadBuffer {
+        CallContractPayloadBuffer {
+            sender: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            bump: 1,
+            payload: vec![0; payload_size],
+        }
+    }
+
+    #[test]
+    fn space_matches_actual_size() {
+        for payload_size in [0, 1, 1024] {
+            let mut serialized = Vec::new();
+            buffer(payload_size)
+                .try_serialize(&mut serialized)
+                .expect("Failed to serialize");
+
+            assert_eq!(
+                CallContractPayloadBuffer::space_for(payload_size),
+                serialized.len()
+            );
+        }
+    }
+
+    #[test]
+    fn event_data_len_matches_emitted_event() {
+        let buffer = buffer(CallContractPayloadBuffer::MAX_PAYLOAD_SIZE);
+        let event = CallContractEvent {
+            sender: buffer.sender,
+            payload_hash: [7; 32],
+            destination_chain: "ethereum".to_owned(),
+            destination_contract_address: "0xDestinationContract".to_owned(),
+            payload: buffer.payload.clone(),
+            nonce: Some(3),
+        };
+
+        assert_eq!(
+            buffer.event_data_len(
+                &event.destination_chain,
+                &event.destination_contract_address
+            ),
+            anchor_lang::event::EVENT_IX_TAG_LE.len() + anchor_lang::Event::data(&event).len()
+        );
+    }
+
+    #[test]
+    fn write_within_bounds() {
+        let mut buffer = buffer(6);
+
+        assert_eq!(buffer.write(0, b"abc"), Some(()));
+        assert_eq!(buffer.write(3, b"def"), Some(()));
+        assert_eq!(buffer.write(6, b""), Some(()));
+
+        assert_eq!(buffer.payload, b"abcdef");
+    }
+
+    #[test]
+    fn write_out_of_bounds() {
+        let mut buffer = buffer(6);
+
+        assert_eq!(buffer.write(4, b"abc"), None);
+        assert_eq!(buffer.write(7, b""), None);
+        assert_eq!(buffer.write(usize::MAX, b"a"), None);
+
+        assert_eq!(buffer.payload, vec![0; 6]);
+    }
+}
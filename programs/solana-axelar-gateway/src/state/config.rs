@@ -1,4 +1,4 @@
-use crate::GatewayError;
+use crate::{GatewayError, GatewayErrorContext, VerifierSetEpoch};
 use anchor_lang::prelude::*;
 use solana_axelar_std::U256;
 
@@ -20,13 +20,45 @@ pub struct GatewayConfig {
     pub domain_separator: [u8; 32],
     /// The canonical bump for this account.
     pub bump: u8,
-    /// padding for bump
-    pub _padding: [u8; 7],
+    /// Whether the gateway is paused (0 = active, 1 = paused).
+    pub paused: u8,
+    /// The layout version of this account, see [`GatewayConfig::VERSION`].
+    pub version: u8,
+    /// Whether the destination chain registry has been initialized
+    /// (0 = no, 1 = yes), in which case calls have to pass it.
+    pub destination_chain_registry: u8,
+    /// How long (in seconds) an approved message can be executed for, copied
+    /// into each `IncomingMessage` on approval. 0 means approvals never expire.
+    pub message_approval_ttl: u32,
+    /// The account receiving the rent of pruned gateway accounts, such as
+    /// expired verifier set trackers. The default pubkey stands for the
+    /// operator.
+    pub rent_recipient: Pubkey,
+    /// Reserved for future fields, so that they can be added without
+    /// reallocating the account.
+    pub _reserved: [u8; 32],
 }
 
 impl GatewayConfig {
     pub const SEED_PREFIX: &'static [u8] = b"gateway";
 
+    /// The current layout version.
+    ///
+    /// Version 0 is the layout without the `_reserved` bytes, whose `version`
+    /// byte was still padding. Such accounts have to go through
+    /// `migrate_config` before they can be loaded again.
+    pub const VERSION: u8 = 1;
+
+    /// The space required for the current layout, with the Anchor
+    /// discriminator included.
+    pub const SPACE: usize = Self::DISCRIMINATOR.len() + std::mem::size_of::<Self>();
+
+    /// The space of version 0 accounts, with the Anchor discriminator included.
+    ///
+    /// Version 1 appended 64 bytes, which are split between `rent_recipient`
+    /// and `_reserved` since.
+    pub const V0_SPACE: usize = Self::SPACE - 64;
+
     pub fn pda_seeds<'a>() -> [&'a [u8]; 1] {
         [Self::SEED_PREFIX]
     }
@@ -39,6 +71,79 @@ impl GatewayConfig {
         Pubkey::find_program_address(&Self::pda_seeds(), &crate::ID)
     }
 
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    pub fn has_destination_chain_registry(&self) -> bool {
+        self.destination_chain_registry != 0
+    }
+
+    /// The account receiving the rent of pruned gateway accounts.
+    pub fn rent_recipient(&self) -> Pubkey {
+        if self.rent_recipient == Pubkey::default() {
+            self.operator
+        } else {
+            self.rent_recipient
+        }
+    }
+
+    /// Whether the verifier set rotated in at `epoch` can no longer sign.
+    pub fn is_epoch_expired(&self, epoch: U256) -> bool {
+        self.current_epoch
+            .checked_sub(epoch)
+            .is_none_or(|elapsed| elapsed >= self.previous_verifier_set_retention)
+    }
+
+    /// The epoch the next verifier set rotation moves to.
+    pub fn next_epoch(&self) -> Result<U256> {
+        self.current_epoch
+            .checked_add(U256::ONE)
+            .ok_or_else(|| error!(GatewayError::EpochCalculationOverflow))
+    }
+
+    /// The oldest epoch whose verifier set can still sign, `None` if no
+    /// verifier set is valid.
+    pub fn oldest_valid_epoch(&self) -> Option<U256> {
+        let retention = self.previous_verifier_set_retention;
+        if retention == U256::ZERO || self.current_epoch == U256::ZERO {
+            return None;
+        }
+
+        // Epochs start at 1
+        let oldest = self
+            .current_epoch
+            .checked_sub(retention)
+            .and_then(|epoch| epoch.checked_add(U256::ONE))
+            .unwrap_or(U256::ONE);
+        Some(oldest.max(U256::ONE))
+    }
+
+    /// All epochs whose verifier sets can still sign, from the oldest to the
+    /// current one.
+    pub fn valid_epochs(&self) -> impl Iterator<Item = U256> {
+        let current_epoch = self.current_epoch;
+        std::iter::successors(self.oldest_valid_epoch(), move |epoch| {
+            if *epoch < current_epoch {
+                epoch.checked_add(U256::ONE)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The `VerifierSetEpoch` PDA recording the current verifier set.
+    pub fn current_verifier_set_epoch_pda(&self) -> Pubkey {
+        VerifierSetEpoch::find_pda(self.current_epoch).0
+    }
+
+    /// The `VerifierSetEpoch` PDAs of all verifier sets that can still sign,
+    /// from the oldest to the current one.
+    pub fn valid_verifier_set_epoch_pdas(&self) -> impl Iterator<Item = Pubkey> {
+        self.valid_epochs()
+            .map(|epoch| VerifierSetEpoch::find_pda(epoch).0)
+    }
+
     pub fn assert_valid_epoch(&self, epoch: U256) -> Result<()> {
         let current_epoch = self.current_epoch;
         let elapsed = current_epoch
@@ -46,7 +151,13 @@ impl GatewayConfig {
             .ok_or(GatewayError::EpochCalculationOverflow)?;
 
         if elapsed >= self.previous_verifier_set_retention {
-            return err!(GatewayError::VerifierSetTooOld);
+            return Err(GatewayError::VerifierSetTooOld.with_context(
+                GatewayErrorContext::VerifierSetEpoch {
+                    epoch,
+                    current_epoch,
+                    retention: self.previous_verifier_set_retention,
+                },
+            ));
         }
         Ok(())
     }
@@ -74,3 +185,69 @@ pub struct InitializeConfigParams {
     /// how many n epochs do we consider valid
     pub previous_verifier_retention: U256,
 }
+
+/// Changes to apply to the [`GatewayConfig`], fields left as `None` are kept
+/// as is.
+#[derive(Debug, Clone, PartialEq, Eq, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateConfigParams {
+    /// the minimum delay required between rotations
+    pub minimum_rotation_delay: Option<u64>,
+    /// how many n epochs do we consider valid
+    pub previous_verifier_set_retention: Option<U256>,
+    /// The domain separator, used as an input for hashing payloads.
+    pub domain_separator: Option<[u8; 32]>,
+    /// The account receiving the rent of pruned gateway accounts, the
+    /// default pubkey standing for the operator.
+    pub rent_recipient: Option<Pubkey>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(current_epoch: u64, retention: u64) -> GatewayConfig {
+        GatewayConfig {
+            current_epoch: U256::from(current_epoch),
+            previous_verifier_set_retention: U256::from(retention),
+            ..bytemuck::Zeroable::zeroed()
+        }
+    }
+
+    fn valid_epochs(config: &GatewayConfig) -> Vec<U256> {
+        config.valid_epochs().collect()
+    }
+
+    #[test]
+    fn valid_epochs_within_retention() {
+        assert_eq!(
+            valid_epochs(&config(5, 2)),
+            vec![U256::from(4_u64), U256::from(5_u64)]
+        );
+        assert_eq!(valid_epochs(&config(5, 1)), vec![U256::from(5_u64)]);
+    }
+
+    #[test]
+    fn valid_epochs_start_at_first_epoch() {
+        assert_eq!(
+            valid_epochs(&config(2, 5)),
+            vec![U256::from(1_u64), U256::from(2_u64)]
+        );
+    }
+
+    #[test]
+    fn no_valid_epochs_without_retention() {
+        assert!(config(5, 0).oldest_valid_epoch().is_none());
+        assert!(valid_epochs(&config(5, 0)).is_empty());
+    }
+
+    #[test]
+    fn valid_epochs_match_assert_valid_epoch() {
+        let config = config(10, 3);
+        for epoch in 1..=10_u64 {
+            assert_eq!(
+                config.assert_valid_epoch(U256::from(epoch)).is_ok(),
+                valid_epochs(&config).contains(&U256::from(epoch)),
+            );
+        }
+    }
+}
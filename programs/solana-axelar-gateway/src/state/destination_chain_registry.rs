@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+/// Operator-managed list of the destination chains `call_contract` accepts.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct DestinationChainRegistry {
+    /// Names of the known destination chains
+    pub chains: Vec<String>,
+
+    /// The canonical bump for this account.
+    pub bump: u8,
+}
+
+impl DestinationChainRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"destination-chain-registry";
+
+    pub fn pda_seeds<'a>() -> [&'a [u8]; 1] {
+        [Self::SEED_PREFIX]
+    }
+
+    pub fn try_find_pda() -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(&Self::pda_seeds(), &crate::ID)
+    }
+
+    pub fn find_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::pda_seeds(), &crate::ID)
+    }
+
+    /// Calculates the space required for a `DestinationChainRegistry` account
+    /// with the Anchor discriminator included.
+    pub fn space_for(chains_total_len: usize) -> usize {
+        Self::DISCRIMINATOR.len() + // Anchor account discriminator
+            4 + chains_total_len + // chains
+            1 // bump (u8)
+    }
+
+    pub fn space(&self) -> usize {
+        Self::space_for(self.chains.iter().map(|chain| 4 + chain.len()).sum())
+    }
+
+    pub fn space_with_chain_added(&self, chain_name: &str) -> usize {
+        self.space() + 4 + chain_name.len()
+    }
+
+    pub fn space_with_chain_removed(&self, chain_name: &str) -> usize {
+        self.space().saturating_sub(4 + chain_name.len())
+    }
+
+    pub fn is_known_chain(&self, chain_name: &str) -> bool {
+        self.chains.iter().any(|chain| chain == chain_name)
+    }
+
+    /// Adds a chain to the registry, ignoring duplicates.
+    pub fn add_chain(&mut self, chain_name: String) {
+        if !self.is_known_chain(&chain_name) {
+            self.chains.push(chain_name);
+        }
+    }
+
+    /// Removes a chain from the registry.
+    pub fn remove_chain(&mut self, chain_name: &str) {
+        self.chains.retain(|chain| chain != chain_name);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::str_to_string)]
+mod tests {
+    use super::*;
+
+    fn serialized_len(registry: &DestinationChainRegistry) -> usize {
+        let mut serialized = Vec::new();
+        registry
+            .try_serialize(&mut serialized)
+            .expect("Failed to serialize");
+        serialized.len()
+    }
+
+    #[test]
+    fn space_matches_actual_size() {
+        let empty = DestinationChainRegistry {
+            chains: vec![],
+            bump: 1,
+        };
+        assert_eq!(empty.space(), serialized_len(&empty));
+
+        let registry = DestinationChainRegistry {
+            chains: vec!["ethereum".to_string(), "avalanche".to_string()],
+            bump: 1,
+        };
+        assert_eq!(registry.space(), serialized_len(&registry));
+    }
+
+    #[test]
+    fn space_with_chain_added_and_removed() {
+        let mut registry = DestinationChainRegistry {
+            chains: vec!["ethereum".to_string()],
+            bump: 1,
+        };
+
+        let space_after_add = registry.space_with_chain_added("avalanche");
+        registry.add_chain("avalanche".to_string());
+        assert_eq!(space_after_add, serialized_len(&registry));
+
+        let space_after_remove = registry.space_with_chain_removed("ethereum");
+        registry.remove_chain("ethereum");
+        assert_eq!(space_after_remove, serialized_len(&registry));
+    }
+
+    #[test]
+    fn add_chain_ignores_duplicates() {
+        let mut registry = DestinationChainRegistry {
+            chains: vec![],
+            bump: 1,
+        };
+
+        registry.add_chain("ethereum".to_string());
+        registry.add_chain("ethereum".to_string());
+
+        assert_eq!(registry.chains, vec!["ethereum".to_string()]);
+        assert!(registry.is_known_chain("ethereum"));
+        assert!(!registry.is_known_chain("ethereum-2"));
+    }
+}
@@ -11,11 +11,31 @@ pub struct IncomingMessage {
     pub status: MessageStatus,
     pub message_hash: [u8; 32],
     pub payload_hash: [u8; 32],
+    /// The account that paid the rent for this PDA on approval. Rent is
+    /// refunded to it once the message is executed and the PDA is closed.
+    pub funder: Pubkey,
+    /// Outcome reported by the destination program after executing the
+    /// message, if any.
+    pub execution_receipt: ExecutionReceipt,
+    pub _pad2: [u8; 1],
+    /// Timestamp (seconds) of when the message was approved
+    pub approved_at: i64,
+    /// Approval TTL (seconds) of the gateway when the message was approved,
+    /// 0 if the approval doesn't expire
+    pub approval_ttl: u32,
+    pub _pad3: [u8; 4],
 }
 
 impl IncomingMessage {
     pub const SEED_PREFIX: &'static [u8] = b"incoming message";
 
+    /// The space of current accounts, with the Anchor discriminator included.
+    pub const SPACE: usize = Self::DISCRIMINATOR.len() + std::mem::size_of::<Self>();
+
+    /// The space of version 0 accounts, approved before `funder` and the
+    /// following fields were appended.
+    pub const V0_SPACE: usize = Self::DISCRIMINATOR.len() + std::mem::offset_of!(Self, funder);
+
     pub fn pda_seeds<'a>(command_id: &'a [u8; 32]) -> [&'a [u8]; 2] {
         [Self::SEED_PREFIX, command_id]
     }
@@ -27,6 +47,27 @@ impl IncomingMessage {
     pub fn find_pda(command_id: &[u8; 32]) -> (Pubkey, u8) {
         Pubkey::find_program_address(&Self::pda_seeds(command_id), &crate::ID)
     }
+
+    /// Whether the approval can no longer be executed at `now`.
+    pub fn is_approval_expired(&self, now: i64) -> bool {
+        self.approval_ttl != 0
+            && now.saturating_sub(self.approved_at) > i64::from(self.approval_ttl)
+    }
+}
+
+/// Marker left at the `IncomingMessage` PDA address after an executed message
+/// has been closed.
+///
+/// Keeping the PDA allocated (instead of fully closing it) guarantees that
+/// `approve_message` can never `init` the same command id again.
+#[account(zero_copy)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct IncomingMessageTombstone {
+    pub bump: u8,
+}
+
+impl IncomingMessageTombstone {
+    pub const SPACE: usize = Self::DISCRIMINATOR.len() + std::mem::size_of::<Self>();
 }
 
 #[repr(C)]
@@ -45,6 +86,13 @@ impl MessageStatus {
         Self(1)
     }
 
+    /// Creates a `MessageStatus` value for an approval that was revoked
+    /// before execution.
+    #[must_use]
+    pub const fn revoked() -> Self {
+        Self(2)
+    }
+
     #[must_use]
     pub const fn is_approved(&self) -> bool {
         self.0 == 0
@@ -52,6 +100,50 @@ impl MessageStatus {
 
     #[must_use]
     pub const fn is_executed(&self) -> bool {
+        self.0 == 1
+    }
+
+    #[must_use]
+    pub const fn is_revoked(&self) -> bool {
+        self.0 == 2
+    }
+}
+
+/// Execution outcome recorded by the destination program of a message through
+/// `record_execution_receipt`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Pod, Zeroable, AnchorSerialize, AnchorDeserialize)]
+pub struct ExecutionReceipt(u8);
+
+impl ExecutionReceipt {
+    /// No receipt was recorded for the message.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self(0)
+    }
+
+    #[must_use]
+    pub const fn succeeded() -> Self {
+        Self(1)
+    }
+
+    #[must_use]
+    pub const fn failed() -> Self {
+        Self(2)
+    }
+
+    #[must_use]
+    pub const fn is_recorded(&self) -> bool {
         self.0 != 0
     }
+
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.0 == 1
+    }
+
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        self.0 == 2
+    }
 }
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+/// Holds the payload of an approved inbound message that is too large to be
+/// passed to the destination program in instruction data.
+///
+/// Relayers upload the payload in chunks over several transactions, then
+/// commit it once it matches `IncomingMessage::payload_hash`. Destination
+/// programs read committed payloads through the executable helpers.
+///
+/// The PDA is derived from the payer as well as the command id, so that no
+/// one can occupy the buffer of a message before its relayer does.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct MessagePayload {
+    /// The account that paid the rent for this PDA. Only it can write to the
+    /// buffer, and rent is refunded to it once the buffer is closed.
+    pub payer: Pubkey,
+
+    /// The canonical bump for this account.
+    pub bump: u8,
+
+    /// Whether the payload was checked against the message payload hash.
+    /// Committed payloads can't be written to anymore.
+    pub committed: bool,
+
+    /// The payload, allocated at its full size on initialization.
+    pub payload: Vec<u8>,
+}
+
+impl MessagePayload {
+    pub const SEED_PREFIX: &'static [u8] = b"message payload";
+
+    /// Upper bound on the payload size, keeping the buffer small enough to be
+    /// deserialized on the destination program's heap.
+    pub const MAX_PAYLOAD_SIZE: usize = 10 * 1024;
+
+    pub fn pda_seeds<'a>(command_id: &'a [u8; 32], payer: &'a Pubkey) -> [&'a [u8]; 3] {
+        [Self::SEED_PREFIX, command_id, payer.as_ref()]
+    }
+
+    pub fn try_find_pda(command_id: &[u8; 32], payer: &Pubkey) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(&Self::pda_seeds(command_id, payer), &crate::ID)
+    }
+
+    pub fn find_pda(command_id: &[u8; 32], payer: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::pda_seeds(command_id, payer), &crate::ID)
+    }
+
+    /// Calculates the space required for a `MessagePayload` account with the
+    /// Anchor discriminator included.
+    pub fn space_for(payload_size: usize) -> usize {
+        Self::DISCRIMINATOR.len() + // Anchor account discriminator
+            32 + // payer
+            1 + // bump (u8)
+            1 + // committed (bool)
+            4 + payload_size // payload
+    }
+
+    /// Copies `bytes` into the payload at `offset`, returning `None` if they
+    /// don't fit.
+    pub fn write(&mut self, offset: usize, bytes: &[u8]) -> Option<()> {
+        let end = offset.checked_add(bytes.len())?;
+        self.payload.get_mut(offset..end)?.copy_from_slice(bytes);
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn space_matches_actual_size() {
+        for payload_size in [0, 1, 1024] {
+            let message_payload = MessagePayload {
+                payer: Pubkey::new_unique(),
+                bump: 1,
+                committed: true,
+                payload: vec![0; payload_size],
+            };
+
+            let mut serialized = Vec::new();
+            message_payload
+                .try_serialize(&mut serialized)
+                .expect("Failed to serialize");
+
+            assert_eq!(MessagePayload::space_for(payload_size), serialized.len());
+        }
+    }
+}
@@ -15,3 +15,24 @@ pub use validate_message_signer::*;
 
 pub mod call_contract_signer;
 pub use call_contract_signer::*;
+
+pub mod destination_chain_registry;
+pub use destination_chain_registry::*;
+
+pub mod call_contract_payload_buffer;
+pub use call_contract_payload_buffer::*;
+
+pub mod message_payload;
+pub use message_payload::*;
+
+pub mod operatorship_proposal;
+pub use operatorship_proposal::*;
+
+pub mod verifier_set_epoch;
+pub use verifier_set_epoch::*;
+
+pub mod call_contract_nonce;
+pub use call_contract_nonce::*;
+
+pub mod signature_batch_buffer;
+pub use signature_batch_buffer::*;
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// A pending gateway operatorship transfer, created by the current operator and
+/// closed once the proposed operator accepts it or the proposal is cancelled.
+///
+/// The PDA is derived from both operators, and the proposal can only be
+/// accepted as long as the operator it was made for is still in place.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct OperatorshipProposal {
+    /// The operator at the time of the proposal
+    pub current_operator: Pubkey,
+
+    /// The account that has to accept the operatorship
+    pub proposed_operator: Pubkey,
+
+    /// The account that paid the rent for this PDA, refunded once it's closed
+    pub payer: Pubkey,
+
+    /// The canonical bump for this account.
+    pub bump: u8,
+}
+
+impl OperatorshipProposal {
+    pub const SEED_PREFIX: &'static [u8] = b"operatorship proposal";
+
+    pub fn pda_seeds<'a>(
+        current_operator: &'a Pubkey,
+        proposed_operator: &'a Pubkey,
+    ) -> [&'a [u8]; 3] {
+        [
+            Self::SEED_PREFIX,
+            current_operator.as_ref(),
+            proposed_operator.as_ref(),
+        ]
+    }
+
+    pub fn try_find_pda(
+        current_operator: &Pubkey,
+        proposed_operator: &Pubkey,
+    ) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &Self::pda_seeds(current_operator, proposed_operator),
+            &crate::ID,
+        )
+    }
+
+    pub fn find_pda(current_operator: &Pubkey, proposed_operator: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &Self::pda_seeds(current_operator, proposed_operator),
+            &crate::ID,
+        )
+    }
+}
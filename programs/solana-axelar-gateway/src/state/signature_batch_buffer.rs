@@ -0,0 +1,116 @@
+use crate::GatewayError;
+use anchor_lang::prelude::*;
+
+/// Holds a Borsh-serialized `SigningVerifierSetBatch` that is too large to fit
+/// in a single `verify_signatures_batch` instruction.
+///
+/// The batch gets uploaded in chunks over several transactions and is consumed
+/// by `verify_signatures_batch`, which closes the buffer.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct SignatureBatchBuffer {
+    /// The account that paid the rent for this PDA and uploads the batch. Rent
+    /// is refunded to it once the buffer is closed.
+    pub payer: Pubkey,
+
+    /// The canonical bump for this account.
+    pub bump: u8,
+
+    /// The serialized batch, allocated at its full size on initialization.
+    pub batch: Vec<u8>,
+}
+
+impl SignatureBatchBuffer {
+    pub const SEED_PREFIX: &'static [u8] = b"signature batch buffer";
+
+    /// Upper bound on the batch size, as accounts created through a CPI can't
+    /// be larger than 10 KiB.
+    pub const MAX_BATCH_SIZE: usize = 10 * 1024 - Self::space_for(0);
+
+    pub fn pda_seeds<'a>(payer: &'a Pubkey, buffer_id: &'a [u8; 8]) -> [&'a [u8]; 3] {
+        [Self::SEED_PREFIX, payer.as_ref(), buffer_id]
+    }
+
+    pub fn try_find_pda(payer: &Pubkey, buffer_id: u64) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &Self::pda_seeds(payer, &buffer_id.to_le_bytes()),
+            &crate::ID,
+        )
+    }
+
+    pub fn find_pda(payer: &Pubkey, buffer_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &Self::pda_seeds(payer, &buffer_id.to_le_bytes()),
+            &crate::ID,
+        )
+    }
+
+    /// Calculates the space required for a `SignatureBatchBuffer` account with
+    /// the Anchor discriminator included.
+    pub const fn space_for(batch_size: usize) -> usize {
+        Self::DISCRIMINATOR.len() + // Anchor account discriminator
+            32 + // payer
+            1 + // bump (u8)
+            4 + batch_size // batch
+    }
+
+    /// Like `space_for`, but fails if the batch is larger than `MAX_BATCH_SIZE`.
+    pub fn checked_space_for(batch_size: u32) -> Result<usize> {
+        let batch_size = batch_size as usize;
+        if batch_size > Self::MAX_BATCH_SIZE {
+            return err!(GatewayError::PayloadBufferTooLarge);
+        }
+
+        Ok(Self::space_for(batch_size))
+    }
+
+    /// Copies `bytes` into the batch at `offset`, returning `None` if they
+    /// don't fit.
+    pub fn write(&mut self, offset: usize, bytes: &[u8]) -> Option<()> {
+        let end = offset.checked_add(bytes.len())?;
+        self.batch.get_mut(offset..end)?.copy_from_slice(bytes);
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn space_matches_actual_size() {
+        for batch_size in [0, 1, SignatureBatchBuffer::MAX_BATCH_SIZE] {
+            let mut serialized = Vec::new();
+            SignatureBatchBuffer {
+                payer: Pubkey::new_unique(),
+                bump: 1,
+                batch: vec![0; batch_size],
+            }
+            .try_serialize(&mut serialized)
+            .expect("Failed to serialize");
+
+            assert_eq!(
+                SignatureBatchBuffer::space_for(batch_size),
+                serialized.len()
+            );
+        }
+        assert_eq!(
+            SignatureBatchBuffer::space_for(SignatureBatchBuffer::MAX_BATCH_SIZE),
+            10 * 1024
+        );
+    }
+
+    #[test]
+    fn checked_space_rejects_oversized_batch() {
+        let max_batch_size = u32::try_from(SignatureBatchBuffer::MAX_BATCH_SIZE).unwrap();
+
+        assert_eq!(
+            SignatureBatchBuffer::checked_space_for(max_batch_size).unwrap(),
+            10 * 1024
+        );
+        assert_eq!(
+            SignatureBatchBuffer::checked_space_for(max_batch_size + 1).unwrap_err(),
+            GatewayError::PayloadBufferTooLarge.into()
+        );
+    }
+}
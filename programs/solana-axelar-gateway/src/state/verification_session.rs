@@ -1,16 +1,21 @@
-use crate::GatewayError;
+use crate::{GatewayError, GatewayErrorContext};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 use bitvec::prelude::*;
 use solana_axelar_std::execute_data::prefixed_message_hash_payload_type;
 use solana_axelar_std::hasher::LeafHash;
+use solana_axelar_std::merkle::verify_single_leaf_proof;
 use solana_axelar_std::PayloadType;
 use solana_axelar_std::{
-    EcdsaRecoverableSignature, MerkleProof, Secp256k1Pubkey, SigningVerifierSetInfo,
-    VerifierSetLeaf, U128,
+    EcdsaRecoverableSignature, MerkleProof, PrecompiledVerifierSetInfo, Secp256k1Pubkey,
+    SigningVerifierSetBatch, SigningVerifierSetInfo, VerifierSetLeaf, U128,
 };
 
 /// This PDA tracks that all the signatures for a given payload get verified
+///
+/// Signature slots of verifiers past [`Self::INLINE_SIGNATURE_SLOTS`] live in a
+/// variable-length bitmap right after this struct in the account data, see
+/// [`Self::space_for`].
 #[account(zero_copy)]
 #[derive(Debug, PartialEq, Eq, Default)]
 #[allow(clippy::pub_underscore_fields)]
@@ -20,12 +25,49 @@ pub struct SignatureVerificationSessionData {
     /// Seed bump for this account's PDA
     pub bump: u8,
     /// Padding for memory alignment.
-    pub _pad: [u8; 15],
+    pub _pad: [u8; 1],
+    /// Number of messages approved using this session.
+    pub approved_message_count: u16,
+    /// Size of the approved message batch, as committed to by the message
+    /// leaves. Zero until the first message gets approved.
+    pub message_set_size: u16,
+    /// Padding for memory alignment.
+    pub _pad2: [u8; 10],
+    /// The account that paid the rent for this session. Rent is refunded to
+    /// it once the session gets closed.
+    pub payer: Pubkey,
 }
 
 impl SignatureVerificationSessionData {
     pub const SEED_PREFIX: &'static [u8] = b"gtw-sig-verif";
 
+    /// Number of signature slots tracked by the fixed-size bitmap in
+    /// [`SignatureVerification::signature_slots`].
+    pub const INLINE_SIGNATURE_SLOTS: usize = 256;
+
+    /// Account space of a session without any extended signature slots.
+    pub const SPACE: usize = Self::DISCRIMINATOR.len() + core::mem::size_of::<Self>();
+
+    /// Account space of a session able to track the signatures of a verifier
+    /// set with `set_size` verifiers.
+    pub fn space_for(set_size: u16) -> usize {
+        let extended_slots = usize::from(set_size).saturating_sub(Self::INLINE_SIGNATURE_SLOTS);
+        Self::SPACE.saturating_add(extended_slots.div_ceil(8))
+    }
+
+    /// Splits the data of a session account into the session itself and the
+    /// bitmap of its extended signature slots.
+    pub fn split_extended_slots(data: &mut [u8]) -> Result<(&mut Self, &mut [u8])> {
+        let (session, extended_slots) = data
+            .get_mut(Self::DISCRIMINATOR.len()..)
+            .and_then(|data| data.split_at_mut_checked(core::mem::size_of::<Self>()))
+            .ok_or(anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+        let session = bytemuck::try_from_bytes_mut(session)
+            .map_err(|_err| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+
+        Ok((session, extended_slots))
+    }
+
     pub fn pda_seeds<'a>(
         payload_merkle_root: &'a [u8; 32],
         payload_type_byte: &'a [u8; 1],
@@ -83,29 +125,41 @@ impl SignatureVerificationSessionData {
         self.signature_verification.is_valid()
     }
 
+    /// Records that a message from the batch signed in this session was approved.
+    pub fn record_approved_message(&mut self, set_size: u16) {
+        self.message_set_size = set_size;
+        self.approved_message_count = self.approved_message_count.saturating_add(1);
+    }
+
+    /// Whether every message from the signed batch has been approved.
+    pub fn all_messages_approved(&self) -> bool {
+        self.message_set_size != 0 && self.approved_message_count >= self.message_set_size
+    }
+
     pub fn process_signature(
         &mut self,
+        extended_slots: &mut [u8],
         payload_merkle_root: [u8; 32],
         verifier_set_merkle_root: &[u8; 32],
         verifier_info: SigningVerifierSetInfo,
     ) -> Result<()> {
-        // Check: Slot is already verified
-        self.check_slot_is_done(&verifier_info.leaf)?;
-
-        // Check: Merkle proof
-        let merkle_proof = MerkleProof::from_bytes(&verifier_info.merkle_proof)
-            .map_err(|_err| GatewayError::InvalidMerkleProof)?;
-
-        let leaf_hash = verifier_info.leaf.hash();
+        let leaf_error = |error: GatewayError| {
+            verifier_error(
+                error,
+                payload_merkle_root,
+                verifier_set_merkle_root,
+                &verifier_info.leaf,
+            )
+        };
 
-        if !merkle_proof.verify(
-            *verifier_set_merkle_root,
-            &[verifier_info.leaf.position.into()],
-            &[leaf_hash],
-            verifier_info.leaf.set_size.into(),
-        ) {
-            return err!(GatewayError::InvalidMerkleProof);
-        }
+        // Check: Slot is already verified, Merkle proof
+        self.check_verifier_leaf(
+            extended_slots,
+            verifier_set_merkle_root,
+            &verifier_info.leaf,
+            &verifier_info.merkle_proof,
+        )
+        .map_err(leaf_error)?;
 
         // Check: Digital signature
         if !Self::verify_ecdsa_signature(
@@ -114,12 +168,181 @@ impl SignatureVerificationSessionData {
             verifier_info.payload_type,
             &payload_merkle_root,
         ) {
-            return err!(GatewayError::SignatureVerificationFailed);
+            return Err(leaf_error(GatewayError::SignatureVerificationFailed));
         }
 
         // Update state
-        self.accumulate_threshold(&verifier_info.leaf)?;
-        self.mark_slot_done(&verifier_info.leaf)?;
+        self.record_signature(
+            extended_slots,
+            verifier_set_merkle_root,
+            &verifier_info.leaf,
+        )
+    }
+
+    /// Same as [`Self::process_signature`], for a verifier whose signature was
+    /// already checked by the secp256k1 precompile.
+    ///
+    /// The caller is responsible for ensuring that the precompile verified a
+    /// signature from this verifier over the session's payload.
+    pub fn process_precompiled_signature(
+        &mut self,
+        extended_slots: &mut [u8],
+        payload_merkle_root: [u8; 32],
+        verifier_set_merkle_root: &[u8; 32],
+        verifier_info: &PrecompiledVerifierSetInfo,
+    ) -> Result<()> {
+        // Check: Slot is already verified, Merkle proof
+        self.check_verifier_leaf(
+            extended_slots,
+            verifier_set_merkle_root,
+            &verifier_info.leaf,
+            &verifier_info.merkle_proof,
+        )
+        .map_err(|error| {
+            verifier_error(
+                error,
+                payload_merkle_root,
+                verifier_set_merkle_root,
+                &verifier_info.leaf,
+            )
+        })?;
+
+        // Update state
+        self.record_signature(
+            extended_slots,
+            verifier_set_merkle_root,
+            &verifier_info.leaf,
+        )
+    }
+
+    /// Same as [`Self::process_signature`], for a batch of verifiers proven by
+    /// a single Merkle multiproof.
+    pub fn process_signature_batch(
+        &mut self,
+        extended_slots: &mut [u8],
+        payload_merkle_root: [u8; 32],
+        verifier_set_merkle_root: &[u8; 32],
+        batch: &SigningVerifierSetBatch,
+    ) -> Result<()> {
+        let Some(set_size) = batch
+            .verifiers
+            .first()
+            .map(|verifier| verifier.leaf.set_size)
+        else {
+            return err!(GatewayError::InvalidSignerBatch);
+        };
+
+        // Check: verifiers are ordered by position, so none of them is repeated
+        let ordered = batch
+            .verifiers
+            .is_sorted_by(|previous, next| previous.leaf.position < next.leaf.position);
+        if !ordered {
+            return err!(GatewayError::InvalidSignerBatch);
+        }
+
+        // Check: Slots are already verified
+        for verifier in &batch.verifiers {
+            self.check_slot_is_done(extended_slots, &verifier.leaf)
+                .map_err(|error| {
+                    verifier_error(
+                        error,
+                        payload_merkle_root,
+                        verifier_set_merkle_root,
+                        &verifier.leaf,
+                    )
+                })?;
+        }
+
+        // Check: Merkle multiproof
+        let invalid_merkle_proof = || {
+            GatewayError::InvalidMerkleProof.with_context(GatewayErrorContext::VerifierBatch {
+                payload_merkle_root,
+                verifier_set_hash: *verifier_set_merkle_root,
+                positions: batch
+                    .verifiers
+                    .iter()
+                    .map(|verifier| verifier.leaf.position)
+                    .collect(),
+                set_size,
+            })
+        };
+        let merkle_proof =
+            MerkleProof::from_bytes(&batch.merkle_proof).map_err(|_err| invalid_merkle_proof())?;
+        let (indices, leaf_hashes): (Vec<usize>, Vec<[u8; 32]>) = batch
+            .verifiers
+            .iter()
+            .map(|verifier| (verifier.leaf.position.into(), verifier.leaf.hash()))
+            .unzip();
+        let same_set_size = batch
+            .verifiers
+            .iter()
+            .all(|verifier| verifier.leaf.set_size == set_size);
+        if !same_set_size
+            || !merkle_proof.verify(
+                *verifier_set_merkle_root,
+                &indices,
+                &leaf_hashes,
+                set_size.into(),
+            )
+        {
+            return Err(invalid_merkle_proof());
+        }
+
+        for verifier in &batch.verifiers {
+            // Check: Digital signature
+            if !Self::verify_ecdsa_signature(
+                &verifier.leaf.signer_pubkey.0,
+                &verifier.signature.0,
+                batch.payload_type,
+                &payload_merkle_root,
+            ) {
+                return Err(verifier_error(
+                    GatewayError::SignatureVerificationFailed,
+                    payload_merkle_root,
+                    verifier_set_merkle_root,
+                    &verifier.leaf,
+                ));
+            }
+
+            // Update state
+            self.record_signature(extended_slots, verifier_set_merkle_root, &verifier.leaf)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_verifier_leaf(
+        &self,
+        extended_slots: &[u8],
+        verifier_set_merkle_root: &[u8; 32],
+        leaf: &VerifierSetLeaf,
+        merkle_proof: &[u8],
+    ) -> core::result::Result<(), GatewayError> {
+        // Check: Slot is already verified
+        self.check_slot_is_done(extended_slots, leaf)?;
+
+        // Check: Merkle proof
+        if !verify_single_leaf_proof(
+            merkle_proof,
+            verifier_set_merkle_root,
+            leaf.position.into(),
+            leaf.set_size.into(),
+            leaf.hash(),
+        ) {
+            return Err(GatewayError::InvalidMerkleProof);
+        }
+
+        Ok(())
+    }
+
+    fn record_signature(
+        &mut self,
+        extended_slots: &mut [u8],
+        verifier_set_merkle_root: &[u8; 32],
+        leaf: &VerifierSetLeaf,
+    ) -> Result<()> {
+        self.accumulate_threshold(leaf)?;
+        self.mark_slot_done(extended_slots, leaf)?;
         self.verify_verifier_set(verifier_set_merkle_root)?;
 
         Ok(())
@@ -162,37 +385,55 @@ impl SignatureVerificationSessionData {
         recovered_uncompressed_pubkey.to_bytes() == full_pubkey
     }
 
-    fn check_slot_is_done(&self, signature_node: &VerifierSetLeaf) -> Result<()> {
-        let signature_slots = self
-            .signature_verification
-            .signature_slots
-            .view_bits::<Lsb0>();
+    fn check_slot_is_done(
+        &self,
+        extended_slots: &[u8],
+        signature_node: &VerifierSetLeaf,
+    ) -> core::result::Result<(), GatewayError> {
         let position: usize = signature_node.position.into();
+        let slot = match position.checked_sub(Self::INLINE_SIGNATURE_SLOTS) {
+            None => self
+                .signature_verification
+                .signature_slots
+                .view_bits::<Lsb0>()
+                .get(position),
+            Some(extended_position) => extended_slots.view_bits::<Lsb0>().get(extended_position),
+        };
 
-        let Some(slot) = signature_slots.get(position) else {
+        let Some(slot) = slot else {
             // Index is out of bounds.
-            return err!(GatewayError::SlotIsOutOfBounds);
+            return Err(GatewayError::SlotIsOutOfBounds);
         };
         // Check if signature slot was already verified.
         if *slot {
-            return err!(GatewayError::SlotAlreadyVerified);
+            return Err(GatewayError::SlotAlreadyVerified);
         }
         Ok(())
     }
 
-    fn mark_slot_done(&mut self, signature_node: &VerifierSetLeaf) -> Result<()> {
-        let signature_slots = self
-            .signature_verification
-            .signature_slots
-            .view_bits_mut::<Lsb0>();
+    fn mark_slot_done(
+        &mut self,
+        extended_slots: &mut [u8],
+        signature_node: &VerifierSetLeaf,
+    ) -> core::result::Result<(), GatewayError> {
         let position: usize = signature_node.position.into();
-        let Some(slot) = signature_slots.get_mut(position) else {
+        let slot = match position.checked_sub(Self::INLINE_SIGNATURE_SLOTS) {
+            None => self
+                .signature_verification
+                .signature_slots
+                .view_bits_mut::<Lsb0>()
+                .get_mut(position),
+            Some(extended_position) => extended_slots
+                .view_bits_mut::<Lsb0>()
+                .get_mut(extended_position),
+        };
+        let Some(slot) = slot else {
             // Index is out of bounds.
-            return err!(GatewayError::SlotIsOutOfBounds);
+            return Err(GatewayError::SlotIsOutOfBounds);
         };
         // Check if signature slot was already verified.
         if *slot {
-            return err!(GatewayError::SlotAlreadyVerified);
+            return Err(GatewayError::SlotAlreadyVerified);
         }
         slot.commit(true);
         Ok(())
@@ -215,13 +456,33 @@ impl SignatureVerificationSessionData {
     #[inline]
     fn verify_verifier_set(&self, expected_hash: &[u8; 32]) -> Result<()> {
         if self.signature_verification.signing_verifier_set_hash != *expected_hash {
-            return err!(GatewayError::InvalidDigitalSignature);
+            return Err(GatewayError::InvalidDigitalSignature.with_context(
+                GatewayErrorContext::VerifierSetHash {
+                    expected: self.signature_verification.signing_verifier_set_hash,
+                    actual: *expected_hash,
+                },
+            ));
         }
 
         Ok(())
     }
 }
 
+/// Attaches the context of the verifier at `leaf` to `error`.
+fn verifier_error(
+    error: GatewayError,
+    payload_merkle_root: [u8; 32],
+    verifier_set_hash: &[u8; 32],
+    leaf: &VerifierSetLeaf,
+) -> Error {
+    error.with_context(GatewayErrorContext::Verifier {
+        payload_merkle_root,
+        verifier_set_hash: *verifier_set_hash,
+        position: leaf.position,
+        set_size: leaf.set_size,
+    })
+}
+
 /// Controls the signature verification session for a given payload.
 #[zero_copy]
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -241,9 +502,9 @@ pub struct SignatureVerification {
     /// from being verified more than once, avoiding deliberate attempts to
     /// decrement the remaining threshold.
     ///
-    /// Currently supports 256 slots. If the signer set maximum size needs to be
-    /// increased in the future, this value must change to make room for
-    /// them.
+    /// Supports the first 256 slots. Slots of larger verifier sets are kept
+    /// in the session account right after this struct, once the session was
+    /// extended with `extend_verification_session`.
     pub signature_slots: [u8; 32],
 
     /// Upon the first successful signature validation, we set the hash of the
@@ -264,6 +525,7 @@ mod tests {
     use core::mem::size_of;
 
     use super::*;
+    use solana_axelar_std::PublicKey;
 
     #[test]
     fn initialization() {
@@ -303,6 +565,66 @@ mod tests {
         assert_eq!(&updated_state, deserialized);
     }
 
+    #[test]
+    fn space_for_verifier_set_size() {
+        let space = SignatureVerificationSessionData::SPACE;
+        assert_eq!(SignatureVerificationSessionData::space_for(2), space);
+        assert_eq!(SignatureVerificationSessionData::space_for(256), space);
+        assert_eq!(SignatureVerificationSessionData::space_for(257), space + 1);
+        assert_eq!(SignatureVerificationSessionData::space_for(264), space + 1);
+        assert_eq!(
+            SignatureVerificationSessionData::space_for(u16::MAX),
+            space + 8160
+        );
+    }
+
+    #[test]
+    fn extended_signature_slots() {
+        let space = SignatureVerificationSessionData::space_for(300);
+        // u64 backing storage keeps the session 8-byte aligned
+        let mut buffer = vec![0_u64; space.div_ceil(8)];
+        let data = bytemuck::cast_slice_mut::<u64, u8>(&mut buffer)
+            .get_mut(..space)
+            .unwrap();
+        let (session, extended_slots) =
+            SignatureVerificationSessionData::split_extended_slots(data).unwrap();
+        assert_eq!(extended_slots.len(), 6);
+
+        let leaf = |position| VerifierSetLeaf {
+            nonce: 0,
+            quorum: 1,
+            signer_pubkey: PublicKey([0; 33]),
+            signer_weight: 1,
+            position,
+            set_size: 300,
+            domain_separator: [0; 32],
+        };
+
+        for position in [0, 255, 256, 299] {
+            assert!(session
+                .check_slot_is_done(extended_slots, &leaf(position))
+                .is_ok());
+            session
+                .mark_slot_done(extended_slots, &leaf(position))
+                .unwrap();
+            assert!(matches!(
+                session.check_slot_is_done(extended_slots, &leaf(position)),
+                Err(GatewayError::SlotAlreadyVerified)
+            ));
+        }
+        assert_eq!(extended_slots, [0b0000_0001, 0, 0, 0, 0, 0b0000_1000]);
+
+        // The bitmap is rounded up to whole bytes, anything past it is out of bounds
+        assert!(matches!(
+            session.check_slot_is_done(extended_slots, &leaf(304)),
+            Err(GatewayError::SlotIsOutOfBounds)
+        ));
+        assert!(matches!(
+            session.mark_slot_done(extended_slots, &leaf(304)),
+            Err(GatewayError::SlotIsOutOfBounds)
+        ));
+    }
+
     #[test]
     fn alignment_compatibility() {
         // Critical: alignment must be ≤ 8 to work with Anchor's 8-byte discriminator
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use solana_axelar_std::U256;
+
+use crate::VerifierSetTracker;
+
+/// Records which verifier set was active at a given epoch.
+///
+/// Together with [`crate::GatewayConfig::current_epoch`], these PDAs let
+/// clients resolve the current and still valid verifier sets by deriving
+/// addresses, instead of scanning all `VerifierSetTracker` accounts.
+#[account(zero_copy)]
+#[derive(Debug, PartialEq, Eq)]
+#[allow(clippy::pub_underscore_fields)]
+pub struct VerifierSetEpoch {
+    /// The canonical bump for this account.
+    pub bump: u8,
+    /// Padding for the bump
+    pub _padding: [u8; 7],
+    /// The epoch the verifier set was rotated in at
+    pub epoch: U256,
+    /// The verifier set hash
+    pub verifier_set_hash: [u8; 32],
+}
+
+impl VerifierSetEpoch {
+    pub const SEED_PREFIX: &'static [u8] = b"ver-set-epoch";
+
+    pub fn pda_seeds(epoch_bytes: &[u8; 32]) -> [&[u8]; 2] {
+        [Self::SEED_PREFIX, epoch_bytes]
+    }
+
+    pub fn try_find_pda(epoch: U256) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(&Self::pda_seeds(&epoch.to_le_bytes()), &crate::ID)
+    }
+
+    pub fn find_pda(epoch: U256) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::pda_seeds(&epoch.to_le_bytes()), &crate::ID)
+    }
+
+    /// The `VerifierSetTracker` PDA of the recorded verifier set.
+    pub fn verifier_set_tracker_pda(&self) -> Pubkey {
+        VerifierSetTracker::find_pda(&self.verifier_set_hash).0
+    }
+}
@@ -30,3 +30,18 @@ impl VerifierSetTracker {
         Pubkey::find_program_address(&Self::pda_seeds(verifier_set_hash), &crate::ID)
     }
 }
+
+/// What remains of a `VerifierSetTracker` once its verifier set expired and
+/// the tracker was closed.
+///
+/// The PDA is kept allocated so that `rotate_signers` can never `init` a
+/// tracker for the same verifier set hash again.
+#[account(zero_copy)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifierSetTrackerTombstone {
+    pub bump: u8,
+}
+
+impl VerifierSetTrackerTombstone {
+    pub const SPACE: usize = Self::DISCRIMINATOR.len() + std::mem::size_of::<Self>();
+}
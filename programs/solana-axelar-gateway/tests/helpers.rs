@@ -3,12 +3,20 @@
 use std::collections::BTreeMap;
 
 use anchor_lang::prelude::AnchorDeserialize;
-use mollusk_harness::gateway::create_verifier_info;
-use mollusk_harness::GatewayTestHarness;
-use solana_axelar_gateway::GatewayError;
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use base64::Engine;
+use mollusk_harness::gateway::{create_verifier_info, generate_random_signer};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use solana_axelar_gateway::{
+    GatewayConfig, GatewayError, GatewayErrorEvent, IncomingMessage, ValidateMessageSigner,
+    VerifierSetTracker,
+};
+use solana_axelar_std::hasher::LeafHash;
 use solana_axelar_std::{
-    Message, Payload, PayloadType, PublicKey, SigningVerifierSetInfo, VerifierSet,
+    MerkleTree, Message, Payload, PayloadType, PublicKey, SigningVerifierSetInfo, VerifierSet,
+    VerifierSetLeaf,
 };
+use solana_sdk::pubkey::Pubkey;
 
 /// Helper to convert gateway errors to ProgramError for Check::err.
 pub fn gateway_err(e: GatewayError) -> solana_sdk::program_error::ProgramError {
@@ -16,6 +24,23 @@ pub fn gateway_err(e: GatewayError) -> solana_sdk::program_error::ProgramError {
     anchor_err.into()
 }
 
+/// Decodes the [`GatewayErrorEvent`]s out of the `Program data:` entries of
+/// the recorded logs.
+pub fn logged_error_events(logs: &[String]) -> Vec<GatewayErrorEvent> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .map(|data| {
+            base64::engine::general_purpose::STANDARD
+                .decode(data)
+                .expect("event data should be base64")
+        })
+        .filter_map(|data| {
+            data.strip_prefix(GatewayErrorEvent::DISCRIMINATOR)
+                .map(|mut event| GatewayErrorEvent::deserialize(&mut event).unwrap())
+        })
+        .collect()
+}
+
 pub fn default_messages() -> Vec<Message> {
     vec![
         Message {
@@ -140,3 +165,129 @@ pub fn compute_new_verifier_set_hash(
     )
     .expect("hash_payload should succeed")
 }
+
+/// Rotates the harness gateway to a verifier set of `set_size` secp256k1
+/// verifiers of weight one.
+pub fn rotate_to_large_verifier_set(harness: &mut GatewayTestHarness, set_size: u16, quorum: u128) {
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+
+    let secret_keys = (0..set_size)
+        .map(|_| {
+            let (secret_key, compressed_pubkey) = generate_random_signer();
+            (PublicKey(compressed_pubkey), secret_key)
+        })
+        .collect::<BTreeMap<_, _>>();
+    let new_verifier_set = VerifierSet {
+        nonce: 1,
+        signers: secret_keys.keys().map(|pubkey| (*pubkey, 1_u128)).collect(),
+        quorum,
+    };
+
+    let new_verifier_set_hash =
+        compute_new_verifier_set_hash(config.domain_separator, &new_verifier_set);
+    let verification_session_pda = harness
+        .init_payload_verification_session(new_verifier_set_hash, PayloadType::RotateSigners);
+    harness.verify_all_signatures(new_verifier_set_hash, PayloadType::RotateSigners);
+    harness.rotate_signers(new_verifier_set_hash, verification_session_pda);
+
+    let leaves = secret_keys
+        .keys()
+        .enumerate()
+        .map(|(position, signer_pubkey)| VerifierSetLeaf {
+            nonce: new_verifier_set.nonce,
+            quorum,
+            signer_pubkey: *signer_pubkey,
+            signer_weight: 1,
+            position: u16::try_from(position).unwrap(),
+            set_size,
+            domain_separator: config.domain_separator,
+        })
+        .collect::<Vec<_>>();
+    let leaf_hashes = leaves.iter().map(VerifierSetLeaf::hash).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::from_leaves(&leaf_hashes);
+    assert_eq!(merkle_tree.root(), Some(new_verifier_set_hash));
+
+    harness.gateway.verifier_set_tracker = VerifierSetTracker::find_pda(&new_verifier_set_hash).0;
+    harness.gateway.signers = secret_keys.into_values().collect();
+    harness.gateway.verifier_set_leaves = leaves;
+    harness.gateway.verifier_merkle_tree = merkle_tree;
+}
+
+/// Approves the first default message and returns (message, incoming_message_pda).
+pub fn approve_first_message(harness: &GatewayTestHarness) -> (Message, Pubkey) {
+    approve_first_of_messages(harness, &default_messages())
+}
+
+/// Approves the first of the given messages and returns (message, incoming_message_pda).
+pub fn approve_first_of_messages(
+    harness: &GatewayTestHarness,
+    messages: &[Message],
+) -> (Message, Pubkey) {
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+
+    let (merklized_messages, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, messages);
+
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+
+    let verifier_infos =
+        build_verifier_infos(harness, payload_merkle_root, PayloadType::ApproveMessages);
+    for info in &verifier_infos {
+        harness.verify_signature(payload_merkle_root, info.clone());
+    }
+
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+
+    let incoming_message_pda = IncomingMessage::find_pda(&messages[0].command_id()).0;
+    (messages[0].clone(), incoming_message_pda)
+}
+
+/// Builds a ValidateMessage instruction for the given message.
+/// The `caller` is set to the signing PDA derived from the message's destination address.
+pub fn build_validate_message_ix(
+    harness: &GatewayTestHarness,
+    message: &Message,
+    incoming_message_pda: Pubkey,
+) -> solana_sdk::instruction::Instruction {
+    let incoming_message: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+
+    let destination_address: Pubkey = message.destination_address.parse().unwrap();
+    let command_id = message.command_id();
+
+    let caller = ValidateMessageSigner::create_pda(
+        &command_id,
+        incoming_message.signing_pda_bump,
+        &destination_address,
+    )
+    .expect("valid signing PDA");
+
+    let (event_authority, _, _) =
+        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+    solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::ValidateMessage {
+            incoming_message_pda,
+            caller,
+            gateway_root_pda: harness.gateway.root,
+            event_authority,
+            program: solana_axelar_gateway::ID,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::ValidateMessage {
+            message: message.clone(),
+        }
+        .data(),
+    }
+}
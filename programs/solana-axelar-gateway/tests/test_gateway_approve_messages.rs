@@ -0,0 +1,245 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{
+    GatewayConfig, GatewayError, GatewayErrorContext, GatewayErrorEvent, IncomingMessage,
+    MessageStatus, SignatureVerificationSessionData,
+};
+use solana_axelar_std::execute_data::merklize_message_batch;
+use solana_axelar_std::hasher::{Hasher, LeafHash};
+use solana_axelar_std::{MerklizedMessageBatch, Message, Messages, PayloadType};
+use solana_sdk::pubkey::Pubkey;
+use solana_svm_log_collector::LogCollector;
+
+/// Builds a batch covering all the given messages, and completes a
+/// verification session for its payload.
+fn signed_message_batch(
+    harness: &GatewayTestHarness,
+    messages: &[Message],
+) -> (Pubkey, [u8; 32], MerklizedMessageBatch) {
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+
+    let positions = (0..messages.len())
+        .map(|position| u16::try_from(position).unwrap())
+        .collect::<Vec<_>>();
+    let (payload_merkle_root, merklized_batch) = merklize_message_batch::<Hasher>(
+        Messages(messages.to_vec()),
+        config.domain_separator,
+        &positions,
+    )
+    .expect("batch should merklize");
+
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+    harness.verify_all_signatures(payload_merkle_root, PayloadType::ApproveMessages);
+
+    (
+        verification_session_pda,
+        payload_merkle_root,
+        merklized_batch,
+    )
+}
+
+#[test]
+fn approve_messages_batch() {
+    let harness = GatewayTestHarness::new();
+    let messages = default_messages();
+
+    let (verification_session_pda, payload_merkle_root, merklized_batch) =
+        signed_message_batch(&harness, &messages);
+
+    harness.approve_messages(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+    );
+
+    for message in &messages {
+        let incoming_message_pda = IncomingMessage::find_pda(&message.command_id());
+        let incoming_message: IncomingMessage = harness
+            .get_account_as(&incoming_message_pda.0)
+            .expect("incoming message should exist");
+
+        assert_eq!(incoming_message.bump, incoming_message_pda.1);
+        assert_eq!(incoming_message.status, MessageStatus::approved());
+        assert_eq!(incoming_message.message_hash, message.hash());
+        assert_eq!(incoming_message.payload_hash, message.payload_hash);
+        assert_eq!(incoming_message.funder, harness.payer);
+    }
+
+    let session: SignatureVerificationSessionData = harness
+        .get_account_as(&verification_session_pda)
+        .expect("session should exist");
+    assert!(session.all_messages_approved());
+}
+
+#[test]
+fn approve_messages_partial_batch() {
+    let harness = GatewayTestHarness::new();
+    let mut messages = default_messages();
+    messages.extend(fake_messages());
+
+    let (verification_session_pda, payload_merkle_root, mut merklized_batch) =
+        signed_message_batch(&harness, &messages);
+
+    // Drop the first leaf; the proof no longer matches the remaining leaves
+    merklized_batch.leaves.remove(0);
+    harness.approve_messages_with_checks(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+        &[Check::err(gateway_err(
+            GatewayError::LeafNodeNotPartOfMerkleRoot,
+        ))],
+    );
+
+    // A proof built for a subset of the positions is accepted
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    let (_, merklized_batch) = merklize_message_batch::<Hasher>(
+        Messages(messages.clone()),
+        config.domain_separator,
+        &[1, 3],
+    )
+    .unwrap();
+
+    harness.approve_messages(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+    );
+
+    assert!(!harness.account_exists(&IncomingMessage::find_pda(&messages[0].command_id()).0));
+    assert!(harness.account_exists(&IncomingMessage::find_pda(&messages[1].command_id()).0));
+
+    let session: SignatureVerificationSessionData = harness
+        .get_account_as(&verification_session_pda)
+        .expect("session should exist");
+    assert_eq!(session.approved_message_count, 2);
+    assert!(!session.all_messages_approved());
+}
+
+#[test]
+fn approve_messages_tampered_leaf() {
+    let harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, mut merklized_batch) =
+        signed_message_batch(&harness, &default_messages());
+
+    merklized_batch.leaves[1].message.payload_hash = [0xff; 32];
+
+    harness.approve_messages_with_checks(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+        &[Check::err(gateway_err(
+            GatewayError::LeafNodeNotPartOfMerkleRoot,
+        ))],
+    );
+}
+
+#[test]
+fn approve_messages_tampered_leaf_logs_error_context() {
+    let mut harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, mut merklized_batch) =
+        signed_message_batch(&harness, &default_messages());
+    merklized_batch.leaves[1].message.payload_hash = [0xff; 32];
+
+    let logger = LogCollector::new_ref();
+    harness.ctx.mollusk.logger = Some(logger.clone());
+
+    harness.approve_messages_with_checks(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+        &[Check::err(gateway_err(
+            GatewayError::LeafNodeNotPartOfMerkleRoot,
+        ))],
+    );
+
+    let events = logged_error_events(logger.borrow().get_recorded_content());
+    assert_eq!(
+        events,
+        vec![GatewayErrorEvent {
+            error_code: GatewayError::LeafNodeNotPartOfMerkleRoot.into(),
+            context: GatewayErrorContext::MessageBatch {
+                payload_merkle_root,
+                positions: vec![0, 1],
+                set_size: 2,
+            },
+        }]
+    );
+}
+
+#[test]
+fn approve_messages_unordered_leaves() {
+    let harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, mut merklized_batch) =
+        signed_message_batch(&harness, &default_messages());
+
+    merklized_batch.leaves.reverse();
+
+    harness.approve_messages_with_checks(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+        &[Check::err(gateway_err(GatewayError::InvalidMessageBatch))],
+    );
+}
+
+#[test]
+fn approve_messages_empty_batch() {
+    let harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, mut merklized_batch) =
+        signed_message_batch(&harness, &default_messages());
+
+    merklized_batch.leaves.clear();
+
+    harness.approve_messages_with_checks(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+        &[Check::err(gateway_err(GatewayError::EmptyMessageBatch))],
+    );
+}
+
+#[test]
+fn approve_messages_already_approved() {
+    let harness = GatewayTestHarness::new();
+    let messages = default_messages();
+
+    let (verification_session_pda, payload_merkle_root, merklized_batch) =
+        signed_message_batch(&harness, &messages);
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    let (merklized_messages, _) = create_merklized_messages(config.domain_separator, &messages);
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+
+    let result = harness.approve_messages_with_checks(
+        &merklized_batch,
+        payload_merkle_root,
+        verification_session_pda,
+        &[],
+    );
+    assert!(result.program_result.is_err());
+
+    // The whole batch is reverted
+    assert!(!harness.account_exists(&IncomingMessage::find_pda(&messages[1].command_id()).0));
+}
@@ -0,0 +1,205 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{CallContractPayloadBuffer, GatewayError};
+use solana_sdk::pubkey::Pubkey;
+
+fn direct_signer(harness: &GatewayTestHarness) -> Pubkey {
+    let signer = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(signer, solana_sdk::native_token::LAMPORTS_PER_SOL);
+    signer
+}
+
+fn payload_of_size(size: usize) -> Vec<u8> {
+    (0..size).map(|i| u8::try_from(i % 251).unwrap()).collect()
+}
+
+fn large_payload() -> Vec<u8> {
+    payload_of_size(4096)
+}
+
+#[test]
+fn call_contract_with_large_payload() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let buffer_id = 7;
+    let payload = large_payload();
+
+    harness.upload_call_contract_buffer(sender, buffer_id, &payload, 900);
+
+    let buffer_pda = CallContractPayloadBuffer::find_pda(&sender, buffer_id).0;
+    let buffer: CallContractPayloadBuffer = harness
+        .get_account_as(&buffer_pda)
+        .expect("payload buffer should exist");
+    assert_eq!(buffer.sender, sender);
+    assert_eq!(buffer.payload, payload);
+
+    let sender_lamports_before = harness
+        .get_account(&sender)
+        .expect("sender should exist")
+        .lamports;
+    let buffer_lamports = harness
+        .get_account(&buffer_pda)
+        .expect("payload buffer should exist")
+        .lamports;
+
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        &[Check::success()],
+    );
+
+    // The buffer is closed and its rent refunded to the payer
+    assert!(harness
+        .get_account_as::<CallContractPayloadBuffer>(&buffer_pda)
+        .is_none());
+    let sender_lamports_after = harness
+        .get_account(&sender)
+        .expect("sender should exist")
+        .lamports;
+    assert_eq!(
+        sender_lamports_after,
+        sender_lamports_before + buffer_lamports
+    );
+}
+
+#[test]
+fn call_contract_with_largest_payload() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let buffer_id = 1;
+    let payload = payload_of_size(CallContractPayloadBuffer::MAX_PAYLOAD_SIZE);
+
+    harness.upload_call_contract_buffer(sender, buffer_id, &payload, 900);
+
+    // A destination address of 1 KiB still leaves room in the event CPI
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        "ethereum".to_owned(),
+        "a".repeat(1024),
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn call_contract_with_buffer_event_too_large() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let buffer_id = 2;
+    let payload = payload_of_size(CallContractPayloadBuffer::MAX_PAYLOAD_SIZE);
+
+    harness.upload_call_contract_buffer(sender, buffer_id, &payload, 900);
+
+    let buffer: CallContractPayloadBuffer = harness
+        .get_account_as(&CallContractPayloadBuffer::find_pda(&sender, buffer_id).0)
+        .expect("payload buffer should exist");
+    let destination_chain = "ethereum";
+
+    // The longest destination address whose event still fits
+    let fitting_len = CallContractPayloadBuffer::MAX_EVENT_DATA_LEN
+        - buffer.event_data_len(destination_chain, "");
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        destination_chain.to_owned(),
+        "a".repeat(fitting_len + 1),
+        &[Check::err(gateway_err(
+            GatewayError::CallContractEventTooLarge,
+        ))],
+    );
+
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        destination_chain.to_owned(),
+        "a".repeat(fitting_len),
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn buffer_cannot_be_reused() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let buffer_id = 0;
+
+    harness.upload_call_contract_buffer(sender, buffer_id, b"payload", 4);
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        &[Check::success()],
+    );
+
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        &[Check::err({
+            let e: anchor_lang::error::Error =
+                anchor_lang::error::ErrorCode::AccountNotInitialized.into();
+            e.into()
+        })],
+    );
+}
+
+#[test]
+fn write_out_of_bounds() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let buffer_id = 0;
+
+    harness.init_call_contract_buffer_with_checks(sender, buffer_id, 8, &[Check::success()]);
+
+    harness.write_call_contract_buffer_with_checks(
+        sender,
+        buffer_id,
+        4,
+        b"12345".to_vec(),
+        &[Check::err(gateway_err(
+            GatewayError::PayloadBufferOutOfBounds,
+        ))],
+    );
+}
+
+#[test]
+fn buffer_too_large() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+
+    let payload_size = u32::try_from(CallContractPayloadBuffer::MAX_PAYLOAD_SIZE + 1).unwrap();
+    harness.init_call_contract_buffer_with_checks(
+        sender,
+        0,
+        payload_size,
+        &[Check::err(gateway_err(GatewayError::PayloadBufferTooLarge))],
+    );
+}
+
+#[test]
+fn call_contract_with_buffer_while_paused() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let buffer_id = 0;
+
+    harness.upload_call_contract_buffer(sender, buffer_id, b"payload", 4);
+    harness.set_gateway_pause_status(true);
+
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        &[Check::err(gateway_err(GatewayError::GatewayPaused))],
+    );
+}
@@ -0,0 +1,211 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::CallContractNonce;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+
+fn direct_signer(harness: &GatewayTestHarness) -> Pubkey {
+    let signer = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(signer, LAMPORTS_PER_SOL);
+    signer
+}
+
+fn next_nonce(harness: &GatewayTestHarness, sender: &Pubkey) -> u64 {
+    harness
+        .get_account_as::<CallContractNonce>(&CallContractNonce::find_pda(sender).0)
+        .expect("call contract nonce should exist")
+        .next_nonce
+}
+
+fn call_contract(harness: &GatewayTestHarness, sender: Pubkey) {
+    harness.call_contract(
+        sender,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        b"Hello from Solana!".to_vec(),
+    );
+}
+
+/// Builds a `call_contract` instruction for a direct signer with the given
+/// nonce account, if any.
+fn build_call_contract_ix(
+    harness: &GatewayTestHarness,
+    sender: Pubkey,
+    call_contract_nonce: Option<Pubkey>,
+) -> Instruction {
+    let (event_authority, _, _) =
+        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+    let mut accounts = solana_axelar_gateway::accounts::CallContract {
+        caller: sender,
+        signing_pda: None,
+        gateway_root_pda: harness.gateway.root,
+        event_authority,
+        program: solana_axelar_gateway::ID,
+        destination_chain_registry: None,
+        call_contract_nonce,
+    }
+    .to_account_metas(None);
+    accounts[0].is_signer = true;
+
+    Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts,
+        data: solana_axelar_gateway::instruction::CallContract {
+            destination_chain: "ethereum".to_owned(),
+            destination_contract_address: "0xDestinationContract".to_owned(),
+            payload: b"Hello from Solana!".to_vec(),
+            signing_pda_bump: 0,
+        }
+        .data(),
+    }
+}
+
+#[test]
+fn nonce_starts_at_zero() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+
+    let nonce_pda = CallContractNonce::find_pda(&sender).0;
+    harness.init_call_contract_nonce_with_checks(
+        sender,
+        &[
+            Check::success(),
+            Check::account(&nonce_pda)
+                .owner(&solana_axelar_gateway::ID)
+                .rent_exempt()
+                .build(),
+        ],
+    );
+
+    let nonce: CallContractNonce = harness
+        .get_account_as(&nonce_pda)
+        .expect("call contract nonce should exist");
+    assert_eq!(nonce.next_nonce, 0);
+    assert_eq!(nonce.bump, CallContractNonce::find_pda(&sender).1);
+}
+
+#[test]
+fn call_contract_advances_nonce() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    harness.init_call_contract_nonce(sender);
+
+    for expected_next_nonce in 1..=3 {
+        call_contract(&harness, sender);
+        assert_eq!(next_nonce(&harness, &sender), expected_next_nonce);
+    }
+}
+
+#[test]
+fn nonces_are_kept_per_sender() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let other_sender = direct_signer(&harness);
+    harness.init_call_contract_nonce(sender);
+    harness.init_call_contract_nonce(other_sender);
+
+    call_contract(&harness, sender);
+    call_contract(&harness, sender);
+    call_contract(&harness, other_sender);
+
+    assert_eq!(next_nonce(&harness, &sender), 2);
+    assert_eq!(next_nonce(&harness, &other_sender), 1);
+}
+
+#[test]
+fn call_contract_before_nonce_is_initialized_succeeds() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+
+    call_contract(&harness, sender);
+
+    assert!(!harness.account_exists(&CallContractNonce::find_pda(&sender).0));
+}
+
+#[test]
+fn call_contract_omitting_nonce_account_succeeds() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    harness.init_call_contract_nonce(sender);
+
+    // The nonce account is the last one, so it can be left out entirely
+    let mut ix = build_call_contract_ix(&harness, sender, None);
+    ix.accounts.pop();
+
+    harness
+        .ctx
+        .process_and_validate_instruction(&ix, &[Check::success()]);
+    assert_eq!(next_nonce(&harness, &sender), 0);
+}
+
+#[test]
+fn call_contract_with_other_senders_nonce_fails() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    let other_sender = direct_signer(&harness);
+    harness.init_call_contract_nonce(sender);
+    harness.init_call_contract_nonce(other_sender);
+
+    // The nonce of another sender can't stand in for its own
+    let ix = build_call_contract_ix(
+        &harness,
+        sender,
+        Some(CallContractNonce::find_pda(&other_sender).0),
+    );
+
+    harness.ctx.process_and_validate_instruction(
+        &ix,
+        &[Check::err(
+            anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::ConstraintSeeds).into(),
+        )],
+    );
+    assert_eq!(next_nonce(&harness, &sender), 0);
+    assert_eq!(next_nonce(&harness, &other_sender), 0);
+}
+
+#[test]
+fn call_contract_with_buffer_advances_nonce() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    harness.init_call_contract_nonce(sender);
+
+    call_contract(&harness, sender);
+
+    let buffer_id = 7;
+    harness.upload_call_contract_buffer(sender, buffer_id, b"buffered payload", 8);
+    harness.call_contract_with_buffer_with_checks(
+        sender,
+        buffer_id,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        &[Check::success()],
+    );
+
+    assert_eq!(next_nonce(&harness, &sender), 2);
+}
+
+#[test]
+fn call_contract_with_hash_advances_nonce() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    harness.init_call_contract_nonce(sender);
+
+    harness.call_contract_with_hash_with_checks(
+        sender,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        [7; 32],
+        "ipfs://payload".to_owned(),
+        &[Check::success()],
+    );
+
+    assert_eq!(next_nonce(&harness, &sender), 1);
+}
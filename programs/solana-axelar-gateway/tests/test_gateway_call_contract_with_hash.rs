@@ -0,0 +1,98 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayError, MAX_PAYLOAD_LOCATION_LEN};
+use solana_sdk::pubkey::Pubkey;
+
+fn direct_signer(harness: &GatewayTestHarness) -> Pubkey {
+    let signer = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(signer, solana_sdk::native_token::LAMPORTS_PER_SOL);
+    signer
+}
+
+fn call_contract_with_hash(
+    harness: &GatewayTestHarness,
+    sender: Pubkey,
+    payload_location: String,
+    checks: &[Check],
+) {
+    let payload = vec![42_u8; 64 * 1024];
+    let payload_hash = solana_keccak_hasher::hash(&payload).to_bytes();
+
+    harness.call_contract_with_hash_with_checks(
+        sender,
+        "ethereum".to_owned(),
+        "0xDestinationContract".to_owned(),
+        payload_hash,
+        payload_location,
+        checks,
+    );
+}
+
+#[test]
+fn call_contract_with_hash_of_large_payload() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+
+    call_contract_with_hash(
+        &harness,
+        sender,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_owned(),
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn call_contract_with_hash_without_payload_location() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+
+    call_contract_with_hash(
+        &harness,
+        sender,
+        String::new(),
+        &[Check::err(gateway_err(
+            GatewayError::InvalidPayloadLocation,
+        ))],
+    );
+}
+
+#[test]
+fn call_contract_with_hash_payload_location_too_long() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+
+    call_contract_with_hash(
+        &harness,
+        sender,
+        "a".repeat(MAX_PAYLOAD_LOCATION_LEN + 1),
+        &[Check::err(gateway_err(
+            GatewayError::InvalidPayloadLocation,
+        ))],
+    );
+    call_contract_with_hash(
+        &harness,
+        sender,
+        "a".repeat(MAX_PAYLOAD_LOCATION_LEN),
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn call_contract_with_hash_while_paused() {
+    let harness = GatewayTestHarness::new();
+    let sender = direct_signer(&harness);
+    harness.set_gateway_pause_status(true);
+
+    call_contract_with_hash(
+        &harness,
+        sender,
+        "ipfs://payload".to_owned(),
+        &[Check::err(gateway_err(GatewayError::GatewayPaused))],
+    );
+}
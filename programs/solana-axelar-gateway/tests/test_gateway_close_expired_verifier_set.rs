@@ -0,0 +1,198 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{
+    GatewayConfig, GatewayError, UpdateConfigParams, VerifierSetEpoch, VerifierSetTracker,
+    VerifierSetTrackerTombstone,
+};
+use solana_axelar_std::U256;
+use solana_sdk::pubkey::Pubkey;
+
+/// Moves the current epoch past the retention window of the initial verifier
+/// set.
+fn expire_initial_verifier_set(harness: &mut GatewayTestHarness) {
+    let tracker: VerifierSetTracker = harness
+        .get_account_as(&harness.gateway.verifier_set_tracker)
+        .expect("verifier set tracker should exist");
+    let root = harness.gateway.root;
+    harness.update_account(&root, |account| {
+        let config: &mut GatewayConfig =
+            bytemuck::from_bytes_mut(&mut account.data[GatewayConfig::DISCRIMINATOR.len()..]);
+        config.current_epoch = tracker
+            .epoch
+            .checked_add(config.previous_verifier_set_retention)
+            .unwrap();
+    });
+}
+
+fn lamports(harness: &GatewayTestHarness, address: &Pubkey) -> u64 {
+    harness
+        .get_account(address)
+        .map_or(0, |account| account.lamports)
+}
+
+#[test]
+fn close_expired_verifier_set() {
+    let mut harness = GatewayTestHarness::new();
+    expire_initial_verifier_set(&mut harness);
+
+    let tracker_pda = harness.gateway.verifier_set_tracker;
+    let epoch_pda = VerifierSetEpoch::find_pda(U256::ONE).0;
+    let tracker_lamports_before = lamports(&harness, &tracker_pda);
+    let epoch_lamports = lamports(&harness, &epoch_pda);
+    assert!(epoch_lamports > 0);
+    let operator_lamports_before = lamports(&harness, &harness.operator);
+
+    harness.close_expired_verifier_set_with_checks(
+        tracker_pda,
+        harness.operator,
+        &[Check::success()],
+    );
+
+    // The tracker is shrunk into a tombstone, the rest of its rent refunded
+    let tracker_account = harness
+        .get_account(&tracker_pda)
+        .expect("tombstone should exist");
+    assert_eq!(
+        tracker_account.data.len(),
+        VerifierSetTrackerTombstone::SPACE
+    );
+    assert!(tracker_account
+        .data
+        .starts_with(VerifierSetTrackerTombstone::DISCRIMINATOR));
+    assert!(harness
+        .get_account_as::<VerifierSetTracker>(&tracker_pda)
+        .is_none());
+
+    // The epoch record is closed as well
+    assert!(harness
+        .get_account(&epoch_pda)
+        .is_none_or(|account| account.lamports == 0));
+
+    let refund = tracker_lamports_before - tracker_account.lamports;
+    assert!(refund > 0);
+    assert_eq!(
+        lamports(&harness, &harness.operator),
+        operator_lamports_before + refund + epoch_lamports
+    );
+}
+
+#[test]
+fn close_expired_verifier_set_without_epoch_record() {
+    let mut harness = GatewayTestHarness::new();
+    expire_initial_verifier_set(&mut harness);
+
+    // Verifier sets rotated in before the epoch history have no record
+    let epoch_pda = VerifierSetEpoch::find_pda(U256::ONE).0;
+    harness.update_account(&epoch_pda, |account| {
+        *account = solana_sdk::account::Account::default();
+    });
+
+    harness.close_expired_verifier_set_with_checks(
+        harness.gateway.verifier_set_tracker,
+        harness.operator,
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn close_expired_verifier_set_with_other_epoch_record() {
+    let mut harness = GatewayTestHarness::new();
+    expire_initial_verifier_set(&mut harness);
+
+    let (event_authority, _, _) =
+        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+    let ix = solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::CloseExpiredVerifierSet {
+            gateway_root_pda: harness.gateway.root,
+            verifier_set_tracker_pda: harness.gateway.verifier_set_tracker,
+            rent_recipient: harness.operator,
+            verifier_set_epoch_pda: VerifierSetEpoch::find_pda(U256::from(2_u64)).0,
+            event_authority,
+            program: solana_axelar_gateway::ID,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::CloseExpiredVerifierSet {}.data(),
+    };
+
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::InvalidVerifierSetEpochProvided,
+        ))],
+    )]);
+}
+
+#[test]
+fn close_verifier_set_within_retention() {
+    let harness = GatewayTestHarness::new();
+
+    harness.close_expired_verifier_set_with_checks(
+        harness.gateway.verifier_set_tracker,
+        harness.operator,
+        &[Check::err(gateway_err(GatewayError::VerifierSetNotExpired))],
+    );
+}
+
+#[test]
+fn close_expired_verifier_set_to_configured_recipient() {
+    let mut harness = GatewayTestHarness::new();
+    let rent_recipient = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(
+        rent_recipient,
+        solana_sdk::native_token::LAMPORTS_PER_SOL,
+    );
+    harness.update_config_with_checks(
+        harness.operator,
+        UpdateConfigParams {
+            rent_recipient: Some(rent_recipient),
+            ..Default::default()
+        },
+        &[Check::success()],
+    );
+    expire_initial_verifier_set(&mut harness);
+
+    // The operator is no longer the recipient once another one is configured
+    harness.close_expired_verifier_set_with_checks(
+        harness.gateway.verifier_set_tracker,
+        harness.operator,
+        &[Check::err(gateway_err(GatewayError::InvalidRentRecipient))],
+    );
+
+    harness.close_expired_verifier_set_with_checks(
+        harness.gateway.verifier_set_tracker,
+        rent_recipient,
+        &[Check::success()],
+    );
+    assert!(lamports(&harness, &rent_recipient) > solana_sdk::native_token::LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn close_expired_verifier_set_twice() {
+    let mut harness = GatewayTestHarness::new();
+    expire_initial_verifier_set(&mut harness);
+
+    harness.close_expired_verifier_set_with_checks(
+        harness.gateway.verifier_set_tracker,
+        harness.operator,
+        &[Check::success()],
+    );
+
+    harness.close_expired_verifier_set_with_checks(
+        harness.gateway.verifier_set_tracker,
+        harness.operator,
+        &[Check::err({
+            let e: anchor_lang::error::Error =
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into();
+            e.into()
+        })],
+    );
+}
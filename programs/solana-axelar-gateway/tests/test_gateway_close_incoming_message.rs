@@ -0,0 +1,243 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{
+    GatewayConfig, GatewayError, IncomingMessage, IncomingMessageTombstone,
+};
+use solana_axelar_std::PayloadType;
+use solana_sdk::pubkey::Pubkey;
+
+fn build_close_incoming_message_ix(
+    incoming_message_pda: Pubkey,
+    funder: Pubkey,
+) -> solana_sdk::instruction::Instruction {
+    solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::CloseIncomingMessage {
+            incoming_message_pda,
+            funder,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::CloseIncomingMessage {}.data(),
+    }
+}
+
+#[test]
+fn close_executed_message_refunds_funder() {
+    let harness = GatewayTestHarness::new();
+
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    let incoming: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+    assert_eq!(incoming.funder, harness.payer);
+
+    let validate_ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&validate_ix, &[Check::success()])]);
+
+    let pda_lamports_before = harness.get_account(&incoming_message_pda).unwrap().lamports;
+    let payer_lamports_before = harness.get_account(&harness.payer).unwrap().lamports;
+
+    let close_ix = build_close_incoming_message_ix(incoming_message_pda, harness.payer);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&close_ix, &[Check::success()])]);
+
+    // The PDA is left behind as a rent-exempt tombstone
+    let tombstone_account = harness.get_account(&incoming_message_pda).unwrap();
+    assert_eq!(tombstone_account.owner, solana_axelar_gateway::ID);
+    assert_eq!(
+        tombstone_account.data.len(),
+        IncomingMessageTombstone::SPACE
+    );
+
+    let tombstone =
+        IncomingMessageTombstone::try_deserialize(&mut tombstone_account.data.as_slice())
+            .expect("tombstone should deserialize");
+    assert_eq!(tombstone.bump, incoming.bump);
+
+    // The funder received everything above the tombstone's rent exemption
+    let refund = pda_lamports_before - tombstone_account.lamports;
+    assert!(refund > 0);
+    assert_eq!(
+        harness.get_account(&harness.payer).unwrap().lamports,
+        payer_lamports_before + refund
+    );
+    assert_eq!(
+        tombstone_account.lamports,
+        solana_sdk::rent::Rent::default().minimum_balance(IncomingMessageTombstone::SPACE)
+    );
+}
+
+#[test]
+fn close_message_not_executed() {
+    let harness = GatewayTestHarness::new();
+
+    let (_message, incoming_message_pda) = approve_first_message(&harness);
+
+    let close_ix = build_close_incoming_message_ix(incoming_message_pda, harness.payer);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &close_ix,
+        &[Check::err(gateway_err(GatewayError::MessageNotExecuted))],
+    )]);
+}
+
+#[test]
+fn close_message_wrong_funder() {
+    let harness = GatewayTestHarness::new();
+
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    let validate_ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&validate_ix, &[Check::success()])]);
+
+    let close_ix = build_close_incoming_message_ix(incoming_message_pda, Pubkey::new_unique());
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &close_ix,
+        &[Check::err(gateway_err(GatewayError::InvalidFunder))],
+    )]);
+}
+
+#[test]
+fn close_message_requires_funder_signature() {
+    let harness = GatewayTestHarness::new();
+
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    let validate_ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&validate_ix, &[Check::success()])]);
+
+    let mut close_ix = build_close_incoming_message_ix(incoming_message_pda, harness.payer);
+    close_ix.accounts[1].is_signer = false;
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &close_ix,
+        &[Check::err(
+            anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::AccountNotSigner).into(),
+        )],
+    )]);
+
+    // The message is left untouched
+    let incoming: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+    assert!(incoming.status.is_executed());
+}
+
+#[test]
+fn closed_message_cannot_be_approved_again() {
+    let harness = GatewayTestHarness::new();
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+
+    let messages = default_messages();
+    let (merklized_messages, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, &messages);
+
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+    harness.verify_all_signatures(payload_merkle_root, PayloadType::ApproveMessages);
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+
+    let incoming_message_pda = IncomingMessage::find_pda(&messages[0].command_id()).0;
+
+    let validate_ix = build_validate_message_ix(&harness, &messages[0], incoming_message_pda);
+    let close_ix = build_close_incoming_message_ix(incoming_message_pda, harness.payer);
+    harness.ctx.process_and_validate_instruction_chain(&[
+        (&validate_ix, &[Check::success()]),
+        (&close_ix, &[Check::success()]),
+    ]);
+
+    // The tombstone keeps the PDA allocated, so it can't be initialized again
+    let result = harness.approve_message_with_checks(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+        &[],
+    );
+    assert!(result.program_result.is_err());
+}
+
+#[test]
+fn close_revoked_message() {
+    let harness = GatewayTestHarness::new();
+
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+    harness.revoke_message(message.command_id());
+
+    let close_ix = build_close_incoming_message_ix(incoming_message_pda, harness.payer);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&close_ix, &[Check::success()])]);
+
+    assert_eq!(
+        harness
+            .get_account(&incoming_message_pda)
+            .unwrap()
+            .data
+            .len(),
+        IncomingMessageTombstone::SPACE
+    );
+}
+
+#[test]
+fn close_expired_message() {
+    let mut harness = GatewayTestHarness::new();
+
+    let message_approval_ttl = 3600;
+    harness.set_message_approval_ttl(message_approval_ttl);
+
+    let (_message, incoming_message_pda) = approve_first_message(&harness);
+
+    let close_ix = build_close_incoming_message_ix(incoming_message_pda, harness.payer);
+
+    // Still within the TTL
+    harness.ctx.mollusk.sysvars.clock.unix_timestamp += i64::from(message_approval_ttl);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &close_ix,
+        &[Check::err(gateway_err(GatewayError::MessageNotExecuted))],
+    )]);
+
+    harness.ctx.mollusk.sysvars.clock.unix_timestamp += 1;
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&close_ix, &[Check::success()])]);
+
+    let tombstone_account = harness.get_account(&incoming_message_pda).unwrap();
+    assert_eq!(
+        tombstone_account.data.len(),
+        IncomingMessageTombstone::SPACE
+    );
+}
+
+#[test]
+fn close_message_without_ttl_never_expires() {
+    let mut harness = GatewayTestHarness::new();
+
+    let (_message, incoming_message_pda) = approve_first_message(&harness);
+    harness.ctx.mollusk.sysvars.clock.unix_timestamp += 10 * 365 * 24 * 3600;
+
+    let close_ix = build_close_incoming_message_ix(incoming_message_pda, harness.payer);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &close_ix,
+        &[Check::err(gateway_err(GatewayError::MessageNotExecuted))],
+    )]);
+}
@@ -0,0 +1,250 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{
+    GatewayConfig, GatewayError, SignatureVerificationSessionData, VerifierSetTracker,
+};
+use solana_axelar_std::PayloadType;
+use solana_sdk::pubkey::Pubkey;
+
+fn build_close_verification_session_ix(
+    harness: &GatewayTestHarness,
+    verification_session_account: Pubkey,
+    payer: Pubkey,
+) -> solana_sdk::instruction::Instruction {
+    solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::CloseVerificationSession {
+            gateway_root_pda: harness.gateway.root,
+            verification_session_account,
+            verifier_set_tracker_pda: harness.gateway.verifier_set_tracker,
+            payer,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::CloseVerificationSession {}.data(),
+    }
+}
+
+/// Initializes and completes a verification session for the default messages,
+/// returning the session PDA, payload merkle root and merklized messages.
+fn signed_default_messages_session(
+    harness: &GatewayTestHarness,
+) -> (Pubkey, [u8; 32], Vec<solana_axelar_std::MerklizedMessage>) {
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+
+    let (merklized_messages, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, &default_messages());
+
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+    harness.verify_all_signatures(payload_merkle_root, PayloadType::ApproveMessages);
+
+    (
+        verification_session_pda,
+        payload_merkle_root,
+        merklized_messages,
+    )
+}
+
+#[test]
+fn close_session_after_all_messages_approved() {
+    let harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, merklized_messages) =
+        signed_default_messages_session(&harness);
+
+    for merklized_message in &merklized_messages {
+        harness.approve_message(
+            merklized_message,
+            payload_merkle_root,
+            verification_session_pda,
+        );
+    }
+
+    let session: SignatureVerificationSessionData = harness
+        .get_account_as(&verification_session_pda)
+        .expect("session should exist");
+    assert_eq!(session.payer, harness.payer);
+    assert!(session.all_messages_approved());
+
+    let session_lamports = harness
+        .get_account(&verification_session_pda)
+        .unwrap()
+        .lamports;
+    let payer_lamports_before = harness.get_account(&harness.payer).unwrap().lamports;
+
+    let ix = build_close_verification_session_ix(&harness, verification_session_pda, harness.payer);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    assert!(!harness.account_exists(&verification_session_pda));
+    assert_eq!(
+        harness.get_account(&harness.payer).unwrap().lamports,
+        payer_lamports_before + session_lamports
+    );
+}
+
+#[test]
+fn close_session_with_pending_messages_fails() {
+    let harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, merklized_messages) =
+        signed_default_messages_session(&harness);
+
+    // Only approve the first of the two messages
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+
+    let ix = build_close_verification_session_ix(&harness, verification_session_pda, harness.payer);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::VerificationSessionInUse,
+        ))],
+    )]);
+}
+
+#[test]
+fn close_session_after_verifier_set_expired() {
+    let mut harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, _payload_merkle_root, _merklized_messages) =
+        signed_default_messages_session(&harness);
+
+    // Move the current epoch past the retention window of the signing set
+    let tracker: VerifierSetTracker = harness
+        .get_account_as(&harness.gateway.verifier_set_tracker)
+        .expect("verifier set tracker should exist");
+    let root = harness.gateway.root;
+    harness.update_account(&root, |account| {
+        let config: &mut GatewayConfig =
+            bytemuck::from_bytes_mut(&mut account.data[GatewayConfig::DISCRIMINATOR.len()..]);
+        config.current_epoch = tracker
+            .epoch
+            .checked_add(config.previous_verifier_set_retention)
+            .unwrap();
+    });
+
+    let ix = build_close_verification_session_ix(&harness, verification_session_pda, harness.payer);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    assert!(!harness.account_exists(&verification_session_pda));
+}
+
+#[test]
+fn close_session_after_verifier_set_tracker_closed() {
+    let mut harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, _payload_merkle_root, _merklized_messages) =
+        signed_default_messages_session(&harness);
+
+    let tracker: VerifierSetTracker = harness
+        .get_account_as(&harness.gateway.verifier_set_tracker)
+        .expect("verifier set tracker should exist");
+    let root = harness.gateway.root;
+    harness.update_account(&root, |account| {
+        let config: &mut GatewayConfig =
+            bytemuck::from_bytes_mut(&mut account.data[GatewayConfig::DISCRIMINATOR.len()..]);
+        config.current_epoch = tracker
+            .epoch
+            .checked_add(config.previous_verifier_set_retention)
+            .unwrap();
+    });
+
+    // The expired tracker is pruned before the session is closed
+    harness.close_expired_verifier_set_with_checks(
+        harness.gateway.verifier_set_tracker,
+        harness.operator,
+        &[Check::success()],
+    );
+
+    let ix = build_close_verification_session_ix(&harness, verification_session_pda, harness.payer);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    assert!(!harness.account_exists(&verification_session_pda));
+}
+
+#[test]
+fn close_incomplete_session_fails() {
+    let harness = GatewayTestHarness::new();
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    let (_merklized_messages, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, &default_messages());
+
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+
+    let ix = build_close_verification_session_ix(&harness, verification_session_pda, harness.payer);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::SigningSessionNotValid,
+        ))],
+    )]);
+}
+
+#[test]
+fn close_session_wrong_payer_fails() {
+    let harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, merklized_messages) =
+        signed_default_messages_session(&harness);
+
+    for merklized_message in &merklized_messages {
+        harness.approve_message(
+            merklized_message,
+            payload_merkle_root,
+            verification_session_pda,
+        );
+    }
+
+    let ix = build_close_verification_session_ix(
+        &harness,
+        verification_session_pda,
+        Pubkey::new_unique(),
+    );
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(GatewayError::InvalidFunder))],
+    )]);
+}
+
+#[test]
+fn session_tracks_approved_messages() {
+    let harness = GatewayTestHarness::new();
+
+    let (verification_session_pda, payload_merkle_root, merklized_messages) =
+        signed_default_messages_session(&harness);
+
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+
+    let session: SignatureVerificationSessionData = harness
+        .get_account_as(&verification_session_pda)
+        .expect("session should exist");
+    assert_eq!(session.approved_message_count, 1);
+    assert_eq!(session.message_set_size, 2);
+    assert!(!session.all_messages_approved());
+}
@@ -92,6 +92,45 @@ fn transfer_operatorship_unauthorized() {
     )]);
 }
 
+#[test]
+fn transfer_operatorship_is_deprecated() {
+    let harness = GatewayTestHarness::new();
+
+    let program_data = anchor_lang::prelude::bpf_loader_upgradeable::get_program_data_address(
+        &solana_axelar_gateway::ID,
+    );
+
+    let (event_authority, _, _) =
+        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+    let ix = solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::TransferOperatorship {
+            gateway_root_pda: harness.gateway.root,
+            operator_or_upgrade_authority: harness.operator,
+            program_data,
+            new_operator: Pubkey::new_unique(),
+            event_authority,
+            program: solana_axelar_gateway::ID,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::TransferOperatorship {}.data(),
+    };
+
+    // The operatorship has to go through the two-step transfer
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::OperatorshipTransferDeprecated,
+        ))],
+    )]);
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    assert_eq!(config.operator, harness.operator);
+}
+
 #[test]
 fn transfer_operatorship_same_operator() {
     let harness = GatewayTestHarness::new();
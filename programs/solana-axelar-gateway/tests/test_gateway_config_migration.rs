@@ -0,0 +1,202 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::Discriminator;
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayConfig, GatewayError, UpdateConfigParams};
+use solana_axelar_std::U256;
+use solana_sdk::{pubkey::Pubkey, rent::Rent};
+
+fn config(harness: &GatewayTestHarness) -> GatewayConfig {
+    harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist")
+}
+
+/// Rewrites the gateway config account into the version 0 layout, as it was
+/// stored before the `version` and `_reserved` fields were introduced.
+fn downgrade_to_v0(harness: &mut GatewayTestHarness) {
+    let root = harness.gateway.root;
+    harness.update_account(&root, |account| {
+        let version_offset =
+            GatewayConfig::DISCRIMINATOR.len() + std::mem::offset_of!(GatewayConfig, version);
+        account.data[version_offset] = 0;
+        account.data.truncate(GatewayConfig::V0_SPACE);
+        account.lamports = Rent::default().minimum_balance(GatewayConfig::V0_SPACE);
+    });
+}
+
+#[test]
+fn initialize_config_sets_current_version() {
+    let harness = GatewayTestHarness::new();
+
+    assert_eq!(config(&harness).version, GatewayConfig::VERSION);
+    assert_eq!(
+        harness
+            .get_account(&harness.gateway.root)
+            .expect("gateway config should exist")
+            .data
+            .len(),
+        GatewayConfig::SPACE
+    );
+}
+
+#[test]
+fn update_config() {
+    let harness = GatewayTestHarness::new();
+    let before = config(&harness);
+    let rent_recipient = Pubkey::new_unique();
+
+    harness.update_config_with_checks(
+        harness.operator,
+        UpdateConfigParams {
+            minimum_rotation_delay: Some(3600),
+            previous_verifier_set_retention: Some(U256::from(8_u64)),
+            domain_separator: Some([7; 32]),
+            rent_recipient: Some(rent_recipient),
+        },
+        &[Check::success()],
+    );
+
+    let after = config(&harness);
+    assert_eq!(after.minimum_rotation_delay, 3600);
+    assert_eq!(after.previous_verifier_set_retention, U256::from(8_u64));
+    assert_eq!(after.domain_separator, [7; 32]);
+    assert_eq!(after.rent_recipient(), rent_recipient);
+    assert_eq!(after.operator, before.operator);
+    assert_eq!(after.current_epoch, before.current_epoch);
+}
+
+#[test]
+fn update_config_keeps_unset_fields() {
+    let harness = GatewayTestHarness::new();
+    let before = config(&harness);
+
+    harness.update_config_with_checks(
+        harness.operator,
+        UpdateConfigParams {
+            minimum_rotation_delay: Some(before.minimum_rotation_delay + 1),
+            ..Default::default()
+        },
+        &[Check::success()],
+    );
+
+    let after = config(&harness);
+    assert_eq!(
+        after.minimum_rotation_delay,
+        before.minimum_rotation_delay + 1
+    );
+    assert_eq!(
+        after.previous_verifier_set_retention,
+        before.previous_verifier_set_retention
+    );
+    assert_eq!(after.domain_separator, before.domain_separator);
+    assert_eq!(after.rent_recipient(), before.operator);
+}
+
+#[test]
+fn update_config_unauthorized() {
+    let harness = GatewayTestHarness::new();
+    let unauthorized = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(
+        unauthorized,
+        solana_sdk::native_token::LAMPORTS_PER_SOL,
+    );
+
+    let before = config(&harness);
+
+    harness.update_config_with_checks(
+        unauthorized,
+        UpdateConfigParams {
+            domain_separator: Some([7; 32]),
+            ..Default::default()
+        },
+        &[Check::err(gateway_err(
+            GatewayError::InvalidUpgradeAuthority,
+        ))],
+    );
+
+    assert_eq!(config(&harness).domain_separator, before.domain_separator);
+}
+
+#[test]
+fn update_config_zero_retention() {
+    let harness = GatewayTestHarness::new();
+    let before = config(&harness);
+
+    harness.update_config_with_checks(
+        harness.operator,
+        UpdateConfigParams {
+            previous_verifier_set_retention: Some(U256::ZERO),
+            ..Default::default()
+        },
+        &[Check::err(gateway_err(GatewayError::InvalidConfigValue))],
+    );
+
+    assert_eq!(
+        config(&harness).previous_verifier_set_retention,
+        before.previous_verifier_set_retention
+    );
+}
+
+#[test]
+fn migrate_v0_config() {
+    let mut harness = GatewayTestHarness::new();
+    let before = config(&harness);
+    downgrade_to_v0(&mut harness);
+
+    harness.migrate_config_with_checks(harness.operator, &[Check::success()]);
+
+    let account = harness
+        .get_account(&harness.gateway.root)
+        .expect("gateway config should exist");
+    assert_eq!(account.data.len(), GatewayConfig::SPACE);
+    assert!(account.lamports >= Rent::default().minimum_balance(GatewayConfig::SPACE));
+    // The reserved bytes appended by the reallocation are zeroed
+    assert!(account.data[GatewayConfig::V0_SPACE..]
+        .iter()
+        .all(|byte| *byte == 0));
+
+    let after = config(&harness);
+    assert_eq!(after.version, GatewayConfig::VERSION);
+    assert_eq!(after.current_epoch, before.current_epoch);
+    assert_eq!(after.operator, before.operator);
+    assert_eq!(after.domain_separator, before.domain_separator);
+    assert_eq!(after.message_approval_ttl, before.message_approval_ttl);
+
+    // The migrated config can be used again
+    harness.set_gateway_pause_status(true);
+    assert!(config(&harness).is_paused());
+}
+
+#[test]
+fn migrate_current_config() {
+    let harness = GatewayTestHarness::new();
+
+    harness.migrate_config_with_checks(
+        harness.operator,
+        &[Check::err(gateway_err(GatewayError::ConfigAlreadyMigrated))],
+    );
+}
+
+#[test]
+fn migrate_config_unauthorized() {
+    let mut harness = GatewayTestHarness::new();
+    downgrade_to_v0(&mut harness);
+    let unauthorized = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(
+        unauthorized,
+        solana_sdk::native_token::LAMPORTS_PER_SOL,
+    );
+
+    harness.migrate_config_with_checks(
+        unauthorized,
+        &[Check::err(gateway_err(
+            GatewayError::InvalidUpgradeAuthority,
+        ))],
+    );
+}
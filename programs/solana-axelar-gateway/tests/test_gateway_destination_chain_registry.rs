@@ -0,0 +1,253 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{
+    DestinationChainRegistry, GatewayConfig, GatewayError, MAX_DESTINATION_CHAIN_LEN,
+};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+fn direct_signer(harness: &GatewayTestHarness) -> Pubkey {
+    let signer = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(signer, solana_sdk::native_token::LAMPORTS_PER_SOL);
+    signer
+}
+
+fn call_contract_with_checks(
+    harness: &GatewayTestHarness,
+    caller: Pubkey,
+    destination_chain: &str,
+    checks: &[Check],
+) {
+    harness.call_contract_with_checks(
+        caller,
+        destination_chain.to_owned(),
+        "0xdeadbeef".to_owned(),
+        b"registry test".to_vec(),
+        checks,
+    );
+}
+
+/// Builds a `call_contract` instruction for a direct signer with the given
+/// registry account, without the caller's nonce.
+fn build_call_contract_ix(
+    harness: &GatewayTestHarness,
+    caller: Pubkey,
+    destination_chain_registry: Option<Pubkey>,
+) -> Instruction {
+    let (event_authority, _, _) =
+        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+    let mut accounts = solana_axelar_gateway::accounts::CallContract {
+        caller,
+        signing_pda: None,
+        gateway_root_pda: harness.gateway.root,
+        event_authority,
+        program: solana_axelar_gateway::ID,
+        destination_chain_registry,
+        call_contract_nonce: None,
+    }
+    .to_account_metas(None);
+    accounts[0].is_signer = true;
+
+    Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts,
+        data: solana_axelar_gateway::instruction::CallContract {
+            destination_chain: "ethereum".to_owned(),
+            destination_contract_address: "0xdeadbeef".to_owned(),
+            payload: b"registry test".to_vec(),
+            signing_pda_bump: 0,
+        }
+        .data(),
+    }
+}
+
+#[test]
+fn add_and_remove_destination_chains() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+
+    harness.add_destination_chain("ethereum");
+    harness.add_destination_chain("avalanche");
+
+    let registry: DestinationChainRegistry = harness
+        .get_account_as(&DestinationChainRegistry::find_pda().0)
+        .expect("registry should exist");
+    assert_eq!(registry.chains, vec!["ethereum", "avalanche"]);
+
+    harness.remove_destination_chain_with_checks("ethereum", &[Check::success()]);
+
+    let registry: DestinationChainRegistry = harness
+        .get_account_as(&DestinationChainRegistry::find_pda().0)
+        .expect("registry should exist");
+    assert_eq!(registry.chains, vec!["avalanche"]);
+}
+
+#[test]
+fn call_contract_to_registered_chain() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+    harness.add_destination_chain("ethereum");
+
+    let caller = direct_signer(&harness);
+    call_contract_with_checks(&harness, caller, "ethereum", &[Check::success()]);
+}
+
+#[test]
+fn call_contract_to_unknown_chain() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+    harness.add_destination_chain("ethereum");
+
+    let caller = direct_signer(&harness);
+    call_contract_with_checks(
+        &harness,
+        caller,
+        "etherum",
+        &[Check::err(gateway_err(
+            GatewayError::UnknownDestinationChain,
+        ))],
+    );
+}
+
+#[test]
+fn call_contract_to_removed_chain() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+    harness.add_destination_chain("ethereum");
+    harness.remove_destination_chain_with_checks("ethereum", &[Check::success()]);
+
+    let caller = direct_signer(&harness);
+    call_contract_with_checks(
+        &harness,
+        caller,
+        "ethereum",
+        &[Check::err(gateway_err(
+            GatewayError::UnknownDestinationChain,
+        ))],
+    );
+}
+
+#[test]
+fn add_destination_chain_twice() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+    harness.add_destination_chain("ethereum");
+
+    harness.add_destination_chain_with_checks(
+        harness.operator,
+        "ethereum",
+        &[Check::err(gateway_err(
+            GatewayError::DestinationChainAlreadyRegistered,
+        ))],
+    );
+}
+
+#[test]
+fn remove_unregistered_destination_chain() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+
+    harness.remove_destination_chain_with_checks(
+        "ethereum",
+        &[Check::err(gateway_err(
+            GatewayError::DestinationChainNotRegistered,
+        ))],
+    );
+}
+
+#[test]
+fn add_destination_chain_unauthorized() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+
+    let unauthorized = direct_signer(&harness);
+    harness.add_destination_chain_with_checks(
+        unauthorized,
+        "ethereum",
+        &[Check::err(gateway_err(
+            GatewayError::InvalidOperatorOrAuthorityAccount,
+        ))],
+    );
+}
+
+#[test]
+fn init_destination_chain_registry_marks_config() {
+    let harness = GatewayTestHarness::new();
+    let config: GatewayConfig = harness.get_account_as(&harness.gateway.root).unwrap();
+    assert!(!config.has_destination_chain_registry());
+
+    harness.init_destination_chain_registry();
+
+    let config: GatewayConfig = harness.get_account_as(&harness.gateway.root).unwrap();
+    assert!(config.has_destination_chain_registry());
+}
+
+#[test]
+fn call_contract_without_registry_once_initialized() {
+    let harness = GatewayTestHarness::new();
+    harness.init_destination_chain_registry();
+    harness.add_destination_chain("ethereum");
+
+    let caller = direct_signer(&harness);
+    let ix = build_call_contract_ix(&harness, caller, None);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::MissingDestinationChainRegistry,
+        ))],
+    )]);
+
+    let ix = build_call_contract_ix(
+        &harness,
+        caller,
+        Some(DestinationChainRegistry::find_pda().0),
+    );
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+}
+
+#[test]
+fn call_contract_with_original_account_layout() {
+    let harness = GatewayTestHarness::new();
+
+    let caller = direct_signer(&harness);
+    let mut ix = build_call_contract_ix(&harness, caller, None);
+    // caller, signing PDA, gateway config, event authority and program, as
+    // sent by callers that predate the trailing optional accounts
+    ix.accounts.truncate(5);
+    assert_eq!(ix.accounts[4].pubkey, solana_axelar_gateway::ID);
+
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+}
+
+#[test]
+fn call_contract_with_invalid_chain_name() {
+    let harness = GatewayTestHarness::new();
+    let caller = direct_signer(&harness);
+
+    let too_long = "a".repeat(MAX_DESTINATION_CHAIN_LEN + 1);
+    for destination_chain in ["", too_long.as_str()] {
+        call_contract_with_checks(
+            &harness,
+            caller,
+            destination_chain,
+            &[Check::err(gateway_err(
+                GatewayError::InvalidDestinationChain,
+            ))],
+        );
+    }
+
+    let longest = "a".repeat(MAX_DESTINATION_CHAIN_LEN);
+    call_contract_with_checks(&harness, caller, &longest, &[Check::success()]);
+}
@@ -0,0 +1,211 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{
+    ExecutionOutcome, ExecutionReceipt, GatewayError, IncomingMessage, ValidateMessageSigner,
+    MAX_FAILURE_REASON_LEN,
+};
+use solana_axelar_std::Message;
+use solana_sdk::pubkey::Pubkey;
+
+fn build_record_execution_receipt_ix(
+    harness: &GatewayTestHarness,
+    message: &Message,
+    incoming_message_pda: Pubkey,
+    caller: Option<Pubkey>,
+    outcome: ExecutionOutcome,
+) -> solana_sdk::instruction::Instruction {
+    let incoming_message: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+
+    let caller = caller.unwrap_or_else(|| {
+        ValidateMessageSigner::create_pda(
+            &message.command_id(),
+            incoming_message.signing_pda_bump,
+            &message.destination_address.parse().unwrap(),
+        )
+        .expect("valid signing PDA")
+    });
+
+    let (event_authority, _, _) =
+        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+    solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::RecordExecutionReceipt {
+            incoming_message_pda,
+            caller,
+            event_authority,
+            program: solana_axelar_gateway::ID,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::RecordExecutionReceipt {
+            message: message.clone(),
+            outcome,
+        }
+        .data(),
+    }
+}
+
+/// Approves and executes the first default message.
+fn execute_first_message(harness: &GatewayTestHarness) -> (Message, Pubkey) {
+    let (message, incoming_message_pda) = approve_first_message(harness);
+
+    let validate_ix = build_validate_message_ix(harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&validate_ix, &[Check::success()])]);
+
+    (message, incoming_message_pda)
+}
+
+fn execution_receipt(
+    harness: &GatewayTestHarness,
+    incoming_message_pda: &Pubkey,
+) -> ExecutionReceipt {
+    harness
+        .get_account_as::<IncomingMessage>(incoming_message_pda)
+        .expect("incoming message should exist")
+        .execution_receipt
+}
+
+#[test]
+fn record_success_receipt() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = execute_first_message(&harness);
+    assert_eq!(
+        execution_receipt(&harness, &incoming_message_pda),
+        ExecutionReceipt::none()
+    );
+
+    let ix = build_record_execution_receipt_ix(
+        &harness,
+        &message,
+        incoming_message_pda,
+        None,
+        ExecutionOutcome::Success,
+    );
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    assert!(execution_receipt(&harness, &incoming_message_pda).is_success());
+}
+
+#[test]
+fn record_failure_receipt() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = execute_first_message(&harness);
+
+    let ix = build_record_execution_receipt_ix(
+        &harness,
+        &message,
+        incoming_message_pda,
+        None,
+        ExecutionOutcome::Failure {
+            reason: "insufficient liquidity".to_owned(),
+        },
+    );
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    assert!(execution_receipt(&harness, &incoming_message_pda).is_failure());
+}
+
+#[test]
+fn receipt_requires_executed_message() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    let ix = build_record_execution_receipt_ix(
+        &harness,
+        &message,
+        incoming_message_pda,
+        None,
+        ExecutionOutcome::Success,
+    );
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(GatewayError::MessageNotExecuted))],
+    )]);
+}
+
+#[test]
+fn receipt_can_only_be_recorded_once() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = execute_first_message(&harness);
+
+    let ix = build_record_execution_receipt_ix(
+        &harness,
+        &message,
+        incoming_message_pda,
+        None,
+        ExecutionOutcome::Success,
+    );
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    let ix = build_record_execution_receipt_ix(
+        &harness,
+        &message,
+        incoming_message_pda,
+        None,
+        ExecutionOutcome::Failure {
+            reason: "late failure".to_owned(),
+        },
+    );
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::ExecutionReceiptAlreadyRecorded,
+        ))],
+    )]);
+    assert!(execution_receipt(&harness, &incoming_message_pda).is_success());
+}
+
+#[test]
+fn receipt_rejects_long_failure_reason() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = execute_first_message(&harness);
+
+    let ix = build_record_execution_receipt_ix(
+        &harness,
+        &message,
+        incoming_message_pda,
+        None,
+        ExecutionOutcome::Failure {
+            reason: "x".repeat(MAX_FAILURE_REASON_LEN + 1),
+        },
+    );
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(GatewayError::FailureReasonTooLong))],
+    )]);
+}
+
+#[test]
+fn receipt_rejects_wrong_caller() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = execute_first_message(&harness);
+
+    let ix = build_record_execution_receipt_ix(
+        &harness,
+        &message,
+        incoming_message_pda,
+        Some(Pubkey::new_unique()),
+        ExecutionOutcome::Success,
+    );
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(GatewayError::InvalidSigningPDA))],
+    )]);
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayConfig, GatewayError, IncomingMessage};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+
+fn build_migrate_incoming_message_ix(
+    harness: &GatewayTestHarness,
+    command_id: [u8; 32],
+) -> Instruction {
+    Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::MigrateIncomingMessage {
+            incoming_message_pda: IncomingMessage::find_pda(&command_id).0,
+            gateway_root_pda: harness.gateway.root,
+            payer: harness.payer,
+            system_program: solana_sdk_ids::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::MigrateIncomingMessage { command_id }.data(),
+    }
+}
+
+/// Rewrites the incoming message account into the version 0 layout, as it was
+/// stored before `funder` and the following fields were appended.
+fn downgrade_to_v0(harness: &mut GatewayTestHarness, incoming_message_pda: &Pubkey) {
+    harness.update_account(incoming_message_pda, |account| {
+        account.data.truncate(IncomingMessage::V0_SPACE);
+        account.lamports = Rent::default().minimum_balance(IncomingMessage::V0_SPACE);
+    });
+}
+
+#[test]
+fn migrated_message_can_be_validated() {
+    let mut harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+    let before: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+    downgrade_to_v0(&mut harness, &incoming_message_pda);
+
+    let migrate_ix = build_migrate_incoming_message_ix(&harness, message.command_id());
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &migrate_ix,
+        &[
+            Check::success(),
+            Check::account(&incoming_message_pda).rent_exempt().build(),
+        ],
+    )]);
+    assert_eq!(
+        harness
+            .get_account(&incoming_message_pda)
+            .expect("incoming message should exist")
+            .data
+            .len(),
+        IncomingMessage::SPACE
+    );
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    let after: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+    assert_eq!(after.bump, before.bump);
+    assert_eq!(after.status, before.status);
+    assert_eq!(after.message_hash, before.message_hash);
+    assert_eq!(after.payload_hash, before.payload_hash);
+    assert_eq!(after.funder, config.rent_recipient());
+    assert!(!after.execution_receipt.is_recorded());
+    assert_eq!(after.approval_ttl, config.message_approval_ttl);
+
+    let validate_ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&validate_ix, &[Check::success()])]);
+}
+
+#[test]
+fn current_message_cannot_be_migrated() {
+    let harness = GatewayTestHarness::new();
+    let (message, _) = approve_first_message(&harness);
+
+    let migrate_ix = build_migrate_incoming_message_ix(&harness, message.command_id());
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &migrate_ix,
+        &[Check::err(gateway_err(
+            GatewayError::IncomingMessageAlreadyMigrated,
+        ))],
+    )]);
+}
@@ -0,0 +1,252 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::gateway::{create_signing_verifier_set_batch, create_verifier_info};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayError, SignatureVerificationSessionData};
+use solana_axelar_std::{PayloadType, SigningVerifierSetInfo};
+use solana_sdk::pubkey::Pubkey;
+
+/// Size of the verifier set, past the capacity of the inline signature slots.
+const SET_SIZE: u16 = 300;
+
+/// Quorum of the verifier set, every verifier has a weight of one.
+const QUORUM: u128 = 3;
+
+const PAYLOAD_MERKLE_ROOT: [u8; 32] = [9; 32];
+
+/// Maximum size of a serialized transaction.
+const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Size of `instruction` sent in a transaction of its own, signed by `payer`.
+fn transaction_size(instruction: &solana_sdk::instruction::Instruction, payer: &Pubkey) -> usize {
+    let message = solana_sdk::message::Message::new(&[instruction.clone()], Some(payer));
+    let signatures = usize::from(message.header.num_required_signatures);
+    // Compact length of the signatures, the signatures and the message
+    1 + signatures * 64 + message.serialize().len()
+}
+
+fn verifier_info(harness: &GatewayTestHarness, position: usize) -> SigningVerifierSetInfo {
+    create_verifier_info(
+        &harness.gateway.signers[position],
+        PAYLOAD_MERKLE_ROOT,
+        &harness.gateway.verifier_set_leaves[position],
+        position,
+        &harness.gateway.verifier_merkle_tree,
+        PayloadType::ApproveMessages,
+    )
+}
+
+fn session(harness: &GatewayTestHarness, session_pda: &Pubkey) -> SignatureVerificationSessionData {
+    harness
+        .get_account_as(session_pda)
+        .expect("verification session should exist")
+}
+
+fn session_data_len(harness: &GatewayTestHarness, session_pda: &Pubkey) -> usize {
+    harness
+        .get_account(session_pda)
+        .expect("verification session should exist")
+        .data
+        .len()
+}
+
+#[test]
+fn signatures_past_inline_slots_require_extension() {
+    let mut harness = GatewayTestHarness::new();
+    rotate_to_large_verifier_set(&mut harness, SET_SIZE, QUORUM);
+
+    let session_pda = harness
+        .init_payload_verification_session(PAYLOAD_MERKLE_ROOT, PayloadType::ApproveMessages);
+    assert_eq!(
+        session_data_len(&harness, &session_pda),
+        SignatureVerificationSessionData::SPACE
+    );
+
+    harness.verify_signature_with_checks(
+        PAYLOAD_MERKLE_ROOT,
+        verifier_info(&harness, 299),
+        &[Check::err(gateway_err(GatewayError::SlotIsOutOfBounds))],
+    );
+
+    harness.extend_verification_session_with_checks(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        SET_SIZE,
+        &[
+            Check::success(),
+            Check::account(&session_pda).rent_exempt().build(),
+        ],
+    );
+    assert_eq!(
+        session_data_len(&harness, &session_pda),
+        SignatureVerificationSessionData::space_for(SET_SIZE)
+    );
+
+    for position in [255, 256, 299] {
+        harness.verify_signature(PAYLOAD_MERKLE_ROOT, verifier_info(&harness, position));
+    }
+
+    assert!(session(&harness, &session_pda)
+        .signature_verification
+        .is_valid());
+}
+
+#[test]
+fn repeated_extended_slot_is_rejected() {
+    let mut harness = GatewayTestHarness::new();
+    rotate_to_large_verifier_set(&mut harness, SET_SIZE, QUORUM);
+
+    harness.init_payload_verification_session(PAYLOAD_MERKLE_ROOT, PayloadType::ApproveMessages);
+    harness.extend_verification_session(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        SET_SIZE,
+    );
+
+    harness.verify_signature(PAYLOAD_MERKLE_ROOT, verifier_info(&harness, 280));
+    harness.verify_signature_with_checks(
+        PAYLOAD_MERKLE_ROOT,
+        verifier_info(&harness, 280),
+        &[Check::err(gateway_err(GatewayError::SlotAlreadyVerified))],
+    );
+}
+
+#[test]
+fn batch_verification_with_extended_slots() {
+    let mut harness = GatewayTestHarness::new();
+    rotate_to_large_verifier_set(&mut harness, SET_SIZE, QUORUM);
+
+    let session_pda = harness
+        .init_payload_verification_session(PAYLOAD_MERKLE_ROOT, PayloadType::ApproveMessages);
+    harness.extend_verification_session(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        SET_SIZE,
+    );
+
+    harness.verify_signatures_batch(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        &[10, 256, 299],
+    );
+
+    assert!(session(&harness, &session_pda)
+        .signature_verification
+        .is_valid());
+}
+
+#[test]
+fn precompile_verification_with_extended_slots() {
+    let mut harness = GatewayTestHarness::new();
+    rotate_to_large_verifier_set(&mut harness, SET_SIZE, QUORUM);
+
+    let session_pda = harness
+        .init_payload_verification_session(PAYLOAD_MERKLE_ROOT, PayloadType::ApproveMessages);
+    harness.extend_verification_session(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        SET_SIZE,
+    );
+
+    let (verifier_infos, precompile_data) = harness.precompiled_verifier_infos(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        &[0, 270, 298],
+    );
+    harness.verify_signatures_precompile_with_checks(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        verifier_infos,
+        precompile_data,
+        &[Check::success()],
+    );
+
+    assert!(session(&harness, &session_pda)
+        .signature_verification
+        .is_valid());
+}
+
+#[test]
+fn extending_never_shrinks_session() {
+    let mut harness = GatewayTestHarness::new();
+    rotate_to_large_verifier_set(&mut harness, SET_SIZE, QUORUM);
+
+    let session_pda = harness
+        .init_payload_verification_session(PAYLOAD_MERKLE_ROOT, PayloadType::ApproveMessages);
+    harness.extend_verification_session(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        SET_SIZE,
+    );
+    harness.verify_signature(PAYLOAD_MERKLE_ROOT, verifier_info(&harness, 299));
+
+    harness.extend_verification_session(PAYLOAD_MERKLE_ROOT, PayloadType::ApproveMessages, 2);
+    assert_eq!(
+        session_data_len(&harness, &session_pda),
+        SignatureVerificationSessionData::space_for(SET_SIZE)
+    );
+
+    // The extended slots survive the no-op extension
+    harness.verify_signature_with_checks(
+        PAYLOAD_MERKLE_ROOT,
+        verifier_info(&harness, 299),
+        &[Check::err(gateway_err(GatewayError::SlotAlreadyVerified))],
+    );
+}
+
+#[test]
+fn batch_verification_past_transaction_size_limit() {
+    let mut harness = GatewayTestHarness::new();
+    rotate_to_large_verifier_set(&mut harness, SET_SIZE, QUORUM);
+
+    let session_pda = harness
+        .init_payload_verification_session(PAYLOAD_MERKLE_ROOT, PayloadType::ApproveMessages);
+    harness.extend_verification_session(
+        PAYLOAD_MERKLE_ROOT,
+        PayloadType::ApproveMessages,
+        SET_SIZE,
+    );
+
+    // More signatures than could ever fit in a single transaction
+    let positions = (0..12).map(|position| position * 25).collect::<Vec<_>>();
+    let batch = create_signing_verifier_set_batch(
+        &harness.gateway.signers,
+        &harness.gateway.verifier_set_leaves,
+        &positions,
+        PAYLOAD_MERKLE_ROOT,
+        &harness.gateway.verifier_merkle_tree,
+        PayloadType::ApproveMessages,
+    );
+    assert!(anchor_lang::prelude::borsh::to_vec(&batch).unwrap().len() > MAX_TRANSACTION_SIZE);
+
+    // Uploading the batch and verifying it each fit in a transaction
+    let instructions = harness.verify_signatures_batch_instructions(PAYLOAD_MERKLE_ROOT, &batch);
+    for instruction in &instructions {
+        assert!(transaction_size(instruction, &harness.operator) <= MAX_TRANSACTION_SIZE);
+    }
+
+    harness.verify_signatures_batch_with_checks(PAYLOAD_MERKLE_ROOT, batch, &[Check::success()]);
+
+    assert!(session(&harness, &session_pda)
+        .signature_verification
+        .is_valid());
+
+    // All the signatures were counted, none of them can be verified again
+    harness.verify_signatures_batch_with_checks(
+        PAYLOAD_MERKLE_ROOT,
+        create_signing_verifier_set_batch(
+            &harness.gateway.signers,
+            &harness.gateway.verifier_set_leaves,
+            &positions[positions.len() - 1..],
+            PAYLOAD_MERKLE_ROOT,
+            &harness.gateway.verifier_merkle_tree,
+            PayloadType::ApproveMessages,
+        ),
+        &[Check::err(gateway_err(GatewayError::SlotAlreadyVerified))],
+    );
+}
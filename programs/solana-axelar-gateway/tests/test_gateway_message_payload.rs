@@ -0,0 +1,207 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayError, Message, MessagePayload};
+use solana_sdk::pubkey::Pubkey;
+
+fn relayer(harness: &GatewayTestHarness) -> Pubkey {
+    let relayer = Pubkey::new_unique();
+    harness
+        .ensure_account_exists_with_lamports(relayer, solana_sdk::native_token::LAMPORTS_PER_SOL);
+    relayer
+}
+
+fn large_payload() -> Vec<u8> {
+    (0..4096_u32)
+        .map(|i| u8::try_from(i % 251).unwrap())
+        .collect()
+}
+
+/// Approves a message carrying `payload`.
+fn approve_message_with_payload(harness: &GatewayTestHarness, payload: &[u8]) -> Message {
+    let mut messages = default_messages();
+    messages[0].payload_hash = solana_keccak_hasher::hash(payload).to_bytes();
+
+    approve_first_of_messages(harness, &messages).0
+}
+
+#[test]
+fn upload_and_commit_message_payload() {
+    let harness = GatewayTestHarness::new();
+    let relayer = relayer(&harness);
+    let payload = large_payload();
+    let command_id = approve_message_with_payload(&harness, &payload).command_id();
+
+    harness.upload_message_payload(relayer, command_id, &payload, 900);
+    harness.commit_message_payload_with_checks(relayer, command_id, &[Check::success()]);
+
+    let message_payload: MessagePayload = harness
+        .get_account_as(&MessagePayload::find_pda(&command_id, &relayer).0)
+        .expect("message payload should exist");
+    assert!(message_payload.committed);
+    assert_eq!(message_payload.payer, relayer);
+    assert_eq!(message_payload.payload, payload);
+
+    // Committed payloads are final
+    harness.write_message_payload_with_checks(
+        relayer,
+        command_id,
+        0,
+        b"tampered".to_vec(),
+        &[Check::err(gateway_err(
+            GatewayError::MessagePayloadAlreadyCommitted,
+        ))],
+    );
+}
+
+#[test]
+fn commit_mismatching_payload() {
+    let harness = GatewayTestHarness::new();
+    let relayer = relayer(&harness);
+    let payload = large_payload();
+    let command_id = approve_message_with_payload(&harness, &payload).command_id();
+
+    let mut tampered_payload = payload;
+    tampered_payload[4000] ^= 1;
+    harness.upload_message_payload(relayer, command_id, &tampered_payload, 900);
+
+    harness.commit_message_payload_with_checks(
+        relayer,
+        command_id,
+        &[Check::err(gateway_err(
+            GatewayError::MessagePayloadHashMismatch,
+        ))],
+    );
+}
+
+#[test]
+fn write_message_payload_by_other_account() {
+    let harness = GatewayTestHarness::new();
+    let relayer_1 = relayer(&harness);
+    let relayer_2 = relayer(&harness);
+    let payload = large_payload();
+    let command_id = approve_message_with_payload(&harness, &payload).command_id();
+
+    harness.init_message_payload_with_checks(relayer_1, command_id, 4096, &[Check::success()]);
+
+    // relayer_2 signing for the buffer of relayer_1
+    let ix = solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::WriteMessagePayload {
+            payer: relayer_2,
+            message_payload: MessagePayload::find_pda(&command_id, &relayer_1).0,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::WriteMessagePayload {
+            command_id,
+            offset: 0,
+            bytes: payload[..900].to_vec(),
+        }
+        .data(),
+    };
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(GatewayError::InvalidFunder))],
+    )]);
+}
+
+#[test]
+fn message_payload_cannot_be_front_run() {
+    let harness = GatewayTestHarness::new();
+    let attacker = relayer(&harness);
+    let relayer = relayer(&harness);
+    let payload = large_payload();
+    let command_id = approve_message_with_payload(&harness, &payload).command_id();
+
+    // The attacker creates a buffer for the message first and never commits it
+    harness.init_message_payload_with_checks(attacker, command_id, 1, &[Check::success()]);
+
+    // The relayer's buffer lives at its own address, so it isn't blocked
+    harness.upload_message_payload(relayer, command_id, &payload, 900);
+    harness.commit_message_payload_with_checks(relayer, command_id, &[Check::success()]);
+
+    let message_payload_pda = MessagePayload::find_pda(&command_id, &relayer).0;
+    assert_ne!(
+        message_payload_pda,
+        MessagePayload::find_pda(&command_id, &attacker).0
+    );
+    let message_payload: MessagePayload = harness
+        .get_account_as(&message_payload_pda)
+        .expect("message payload should exist");
+    assert!(message_payload.committed);
+    assert_eq!(message_payload.payload, payload);
+}
+
+#[test]
+fn message_payload_for_executed_message() {
+    let harness = GatewayTestHarness::new();
+    let relayer = relayer(&harness);
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    harness.init_message_payload_with_checks(
+        relayer,
+        message.command_id(),
+        32,
+        &[Check::err(gateway_err(GatewayError::MessageNotApproved))],
+    );
+}
+
+#[test]
+fn message_payload_too_large() {
+    let harness = GatewayTestHarness::new();
+    let relayer = relayer(&harness);
+    let command_id = approve_first_message(&harness).0.command_id();
+
+    let payload_size = u32::try_from(MessagePayload::MAX_PAYLOAD_SIZE + 1).unwrap();
+    harness.init_message_payload_with_checks(
+        relayer,
+        command_id,
+        payload_size,
+        &[Check::err(gateway_err(GatewayError::PayloadBufferTooLarge))],
+    );
+}
+
+#[test]
+fn close_message_payload() {
+    let harness = GatewayTestHarness::new();
+    let relayer = relayer(&harness);
+    let payload = large_payload();
+    let command_id = approve_message_with_payload(&harness, &payload).command_id();
+
+    harness.upload_message_payload(relayer, command_id, &payload, 900);
+
+    let message_payload_pda = MessagePayload::find_pda(&command_id, &relayer).0;
+    let relayer_lamports_before = harness
+        .get_account(&relayer)
+        .expect("relayer should exist")
+        .lamports;
+    let buffer_lamports = harness
+        .get_account(&message_payload_pda)
+        .expect("message payload should exist")
+        .lamports;
+
+    harness.close_message_payload_with_checks(relayer, command_id, &[Check::success()]);
+
+    assert!(harness
+        .get_account_as::<MessagePayload>(&message_payload_pda)
+        .is_none());
+    let relayer_lamports_after = harness
+        .get_account(&relayer)
+        .expect("relayer should exist")
+        .lamports;
+    assert_eq!(
+        relayer_lamports_after,
+        relayer_lamports_before + buffer_lamports
+    );
+}
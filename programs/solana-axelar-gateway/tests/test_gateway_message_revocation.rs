@@ -0,0 +1,154 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayConfig, GatewayError, IncomingMessage, MessageStatus};
+use solana_sdk::pubkey::Pubkey;
+
+#[test]
+fn revoked_message_cannot_be_executed() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    harness.revoke_message(message.command_id());
+
+    let incoming_message: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+    assert_eq!(incoming_message.status, MessageStatus::revoked());
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(GatewayError::MessageRevoked))],
+    )]);
+}
+
+#[test]
+fn revoke_executed_message() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+
+    harness.revoke_message_with_checks(
+        harness.operator,
+        message.command_id(),
+        &[Check::err(gateway_err(GatewayError::MessageNotApproved))],
+    );
+}
+
+#[test]
+fn revoke_message_unauthorized() {
+    let harness = GatewayTestHarness::new();
+    let (message, _) = approve_first_message(&harness);
+
+    let unauthorized = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(
+        unauthorized,
+        solana_sdk::native_token::LAMPORTS_PER_SOL,
+    );
+
+    harness.revoke_message_with_checks(
+        unauthorized,
+        message.command_id(),
+        &[Check::err(gateway_err(
+            GatewayError::InvalidOperatorOrAuthorityAccount,
+        ))],
+    );
+}
+
+#[test]
+fn expired_message_cannot_be_executed() {
+    let mut harness = GatewayTestHarness::new();
+
+    let message_approval_ttl = 3600;
+    harness.set_message_approval_ttl(message_approval_ttl);
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    assert_eq!(config.message_approval_ttl, message_approval_ttl);
+
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+    let incoming_message: IncomingMessage = harness
+        .get_account_as(&incoming_message_pda)
+        .expect("incoming message should exist");
+    assert_eq!(incoming_message.approval_ttl, message_approval_ttl);
+
+    harness.ctx.mollusk.sysvars.clock.unix_timestamp += i64::from(message_approval_ttl) + 1;
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::MessageApprovalExpired,
+        ))],
+    )]);
+
+    // The message keeps the TTL it was approved with
+    harness.set_message_approval_ttl(0);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::MessageApprovalExpired,
+        ))],
+    )]);
+}
+
+#[test]
+fn message_approved_before_ttl_update_keeps_its_ttl() {
+    let mut harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    harness.set_message_approval_ttl(3600);
+    harness.ctx.mollusk.sysvars.clock.unix_timestamp += 10 * 3600;
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+}
+
+#[test]
+fn message_executed_within_ttl() {
+    let mut harness = GatewayTestHarness::new();
+
+    let message_approval_ttl = 3600;
+    harness.set_message_approval_ttl(message_approval_ttl);
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+    harness.ctx.mollusk.sysvars.clock.unix_timestamp += i64::from(message_approval_ttl);
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+}
+
+#[test]
+fn message_approval_ttl_can_be_disabled() {
+    let mut harness = GatewayTestHarness::new();
+
+    harness.set_message_approval_ttl(3600);
+    harness.set_message_approval_ttl(0);
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    assert_eq!(config.message_approval_ttl, 0);
+
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+    harness.ctx.mollusk.sysvars.clock.unix_timestamp += 10 * 365 * 24 * 3600;
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness
+        .ctx
+        .process_and_validate_instruction_chain(&[(&ix, &[Check::success()])]);
+}
@@ -0,0 +1,251 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayConfig, GatewayError, OperatorshipProposal};
+use solana_sdk::pubkey::Pubkey;
+
+fn new_signer(harness: &GatewayTestHarness) -> Pubkey {
+    let signer = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(signer, solana_sdk::native_token::LAMPORTS_PER_SOL);
+    signer
+}
+
+fn operator(harness: &GatewayTestHarness) -> Pubkey {
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    config.operator
+}
+
+/// Builds an `accept_operatorship` instruction for the proposal of
+/// `proposed_operator`, signed by `signer`.
+fn accept_operatorship_ix(
+    harness: &GatewayTestHarness,
+    signer: Pubkey,
+    proposed_operator: Pubkey,
+) -> solana_sdk::instruction::Instruction {
+    let (event_authority, _, _) =
+        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
+
+    solana_sdk::instruction::Instruction {
+        program_id: solana_axelar_gateway::ID,
+        accounts: solana_axelar_gateway::accounts::AcceptOperatorship {
+            gateway_root_pda: harness.gateway.root,
+            proposed_operator: signer,
+            operatorship_proposal: OperatorshipProposal::find_pda(
+                &harness.operator,
+                &proposed_operator,
+            )
+            .0,
+            payer: harness.operator,
+            event_authority,
+            program: solana_axelar_gateway::ID,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_gateway::instruction::AcceptOperatorship {}.data(),
+    }
+}
+
+#[test]
+fn propose_and_accept_operatorship() {
+    let harness = GatewayTestHarness::new();
+    let new_operator = new_signer(&harness);
+
+    harness.propose_gateway_operatorship_with_checks(
+        harness.operator,
+        new_operator,
+        &[Check::success()],
+    );
+
+    let proposal_pda = OperatorshipProposal::find_pda(&harness.operator, &new_operator).0;
+    let proposal: OperatorshipProposal = harness
+        .get_account_as(&proposal_pda)
+        .expect("proposal should exist");
+    assert_eq!(proposal.current_operator, harness.operator);
+    assert_eq!(proposal.proposed_operator, new_operator);
+    assert_eq!(proposal.payer, harness.operator);
+
+    // Proposing doesn't transfer the operatorship yet
+    assert_eq!(operator(&harness), harness.operator);
+
+    harness.accept_gateway_operatorship_with_checks(
+        harness.operator,
+        new_operator,
+        &[Check::success()],
+    );
+
+    assert_eq!(operator(&harness), new_operator);
+    assert!(harness
+        .get_account_as::<OperatorshipProposal>(&proposal_pda)
+        .is_none());
+}
+
+#[test]
+fn accept_operatorship_by_other_account() {
+    let harness = GatewayTestHarness::new();
+    let new_operator = new_signer(&harness);
+    let other = new_signer(&harness);
+
+    harness.propose_gateway_operatorship_with_checks(
+        harness.operator,
+        new_operator,
+        &[Check::success()],
+    );
+
+    // The proposal of `new_operator`, signed by another account
+    let ix = accept_operatorship_ix(&harness, other, new_operator);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(
+            GatewayError::InvalidProposedOperator,
+        ))],
+    )]);
+    assert_eq!(operator(&harness), harness.operator);
+}
+
+#[test]
+fn cancel_operatorship_proposal() {
+    let harness = GatewayTestHarness::new();
+    let new_operator = new_signer(&harness);
+
+    harness.propose_gateway_operatorship_with_checks(
+        harness.operator,
+        new_operator,
+        &[Check::success()],
+    );
+    harness.cancel_gateway_operatorship_proposal_with_checks(
+        harness.operator,
+        harness.operator,
+        new_operator,
+        &[Check::success()],
+    );
+
+    assert!(harness
+        .get_account_as::<OperatorshipProposal>(
+            &OperatorshipProposal::find_pda(&harness.operator, &new_operator).0
+        )
+        .is_none());
+
+    // The cancelled proposal can't be accepted anymore
+    harness.accept_gateway_operatorship_with_checks(
+        harness.operator,
+        new_operator,
+        &[Check::err({
+            let e: anchor_lang::error::Error =
+                anchor_lang::error::ErrorCode::AccountNotInitialized.into();
+            e.into()
+        })],
+    );
+    assert_eq!(operator(&harness), harness.operator);
+
+    // A new proposal can be made afterwards
+    harness.propose_gateway_operatorship_with_checks(
+        harness.operator,
+        new_operator,
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn propose_operatorship_unauthorized() {
+    let harness = GatewayTestHarness::new();
+    let unauthorized = new_signer(&harness);
+
+    harness.propose_gateway_operatorship_with_checks(
+        unauthorized,
+        unauthorized,
+        &[Check::err(gateway_err(
+            GatewayError::InvalidOperatorOrAuthorityAccount,
+        ))],
+    );
+}
+
+#[test]
+fn cancel_operatorship_proposal_unauthorized() {
+    let harness = GatewayTestHarness::new();
+    let new_operator = new_signer(&harness);
+
+    harness.propose_gateway_operatorship_with_checks(
+        harness.operator,
+        new_operator,
+        &[Check::success()],
+    );
+
+    // The proposed operator can't cancel the proposal either
+    harness.cancel_gateway_operatorship_proposal_with_checks(
+        new_operator,
+        harness.operator,
+        new_operator,
+        &[Check::err(gateway_err(
+            GatewayError::InvalidOperatorOrAuthorityAccount,
+        ))],
+    );
+}
+
+#[test]
+fn propose_several_operators() {
+    let harness = GatewayTestHarness::new();
+    let first = new_signer(&harness);
+    let second = new_signer(&harness);
+
+    // A pending proposal doesn't block proposing someone else
+    harness.propose_gateway_operatorship_with_checks(harness.operator, first, &[Check::success()]);
+    harness.propose_gateway_operatorship_with_checks(harness.operator, second, &[Check::success()]);
+
+    harness.accept_gateway_operatorship_with_checks(harness.operator, second, &[Check::success()]);
+    assert_eq!(operator(&harness), second);
+
+    // The other proposal was made for the previous operator
+    harness.accept_gateway_operatorship_with_checks(
+        harness.operator,
+        first,
+        &[Check::err(gateway_err(
+            GatewayError::StaleOperatorshipProposal,
+        ))],
+    );
+    assert_eq!(operator(&harness), second);
+
+    // The new operator can clean it up, refunding the previous operator
+    harness.cancel_gateway_operatorship_proposal_with_checks(
+        second,
+        harness.operator,
+        first,
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn stale_proposal_after_transfer() {
+    let harness = GatewayTestHarness::new();
+    let proposed = new_signer(&harness);
+    let replacement = new_signer(&harness);
+
+    harness.propose_gateway_operatorship_with_checks(
+        harness.operator,
+        proposed,
+        &[Check::success()],
+    );
+
+    // Another proposal gets accepted meanwhile
+    harness.transfer_gateway_operatorship(replacement);
+
+    harness.accept_gateway_operatorship_with_checks(
+        harness.operator,
+        proposed,
+        &[Check::err(gateway_err(
+            GatewayError::StaleOperatorshipProposal,
+        ))],
+    );
+    assert_eq!(operator(&harness), replacement);
+
+    // The current operator can propose again
+    harness.propose_gateway_operatorship_with_checks(replacement, proposed, &[Check::success()]);
+    harness.accept_gateway_operatorship_with_checks(replacement, proposed, &[Check::success()]);
+    assert_eq!(operator(&harness), proposed);
+}
@@ -0,0 +1,151 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use std::collections::BTreeMap;
+
+use mollusk_harness::gateway::generate_random_signer;
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayConfig, GatewayError, VerifierSetTracker};
+use solana_axelar_std::{PayloadType, PublicKey, VerifierSet};
+use solana_sdk::pubkey::Pubkey;
+
+fn gateway_config(harness: &GatewayTestHarness) -> GatewayConfig {
+    harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist")
+}
+
+#[test]
+fn pause_and_unpause() {
+    let harness = GatewayTestHarness::new();
+    assert!(!gateway_config(&harness).is_paused());
+
+    harness.set_gateway_pause_status(true);
+    assert!(gateway_config(&harness).is_paused());
+
+    harness.set_gateway_pause_status(false);
+    assert!(!gateway_config(&harness).is_paused());
+}
+
+#[test]
+fn pause_unauthorized() {
+    let harness = GatewayTestHarness::new();
+
+    let unauthorized = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(
+        unauthorized,
+        solana_sdk::native_token::LAMPORTS_PER_SOL,
+    );
+
+    harness.set_gateway_pause_status_with_checks(
+        unauthorized,
+        true,
+        &[Check::err(gateway_err(
+            GatewayError::InvalidOperatorOrAuthorityAccount,
+        ))],
+    );
+}
+
+#[test]
+fn pause_status_unchanged() {
+    let harness = GatewayTestHarness::new();
+
+    harness.set_gateway_pause_status_with_checks(
+        harness.operator,
+        false,
+        &[Check::err(gateway_err(GatewayError::PauseStatusUnchanged))],
+    );
+}
+
+#[test]
+fn paused_gateway_rejects_approve_message() {
+    let harness = GatewayTestHarness::new();
+    let config = gateway_config(&harness);
+
+    let (merklized_messages, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, &default_messages());
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+    harness.verify_all_signatures(payload_merkle_root, PayloadType::ApproveMessages);
+
+    harness.set_gateway_pause_status(true);
+    harness.approve_message_with_checks(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+        &[Check::err(gateway_err(GatewayError::GatewayPaused))],
+    );
+
+    harness.set_gateway_pause_status(false);
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+}
+
+#[test]
+fn paused_gateway_rejects_validate_message() {
+    let harness = GatewayTestHarness::new();
+    let (message, incoming_message_pda) = approve_first_message(&harness);
+
+    harness.set_gateway_pause_status(true);
+
+    let ix = build_validate_message_ix(&harness, &message, incoming_message_pda);
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &ix,
+        &[Check::err(gateway_err(GatewayError::GatewayPaused))],
+    )]);
+}
+
+#[test]
+fn paused_gateway_rejects_call_contract() {
+    let harness = GatewayTestHarness::new();
+
+    let direct_signer = Pubkey::new_unique();
+    harness.ensure_account_exists_with_lamports(
+        direct_signer,
+        solana_sdk::native_token::LAMPORTS_PER_SOL,
+    );
+
+    harness.set_gateway_pause_status(true);
+    harness.call_contract_with_checks(
+        direct_signer,
+        "ethereum".to_owned(),
+        "0xdeadbeef".to_owned(),
+        b"paused".to_vec(),
+        &[Check::err(gateway_err(GatewayError::GatewayPaused))],
+    );
+}
+
+#[test]
+fn paused_gateway_allows_rotate_signers() {
+    let harness = GatewayTestHarness::new();
+    let config = gateway_config(&harness);
+
+    harness.set_gateway_pause_status(true);
+
+    let (_, new_compressed_pubkey) = generate_random_signer();
+    let mut new_signers = BTreeMap::new();
+    new_signers.insert(PublicKey(new_compressed_pubkey), 100_u128);
+    let new_verifier_set = VerifierSet {
+        nonce: 2,
+        signers: new_signers,
+        quorum: 100,
+    };
+    let new_verifier_set_hash =
+        compute_new_verifier_set_hash(config.domain_separator, &new_verifier_set);
+
+    let verification_session_pda = harness
+        .init_payload_verification_session(new_verifier_set_hash, PayloadType::RotateSigners);
+    harness.verify_all_signatures(new_verifier_set_hash, PayloadType::RotateSigners);
+    harness.rotate_signers(new_verifier_set_hash, verification_session_pda);
+
+    let (new_tracker_pda, _) = VerifierSetTracker::find_pda(&new_verifier_set_hash);
+    assert!(harness.account_exists(&new_tracker_pda));
+    assert!(gateway_config(&harness).is_paused());
+}
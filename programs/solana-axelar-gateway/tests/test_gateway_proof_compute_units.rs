@@ -0,0 +1,142 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+//! Compute units `verify_signature` and `approve_message` spend on the Merkle
+//! proof of a small and a large tree.
+//!
+//! The comparison with the allocating proof path needs a baseline gateway,
+//! built from this tree with the commit adding `verify_single_leaf_proof`
+//! reverted:
+//!
+//! ```sh
+//! git worktree add /tmp/baseline HEAD && cd /tmp/baseline
+//! git revert --no-commit $(git log --reverse --format=%h -S verify_single_leaf_proof \
+//!     -- crates/solana-axelar-std/src/merkle.rs | head -1)
+//! cargo build-sbf --manifest-path programs/solana-axelar-gateway/Cargo.toml
+//! cd - && cargo build-sbf --manifest-path programs/solana-axelar-gateway/Cargo.toml
+//! GATEWAY_BASELINE_ELF=/tmp/baseline/target/deploy/solana_axelar_gateway.so \
+//!     cargo test -p solana-axelar-gateway --test test_gateway_proof_compute_units \
+//!     -- --ignored
+//! ```
+//!
+//! No figures are recorded yet: the SBF programs couldn't be built offline
+//! when this comparison was added, so it hasn't been run.
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::gateway::create_verifier_info;
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use solana_axelar_gateway::GatewayConfig;
+use solana_axelar_std::{CrossChainId, Message, PayloadType};
+
+/// Size of the verifier set and message batch of the small tree, the default
+/// harness verifier set.
+const SMALL_TREE: u16 = 2;
+
+/// Size of the verifier set and message batch of the large tree, filling the
+/// inline signature slots.
+const LARGE_TREE: u16 = 256;
+
+/// Compute units consumed by the measured instructions.
+#[derive(Debug, Clone, Copy)]
+struct ComputeUnits {
+    verify_signature: u64,
+    approve_message: u64,
+}
+
+fn messages(count: u16) -> Vec<Message> {
+    (0..count)
+        .map(|id| Message {
+            cc_id: CrossChainId {
+                chain: "ethereum".to_owned(),
+                id: format!("msg_{id}"),
+            },
+            source_address: "0xSourceAddress".to_owned(),
+            destination_chain: "solana".to_owned(),
+            destination_address: "DNHKNbf4JWJNnquuWJuNUSFGsXbDYs1sPR1ZvVhah827".to_owned(),
+            payload_hash: [1; 32],
+        })
+        .collect()
+}
+
+/// Measures the proofs of the last verifier and the last message of trees of
+/// `tree_size` leaves, running the gateway `elf` if given and the current
+/// build otherwise.
+fn measure(elf: Option<&[u8]>, tree_size: u16) -> ComputeUnits {
+    let mut harness = GatewayTestHarness::new();
+    if let Some(elf) = elf {
+        harness.ctx.mollusk.add_program_with_loader_and_elf(
+            &solana_axelar_gateway::ID,
+            &solana_sdk_ids::bpf_loader_upgradeable::ID,
+            elf,
+        );
+    }
+    if tree_size != SMALL_TREE {
+        rotate_to_large_verifier_set(&mut harness, tree_size, 2);
+    }
+
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    let (merklized_messages, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, &messages(tree_size));
+    let session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+
+    // The first verifier signs, the last one reaches the quorum
+    let last = usize::from(tree_size) - 1;
+    let verifier_info = |position: usize| {
+        create_verifier_info(
+            &harness.gateway.signers[position],
+            payload_merkle_root,
+            &harness.gateway.verifier_set_leaves[position],
+            position,
+            &harness.gateway.verifier_merkle_tree,
+            PayloadType::ApproveMessages,
+        )
+    };
+    harness.verify_signature(payload_merkle_root, verifier_info(0));
+    let verify_signature = harness
+        .verify_signature(payload_merkle_root, verifier_info(last))
+        .compute_units_consumed;
+
+    let approve_message = harness
+        .approve_message(&merklized_messages[last], payload_merkle_root, session_pda)
+        .compute_units_consumed;
+
+    ComputeUnits {
+        verify_signature,
+        approve_message,
+    }
+}
+
+#[test]
+fn proof_compute_units_grow_with_tree_size() {
+    let small = measure(None, SMALL_TREE);
+    let large = measure(None, LARGE_TREE);
+
+    assert!(large.verify_signature > small.verify_signature);
+    assert!(large.approve_message > small.approve_message);
+}
+
+#[test]
+#[ignore = "needs the baseline gateway program, see the module docs"]
+fn single_leaf_proofs_use_fewer_compute_units_than_baseline() {
+    let path = std::env::var("GATEWAY_BASELINE_ELF")
+        .expect("GATEWAY_BASELINE_ELF should point to the baseline gateway program");
+    let baseline_elf = std::fs::read(path).expect("baseline gateway program should be readable");
+
+    for tree_size in [SMALL_TREE, LARGE_TREE] {
+        let baseline = measure(Some(&baseline_elf), tree_size);
+        let current = measure(None, tree_size);
+
+        assert!(
+            current.verify_signature < baseline.verify_signature,
+            "{tree_size} leaves: {current:?} vs baseline {baseline:?}"
+        );
+        assert!(
+            current.approve_message < baseline.approve_message,
+            "{tree_size} leaves: {current:?} vs baseline {baseline:?}"
+        );
+    }
+}
@@ -7,82 +7,10 @@ use helpers::*;
 use anchor_lang::{InstructionData, ToAccountMetas};
 use mollusk_harness::{GatewayTestHarness, TestHarness};
 use mollusk_svm::result::Check;
-use solana_axelar_gateway::{
-    GatewayConfig, GatewayError, IncomingMessage, MessageStatus, ValidateMessageSigner,
-};
-use solana_axelar_std::{Message, PayloadType};
+use solana_axelar_gateway::{GatewayError, IncomingMessage, MessageStatus, ValidateMessageSigner};
+use solana_axelar_std::Message;
 use solana_sdk::pubkey::Pubkey;
 
-/// Approves the first default message and returns (message, incoming_message_pda).
-fn approve_first_message(harness: &GatewayTestHarness) -> (Message, Pubkey) {
-    let config: GatewayConfig = harness
-        .get_account_as(&harness.gateway.root)
-        .expect("gateway config should exist");
-
-    let messages = default_messages();
-    let (merklized_messages, payload_merkle_root) =
-        create_merklized_messages(config.domain_separator, &messages);
-
-    let verification_session_pda = harness
-        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
-
-    let verifier_infos =
-        build_verifier_infos(harness, payload_merkle_root, PayloadType::ApproveMessages);
-    for info in &verifier_infos {
-        harness.verify_signature(payload_merkle_root, info.clone());
-    }
-
-    harness.approve_message(
-        &merklized_messages[0],
-        payload_merkle_root,
-        verification_session_pda,
-    );
-
-    let incoming_message_pda = IncomingMessage::find_pda(&messages[0].command_id()).0;
-    (messages[0].clone(), incoming_message_pda)
-}
-
-/// Builds a ValidateMessage instruction for the given message.
-/// The `caller` is set to the signing PDA derived from the message's destination address.
-fn build_validate_message_ix(
-    harness: &GatewayTestHarness,
-    message: &Message,
-    incoming_message_pda: Pubkey,
-) -> solana_sdk::instruction::Instruction {
-    let incoming_message: IncomingMessage = harness
-        .get_account_as(&incoming_message_pda)
-        .expect("incoming message should exist");
-
-    let destination_address: Pubkey = message.destination_address.parse().unwrap();
-    let command_id = message.command_id();
-
-    let caller = ValidateMessageSigner::create_pda(
-        &command_id,
-        incoming_message.signing_pda_bump,
-        &destination_address,
-    )
-    .expect("valid signing PDA");
-
-    let (event_authority, _, _) =
-        mollusk_test_utils::get_event_authority_and_program_accounts(&solana_axelar_gateway::ID);
-
-    solana_sdk::instruction::Instruction {
-        program_id: solana_axelar_gateway::ID,
-        accounts: solana_axelar_gateway::accounts::ValidateMessage {
-            incoming_message_pda,
-            caller,
-            gateway_root_pda: harness.gateway.root,
-            event_authority,
-            program: solana_axelar_gateway::ID,
-        }
-        .to_account_metas(None),
-        data: solana_axelar_gateway::instruction::ValidateMessage {
-            message: message.clone(),
-        }
-        .data(),
-    }
-}
-
 #[test]
 fn validate_message_success() {
     let harness = GatewayTestHarness::new();
@@ -0,0 +1,139 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayConfig, VerifierSetEpoch, VerifierSetTracker};
+use solana_axelar_std::{PayloadType, U256};
+use solana_sdk::pubkey::Pubkey;
+
+fn config(harness: &GatewayTestHarness) -> GatewayConfig {
+    harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist")
+}
+
+fn verifier_set_epoch(harness: &GatewayTestHarness, pda: &Pubkey) -> VerifierSetEpoch {
+    harness
+        .get_account_as(pda)
+        .expect("verifier set epoch should exist")
+}
+
+fn rotate_to(harness: &GatewayTestHarness, new_verifier_set_hash: [u8; 32]) {
+    let verification_session_pda = harness
+        .init_payload_verification_session(new_verifier_set_hash, PayloadType::RotateSigners);
+    for info in build_verifier_infos(harness, new_verifier_set_hash, PayloadType::RotateSigners) {
+        harness.verify_signature(new_verifier_set_hash, info);
+    }
+    harness.rotate_signers(new_verifier_set_hash, verification_session_pda);
+}
+
+#[test]
+fn initial_verifier_set_is_recorded() {
+    let harness = GatewayTestHarness::new();
+    let tracker: VerifierSetTracker = harness
+        .get_account_as(&harness.gateway.verifier_set_tracker)
+        .expect("verifier set tracker should exist");
+
+    let config = config(&harness);
+    let set_epoch = verifier_set_epoch(&harness, &config.current_verifier_set_epoch_pda());
+
+    assert_eq!(set_epoch.epoch, U256::ONE);
+    assert_eq!(set_epoch.verifier_set_hash, tracker.verifier_set_hash);
+    assert_eq!(
+        set_epoch.verifier_set_tracker_pda(),
+        harness.gateway.verifier_set_tracker
+    );
+}
+
+#[test]
+fn rotation_records_new_verifier_set() {
+    let harness = GatewayTestHarness::new();
+    let initial_tracker = harness.gateway.verifier_set_tracker;
+    let new_verifier_set_hash = [9; 32];
+
+    rotate_to(&harness, new_verifier_set_hash);
+
+    let config = config(&harness);
+    let current = verifier_set_epoch(&harness, &config.current_verifier_set_epoch_pda());
+    assert_eq!(current.epoch, U256::from(2_u64));
+    assert_eq!(current.verifier_set_hash, new_verifier_set_hash);
+    assert_eq!(
+        current.verifier_set_tracker_pda(),
+        VerifierSetTracker::find_pda(&new_verifier_set_hash).0
+    );
+
+    // Both verifier sets are still within the retention
+    let valid_trackers: Vec<Pubkey> = config
+        .valid_verifier_set_epoch_pdas()
+        .map(|pda| verifier_set_epoch(&harness, &pda).verifier_set_tracker_pda())
+        .collect();
+    assert_eq!(
+        valid_trackers,
+        vec![
+            initial_tracker,
+            VerifierSetTracker::find_pda(&new_verifier_set_hash).0
+        ]
+    );
+}
+
+#[test]
+fn record_verifier_set_epoch_backfills_history() {
+    let harness = GatewayTestHarness::new();
+    let first_epoch_pda = VerifierSetEpoch::find_pda(U256::ONE).0;
+
+    // Simulate a verifier set rotated in before the history existed
+    harness
+        .ctx
+        .account_store
+        .borrow_mut()
+        .remove(&first_epoch_pda);
+
+    harness.record_verifier_set_epoch_with_checks(
+        harness.gateway.verifier_set_tracker,
+        &[Check::success()],
+    );
+
+    let set_epoch = verifier_set_epoch(&harness, &first_epoch_pda);
+    assert_eq!(set_epoch.epoch, U256::ONE);
+    assert_eq!(
+        set_epoch.verifier_set_tracker_pda(),
+        harness.gateway.verifier_set_tracker
+    );
+}
+
+#[test]
+fn rotation_without_epoch_record() {
+    let harness = GatewayTestHarness::new();
+    let new_verifier_set_hash = [9; 32];
+    let new_epoch_pda = VerifierSetEpoch::find_pda(U256::from(2_u64)).0;
+
+    // Callers built for the layout without the epoch record still rotate
+    let verification_session_pda = harness
+        .init_payload_verification_session(new_verifier_set_hash, PayloadType::RotateSigners);
+    for info in build_verifier_infos(&harness, new_verifier_set_hash, PayloadType::RotateSigners) {
+        harness.verify_signature(new_verifier_set_hash, info);
+    }
+    harness.rotate_signers_with_epoch_record(
+        new_verifier_set_hash,
+        verification_session_pda,
+        None,
+        &[Check::success()],
+    );
+
+    assert_eq!(config(&harness).current_epoch, U256::from(2_u64));
+    assert!(harness.get_account(&new_epoch_pda).is_none());
+
+    // The record can be backfilled afterwards
+    harness.record_verifier_set_epoch_with_checks(
+        VerifierSetTracker::find_pda(&new_verifier_set_hash).0,
+        &[Check::success()],
+    );
+
+    let set_epoch = verifier_set_epoch(&harness, &new_epoch_pda);
+    assert_eq!(set_epoch.epoch, U256::from(2_u64));
+    assert_eq!(set_epoch.verifier_set_hash, new_verifier_set_hash);
+}
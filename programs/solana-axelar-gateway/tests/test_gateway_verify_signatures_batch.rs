@@ -0,0 +1,238 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use anchor_lang::InstructionData;
+use mollusk_harness::gateway::create_signing_verifier_set_batch;
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{
+    GatewayConfig, GatewayError, SignatureBatchBuffer, SignatureVerificationSessionData,
+};
+use solana_axelar_std::{PayloadType, SigningVerifierSetBatch};
+
+fn init_default_session(harness: &GatewayTestHarness) -> ([u8; 32], solana_sdk::pubkey::Pubkey) {
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+
+    let (_, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, &default_messages());
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+
+    (payload_merkle_root, verification_session_pda)
+}
+
+fn batch(
+    harness: &GatewayTestHarness,
+    payload_merkle_root: [u8; 32],
+    positions: &[usize],
+) -> SigningVerifierSetBatch {
+    create_signing_verifier_set_batch(
+        &harness.gateway.signers,
+        &harness.gateway.verifier_set_leaves,
+        positions,
+        payload_merkle_root,
+        &harness.gateway.verifier_merkle_tree,
+        PayloadType::ApproveMessages,
+    )
+}
+
+fn session(
+    harness: &GatewayTestHarness,
+    verification_session_pda: &solana_sdk::pubkey::Pubkey,
+) -> SignatureVerificationSessionData {
+    harness
+        .get_account_as(verification_session_pda)
+        .expect("session should exist")
+}
+
+#[test]
+fn verify_all_signatures_in_one_batch() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, verification_session_pda) = init_default_session(&harness);
+
+    harness.verify_signatures_batch(payload_merkle_root, PayloadType::ApproveMessages, &[0, 1]);
+
+    assert!(session(&harness, &verification_session_pda).is_valid());
+
+    // The session can be used to approve messages as usual
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    let (merklized_messages, _) =
+        create_merklized_messages(config.domain_separator, &default_messages());
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+}
+
+#[test]
+fn batch_and_single_verification_share_slots() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, verification_session_pda) = init_default_session(&harness);
+
+    harness.verify_signatures_batch(payload_merkle_root, PayloadType::ApproveMessages, &[1]);
+    assert!(!session(&harness, &verification_session_pda).is_valid());
+
+    // The already verified signer can't be counted twice
+    harness.verify_signatures_batch_with_checks(
+        payload_merkle_root,
+        batch(&harness, payload_merkle_root, &[1]),
+        &[Check::err(gateway_err(GatewayError::SlotAlreadyVerified))],
+    );
+
+    let verifier_infos =
+        build_verifier_infos(&harness, payload_merkle_root, PayloadType::ApproveMessages);
+    harness.verify_signature(payload_merkle_root, verifier_infos[0].clone());
+    assert!(session(&harness, &verification_session_pda).is_valid());
+}
+
+#[test]
+fn empty_batch_fails() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+
+    let mut batch = batch(&harness, payload_merkle_root, &[0, 1]);
+    batch.verifiers.clear();
+
+    harness.verify_signatures_batch_with_checks(
+        payload_merkle_root,
+        batch,
+        &[Check::err(gateway_err(GatewayError::InvalidSignerBatch))],
+    );
+}
+
+#[test]
+fn unordered_batch_fails() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+
+    let mut batch = batch(&harness, payload_merkle_root, &[0, 1]);
+    batch.verifiers.reverse();
+
+    harness.verify_signatures_batch_with_checks(
+        payload_merkle_root,
+        batch,
+        &[Check::err(gateway_err(GatewayError::InvalidSignerBatch))],
+    );
+}
+
+#[test]
+fn tampered_leaf_fails_multiproof() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+
+    let mut batch = batch(&harness, payload_merkle_root, &[0, 1]);
+    batch.verifiers[1].leaf.signer_weight += 1;
+
+    harness.verify_signatures_batch_with_checks(
+        payload_merkle_root,
+        batch,
+        &[Check::err(gateway_err(GatewayError::InvalidMerkleProof))],
+    );
+}
+
+#[test]
+fn invalid_signature_in_batch_fails() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+
+    // The second signature is made over another payload
+    let mut batch = batch(&harness, payload_merkle_root, &[0, 1]);
+    let other_batch = self::batch(&harness, [42; 32], &[0, 1]);
+    batch.verifiers[1].signature = other_batch.verifiers[1].signature;
+
+    harness.verify_signatures_batch_with_checks(
+        payload_merkle_root,
+        batch,
+        &[Check::err(gateway_err(
+            GatewayError::SignatureVerificationFailed,
+        ))],
+    );
+}
+
+#[test]
+fn batch_buffer_closed_after_verification() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+
+    let batch = batch(&harness, payload_merkle_root, &[0, 1]);
+    let instructions = harness.verify_signatures_batch_instructions(payload_merkle_root, &batch);
+    let signature_batch_buffer = instructions[0].accounts[1].pubkey;
+
+    let chain = instructions
+        .iter()
+        .map(|ix| (ix, [Check::success()].as_slice()))
+        .collect::<Vec<_>>();
+    harness.ctx.process_and_validate_instruction_chain(&chain);
+
+    assert!(harness
+        .get_account_as::<SignatureBatchBuffer>(&signature_batch_buffer)
+        .is_none());
+}
+
+#[test]
+fn batch_signed_for_other_payload_type_fails() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+
+    // A batch signed for a rotation, verified against the approval session
+    let rotation_batch = create_signing_verifier_set_batch(
+        &harness.gateway.signers,
+        &harness.gateway.verifier_set_leaves,
+        &[0, 1],
+        payload_merkle_root,
+        &harness.gateway.verifier_merkle_tree,
+        PayloadType::RotateSigners,
+    );
+    let mut instructions =
+        harness.verify_signatures_batch_instructions(payload_merkle_root, &rotation_batch);
+    instructions.last_mut().unwrap().data =
+        solana_axelar_gateway::instruction::VerifySignaturesBatch {
+            payload_merkle_root,
+            payload_type: PayloadType::ApproveMessages,
+        }
+        .data();
+
+    let (verify_ix, upload_ixs) = instructions.split_last().unwrap();
+    let mut chain = upload_ixs
+        .iter()
+        .map(|ix| (ix, [Check::success()].as_slice()))
+        .collect::<Vec<_>>();
+    let checks = [Check::err(gateway_err(
+        GatewayError::SignerBatchPayloadTypeMismatch,
+    ))];
+    chain.push((verify_ix, &checks[..]));
+    harness.ctx.process_and_validate_instruction_chain(&chain);
+}
+
+#[test]
+fn oversized_batch_buffer_is_not_allocated() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+    let batch = batch(&harness, payload_merkle_root, &[0, 1]);
+
+    let mut instructions =
+        harness.verify_signatures_batch_instructions(payload_merkle_root, &batch);
+    let init_ix = &mut instructions[0];
+    let buffer_id = u64::from_le_bytes(init_ix.data[8..16].try_into().unwrap());
+    init_ix.data = solana_axelar_gateway::instruction::InitializeSignatureBatchBuffer {
+        buffer_id,
+        batch_size: u32::try_from(SignatureBatchBuffer::MAX_BATCH_SIZE + 1).unwrap(),
+    }
+    .data();
+
+    harness.ctx.process_and_validate_instruction_chain(&[(
+        &instructions[0],
+        &[Check::err(gateway_err(GatewayError::PayloadBufferTooLarge))],
+    )]);
+
+    let signature_batch_buffer = SignatureBatchBuffer::find_pda(&harness.operator, buffer_id).0;
+    assert!(harness.get_account(&signature_batch_buffer).is_none());
+}
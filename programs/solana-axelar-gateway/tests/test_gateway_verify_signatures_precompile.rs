@@ -0,0 +1,131 @@
+#![cfg(test)]
+#![allow(clippy::indexing_slicing)]
+
+mod helpers;
+use helpers::*;
+
+use mollusk_harness::{GatewayTestHarness, TestHarness};
+use mollusk_svm::result::Check;
+use solana_axelar_gateway::{GatewayConfig, GatewayError, SignatureVerificationSessionData};
+use solana_axelar_std::PayloadType;
+
+fn init_default_session(harness: &GatewayTestHarness) -> ([u8; 32], solana_sdk::pubkey::Pubkey) {
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+
+    let (_, payload_merkle_root) =
+        create_merklized_messages(config.domain_separator, &default_messages());
+    let verification_session_pda = harness
+        .init_payload_verification_session(payload_merkle_root, PayloadType::ApproveMessages);
+
+    (payload_merkle_root, verification_session_pda)
+}
+
+#[test]
+fn verify_all_signatures_in_one_instruction() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, verification_session_pda) = init_default_session(&harness);
+
+    harness.verify_all_signatures_precompile(payload_merkle_root, PayloadType::ApproveMessages);
+
+    let session: SignatureVerificationSessionData = harness
+        .get_account_as(&verification_session_pda)
+        .expect("session should exist");
+    assert!(session.is_valid());
+
+    // The session can be used to approve messages as usual
+    let config: GatewayConfig = harness
+        .get_account_as(&harness.gateway.root)
+        .expect("gateway config should exist");
+    let (merklized_messages, _) =
+        create_merklized_messages(config.domain_separator, &default_messages());
+    harness.approve_message(
+        &merklized_messages[0],
+        payload_merkle_root,
+        verification_session_pda,
+    );
+}
+
+#[test]
+fn precompile_and_single_verification_share_slots() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+    let payload_type = PayloadType::ApproveMessages;
+
+    let verifier_infos = build_verifier_infos(&harness, payload_merkle_root, payload_type);
+    harness.verify_signature(payload_merkle_root, verifier_infos[0].clone());
+
+    let (precompiled_infos, precompile_data) =
+        harness.precompiled_verifier_infos(payload_merkle_root, payload_type, &[0]);
+    harness.verify_signatures_precompile_with_checks(
+        payload_merkle_root,
+        payload_type,
+        precompiled_infos,
+        precompile_data,
+        &[Check::err(gateway_err(GatewayError::SlotAlreadyVerified))],
+    );
+}
+
+#[test]
+fn precompile_signed_wrong_payload_type() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+
+    let (verifier_infos, _) =
+        harness.precompiled_verifier_infos(payload_merkle_root, PayloadType::ApproveMessages, &[0]);
+    let (_, precompile_data) =
+        harness.precompiled_verifier_infos(payload_merkle_root, PayloadType::RotateSigners, &[0]);
+
+    harness.verify_signatures_precompile_with_checks(
+        payload_merkle_root,
+        PayloadType::ApproveMessages,
+        verifier_infos,
+        precompile_data,
+        &[Check::err(gateway_err(
+            GatewayError::PrecompileSignatureMismatch,
+        ))],
+    );
+}
+
+#[test]
+fn precompile_signers_out_of_order() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+    let payload_type = PayloadType::ApproveMessages;
+
+    let (mut verifier_infos, precompile_data) =
+        harness.precompiled_verifier_infos(payload_merkle_root, payload_type, &[0, 1]);
+    verifier_infos.reverse();
+
+    harness.verify_signatures_precompile_with_checks(
+        payload_merkle_root,
+        payload_type,
+        verifier_infos,
+        precompile_data,
+        &[Check::err(gateway_err(
+            GatewayError::PrecompileSignatureMismatch,
+        ))],
+    );
+}
+
+#[test]
+fn precompile_signature_count_mismatch() {
+    let harness = GatewayTestHarness::new();
+    let (payload_merkle_root, _) = init_default_session(&harness);
+    let payload_type = PayloadType::ApproveMessages;
+
+    let (mut verifier_infos, precompile_data) =
+        harness.precompiled_verifier_infos(payload_merkle_root, payload_type, &[0, 1]);
+    verifier_infos.pop();
+
+    harness.verify_signatures_precompile_with_checks(
+        payload_merkle_root,
+        payload_type,
+        verifier_infos,
+        precompile_data,
+        &[Check::err(gateway_err(
+            GatewayError::PrecompileSignatureMismatch,
+        ))],
+    );
+}
@@ -95,3 +95,24 @@ pub struct InterchainTransferSent {
     pub amount: u64,
     pub data_hash: Option<[u8; 32]>,
 }
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipProposed {
+    pub current_operator: Pubkey,
+    pub proposed_operator: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipProposalCancelled {
+    pub current_operator: Pubkey,
+    pub proposed_operator: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipTransferred {
+    pub previous_operator: Pubkey,
+    pub new_operator: Pubkey,
+}
@@ -19,6 +19,21 @@ pub struct GMPAccounts<'info> {
     pub gas_service: AccountInfo<'info>,
     pub gas_treasury: AccountInfo<'info>,
     pub gas_event_authority: AccountInfo<'info>,
+    /// The gateway's destination chain registry, once it has been initialized
+    pub gateway_destination_chain_registry: Option<AccountInfo<'info>>,
+}
+
+impl<'info> GMPAccounts<'info> {
+    /// Takes the gateway's destination chain registry from the first of the
+    /// remaining accounts, if any. The gateway checks its address.
+    #[must_use]
+    pub fn with_destination_chain_registry(
+        mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Self {
+        self.gateway_destination_chain_registry = remaining_accounts.first().cloned();
+        self
+    }
 }
 
 pub trait ToGMPAccounts<'info> {
@@ -95,6 +110,8 @@ pub fn send_to_hub(
         caller: gmp_accounts.its_program.to_account_info(),
         signing_pda: Some(gmp_accounts.call_contract_signing_pda.to_account_info()),
         gateway_root_pda: gmp_accounts.gateway_root_pda.to_account_info(),
+        destination_chain_registry: gmp_accounts.gateway_destination_chain_registry,
+        call_contract_nonce: None,
         // For event_cpi
         event_authority: gmp_accounts.gateway_event_authority.to_account_info(),
         program: gmp_accounts.gateway_program.to_account_info(),
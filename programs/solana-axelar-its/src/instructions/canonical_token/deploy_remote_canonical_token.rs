@@ -102,6 +102,7 @@ impl<'info> ToGMPAccounts<'info> for DeployRemoteCanonicalInterchainToken<'info>
             gas_service: self.gas_service.to_account_info(),
             gas_treasury: self.gas_treasury.to_account_info(),
             gas_event_authority: self.gas_event_authority.to_account_info(),
+            gateway_destination_chain_registry: None,
         }
     }
 }
@@ -140,7 +141,10 @@ pub fn deploy_remote_canonical_interchain_token_handler(
         minter: None, // Canonical tokens don't have destination minters
     });
 
-    let gmp_accounts = ctx.accounts.to_gmp_accounts();
+    let gmp_accounts = ctx
+        .accounts
+        .to_gmp_accounts()
+        .with_destination_chain_registry(ctx.remaining_accounts);
 
     send_to_hub_wrap(gmp_accounts, payload, destination_chain, gas_value)?;
 
@@ -0,0 +1,145 @@
+use crate::{
+    events::OperatorshipTransferred,
+    state::{roles, InterchainTokenService, RoleProposal, UserRoles},
+    ItsError,
+};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{prelude::*, InstructionData};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptOperatorship<'info> {
+    pub system_program: Program<'info, System>,
+
+    /// Payer for transaction fees and account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Destination user account (signer accepting the OPERATOR role)
+    pub destination_user_account: Signer<'info>,
+
+    /// Destination user roles account
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserRoles::DISCRIMINATOR.len() + UserRoles::INIT_SPACE,
+        seeds = [
+            UserRoles::SEED_PREFIX,
+            resource_account.key().as_ref(),
+            destination_user_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub destination_roles_account: Account<'info, UserRoles>,
+
+    /// The ITS root PDA (resource account)
+    #[account(
+        seeds = [InterchainTokenService::SEED_PREFIX],
+        bump = resource_account.bump,
+    )]
+    pub resource_account: Account<'info, InterchainTokenService>,
+
+    /// Origin user account (who proposed the transfer, receives the proposal rent back)
+    /// CHECK: This is treated as an arbitrary user account and is only used for its public key.
+    #[account(mut)]
+    pub origin_user_account: UncheckedAccount<'info>,
+
+    /// Origin user roles account (current operator's roles)
+    #[account(
+        mut,
+        seeds = [
+            UserRoles::SEED_PREFIX,
+            resource_account.key().as_ref(),
+            origin_user_account.key().as_ref(),
+        ],
+        bump = origin_roles_account.bump,
+        constraint = origin_roles_account.has_operator_role() @ ItsError::MissingOperatorRole,
+    )]
+    pub origin_roles_account: Account<'info, UserRoles>,
+
+    /// The proposal made with `propose_operatorship`
+    #[account(
+        mut,
+        seeds = [
+            RoleProposal::SEED_PREFIX,
+            resource_account.key().as_ref(),
+            origin_user_account.key().as_ref(),
+            destination_user_account.key().as_ref(),
+        ],
+        bump = proposal_account.bump,
+        close = origin_user_account,
+    )]
+    pub proposal_account: Account<'info, RoleProposal>,
+}
+
+/// Second step of the two-step operatorship transfer: the destination user
+/// accepts the OPERATOR role, which is removed from the origin user.
+pub fn accept_operatorship_handler(ctx: Context<AcceptOperatorship>) -> Result<()> {
+    msg!("Instruction: AcceptOperatorship");
+
+    let origin_roles = &mut ctx.accounts.origin_roles_account;
+    let destination_roles = &mut ctx.accounts.destination_roles_account;
+
+    origin_roles.remove(roles::OPERATOR);
+
+    destination_roles.insert(roles::OPERATOR);
+    destination_roles.bump = ctx.bumps.destination_roles_account;
+
+    emit_cpi!(OperatorshipTransferred {
+        previous_operator: ctx.accounts.origin_user_account.key(),
+        new_operator: ctx.accounts.destination_user_account.key(),
+    });
+
+    // Close if no remaining roles
+    if !ctx.accounts.origin_roles_account.has_roles() {
+        ctx.accounts
+            .origin_roles_account
+            .close(ctx.accounts.payer.to_account_info())
+            .map_err(|e| e.with_account_name("origin_roles_account"))?;
+    }
+
+    Ok(())
+}
+
+/// Creates an AcceptOperatorship instruction
+pub fn make_accept_operatorship_instruction(
+    payer: Pubkey,
+    destination_user_account: Pubkey,
+    origin_user_account: Pubkey,
+) -> (Instruction, crate::accounts::AcceptOperatorship) {
+    let resource_account = InterchainTokenService::find_pda().0;
+
+    let origin_roles_account = UserRoles::find_pda(&resource_account, &origin_user_account).0;
+    let destination_roles_account =
+        UserRoles::find_pda(&resource_account, &destination_user_account).0;
+    let proposal_account = RoleProposal::find_pda(
+        &resource_account,
+        &origin_user_account,
+        &destination_user_account,
+    )
+    .0;
+
+    let (event_authority, _) = crate::EVENT_AUTHORITY_AND_BUMP;
+
+    let accounts = crate::accounts::AcceptOperatorship {
+        system_program: anchor_lang::system_program::ID,
+        payer,
+        destination_user_account,
+        destination_roles_account,
+        resource_account,
+        origin_user_account,
+        origin_roles_account,
+        proposal_account,
+        event_authority,
+        program: crate::ID,
+    };
+
+    (
+        Instruction {
+            program_id: crate::ID,
+            accounts: accounts.to_account_metas(None),
+            data: crate::instruction::AcceptOperatorship {}.data(),
+        },
+        accounts,
+    )
+}
@@ -0,0 +1,103 @@
+use crate::{
+    events::OperatorshipProposalCancelled,
+    state::{InterchainTokenService, RoleProposal, UserRoles},
+    ItsError,
+};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{prelude::*, InstructionData};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelOperatorshipProposal<'info> {
+    /// Origin user account (signer who made the proposal, receives its rent back)
+    #[account(mut)]
+    pub origin_user_account: Signer<'info>,
+
+    /// Origin user roles account (current operator's roles)
+    #[account(
+        seeds = [
+            UserRoles::SEED_PREFIX,
+            resource_account.key().as_ref(),
+            origin_user_account.key().as_ref(),
+        ],
+        bump = origin_roles_account.bump,
+        constraint = origin_roles_account.has_operator_role() @ ItsError::MissingOperatorRole,
+    )]
+    pub origin_roles_account: Account<'info, UserRoles>,
+
+    /// The ITS root PDA (resource account)
+    #[account(
+        seeds = [InterchainTokenService::SEED_PREFIX],
+        bump = resource_account.bump,
+    )]
+    pub resource_account: Account<'info, InterchainTokenService>,
+
+    /// Destination user account of the proposal
+    /// CHECK: This is treated as an arbitrary user account and is only used for its public key.
+    pub destination_user_account: UncheckedAccount<'info>,
+
+    /// The proposal made with `propose_operatorship`
+    #[account(
+        mut,
+        seeds = [
+            RoleProposal::SEED_PREFIX,
+            resource_account.key().as_ref(),
+            origin_user_account.key().as_ref(),
+            destination_user_account.key().as_ref(),
+        ],
+        bump = proposal_account.bump,
+        close = origin_user_account,
+    )]
+    pub proposal_account: Account<'info, RoleProposal>,
+}
+
+/// Cancels a pending operatorship proposal.
+pub fn cancel_operatorship_proposal_handler(
+    ctx: Context<CancelOperatorshipProposal>,
+) -> Result<()> {
+    msg!("Instruction: CancelOperatorshipProposal");
+
+    emit_cpi!(OperatorshipProposalCancelled {
+        current_operator: ctx.accounts.origin_user_account.key(),
+        proposed_operator: ctx.accounts.destination_user_account.key(),
+    });
+
+    Ok(())
+}
+
+/// Creates a CancelOperatorshipProposal instruction
+pub fn make_cancel_operatorship_proposal_instruction(
+    origin_user_account: Pubkey,
+    destination_user_account: Pubkey,
+) -> (Instruction, crate::accounts::CancelOperatorshipProposal) {
+    let resource_account = InterchainTokenService::find_pda().0;
+
+    let origin_roles_account = UserRoles::find_pda(&resource_account, &origin_user_account).0;
+    let proposal_account = RoleProposal::find_pda(
+        &resource_account,
+        &origin_user_account,
+        &destination_user_account,
+    )
+    .0;
+
+    let (event_authority, _) = crate::EVENT_AUTHORITY_AND_BUMP;
+
+    let accounts = crate::accounts::CancelOperatorshipProposal {
+        origin_user_account,
+        origin_roles_account,
+        resource_account,
+        destination_user_account,
+        proposal_account,
+        event_authority,
+        program: crate::ID,
+    };
+
+    (
+        Instruction {
+            program_id: crate::ID,
+            accounts: accounts.to_account_metas(None),
+            data: crate::instruction::CancelOperatorshipProposal {}.data(),
+        },
+        accounts,
+    )
+}
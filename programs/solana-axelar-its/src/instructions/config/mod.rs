@@ -15,3 +15,12 @@ pub use set_flow_limit::*;
 
 pub mod transfer_operatorship;
 pub use transfer_operatorship::*;
+
+pub mod propose_operatorship;
+pub use propose_operatorship::*;
+
+pub mod accept_operatorship;
+pub use accept_operatorship::*;
+
+pub mod cancel_operatorship_proposal;
+pub use cancel_operatorship_proposal::*;
@@ -0,0 +1,119 @@
+use crate::{
+    events::OperatorshipProposed,
+    state::{roles, InterchainTokenService, RoleProposal, UserRoles},
+    ItsError,
+};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{prelude::*, InstructionData};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeOperatorship<'info> {
+    pub system_program: Program<'info, System>,
+
+    /// Payer for transaction fees and account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Origin user account (signer who currently has OPERATOR role)
+    pub origin_user_account: Signer<'info>,
+
+    /// Origin user roles account (current operator's roles)
+    #[account(
+        seeds = [
+            UserRoles::SEED_PREFIX,
+            resource_account.key().as_ref(),
+            origin_user_account.key().as_ref(),
+        ],
+        bump = origin_roles_account.bump,
+        constraint = origin_roles_account.has_operator_role() @ ItsError::MissingOperatorRole,
+    )]
+    pub origin_roles_account: Account<'info, UserRoles>,
+
+    /// The ITS root PDA (resource account)
+    #[account(
+        seeds = [InterchainTokenService::SEED_PREFIX],
+        bump = resource_account.bump,
+    )]
+    pub resource_account: Account<'info, InterchainTokenService>,
+
+    /// Destination user account (will be able to accept the OPERATOR role)
+    /// CHECK: This is treated as an arbitrary user account and is only used for its public key.
+    #[account(
+        constraint = destination_user_account.key() != origin_user_account.key() @ ItsError::InvalidArgument,
+    )]
+    pub destination_user_account: UncheckedAccount<'info>,
+
+    /// The proposal, accepted with `accept_operatorship`
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RoleProposal::DISCRIMINATOR.len() + RoleProposal::INIT_SPACE,
+        seeds = [
+            RoleProposal::SEED_PREFIX,
+            resource_account.key().as_ref(),
+            origin_user_account.key().as_ref(),
+            destination_user_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal_account: Account<'info, RoleProposal>,
+}
+
+/// First step of the two-step operatorship transfer: proposes the destination
+/// user as the new operator. The OPERATOR role is only moved once it accepts.
+pub fn propose_operatorship_handler(ctx: Context<ProposeOperatorship>) -> Result<()> {
+    msg!("Instruction: ProposeOperatorship");
+
+    ctx.accounts.proposal_account.set_inner(RoleProposal {
+        roles: roles::OPERATOR,
+        bump: ctx.bumps.proposal_account,
+    });
+
+    emit_cpi!(OperatorshipProposed {
+        current_operator: ctx.accounts.origin_user_account.key(),
+        proposed_operator: ctx.accounts.destination_user_account.key(),
+    });
+
+    Ok(())
+}
+
+/// Creates a ProposeOperatorship instruction
+pub fn make_propose_operatorship_instruction(
+    payer: Pubkey,
+    origin_user_account: Pubkey,
+    destination_user_account: Pubkey,
+) -> (Instruction, crate::accounts::ProposeOperatorship) {
+    let resource_account = InterchainTokenService::find_pda().0;
+
+    let origin_roles_account = UserRoles::find_pda(&resource_account, &origin_user_account).0;
+    let proposal_account = RoleProposal::find_pda(
+        &resource_account,
+        &origin_user_account,
+        &destination_user_account,
+    )
+    .0;
+
+    let (event_authority, _) = crate::EVENT_AUTHORITY_AND_BUMP;
+
+    let accounts = crate::accounts::ProposeOperatorship {
+        system_program: anchor_lang::system_program::ID,
+        payer,
+        origin_user_account,
+        origin_roles_account,
+        resource_account,
+        destination_user_account,
+        proposal_account,
+        event_authority,
+        program: crate::ID,
+    };
+
+    (
+        Instruction {
+            program_id: crate::ID,
+            accounts: accounts.to_account_metas(None),
+            data: crate::instruction::ProposeOperatorship {}.data(),
+        },
+        accounts,
+    )
+}
@@ -87,6 +87,7 @@ impl<'info> LinkToken<'info> {
             gas_treasury: self.gas_treasury.to_account_info(),
             gas_service: self.gas_service.to_account_info(),
             gas_event_authority: self.gas_event_authority.to_account_info(),
+            gateway_destination_chain_registry: None,
         }
     }
 }
@@ -145,7 +146,10 @@ pub fn link_token_handler(
         },
     });
 
-    let gmp_accounts = ctx.accounts.to_gmp_accounts();
+    let gmp_accounts = ctx
+        .accounts
+        .to_gmp_accounts()
+        .with_destination_chain_registry(ctx.remaining_accounts);
 
     // Process the outbound GMP message
     send_to_hub_wrap(gmp_accounts, payload, destination_chain, gas_value)?;
@@ -68,6 +68,7 @@ impl<'info> ToGMPAccounts<'info> for RegisterTokenMetadata<'info> {
             gas_service: self.gas_service.to_account_info(),
             gas_treasury: self.gas_treasury.to_account_info(),
             gas_event_authority: self.gas_event_authority.to_account_info(),
+            gateway_destination_chain_registry: None,
         }
     }
 }
@@ -91,7 +92,10 @@ pub fn register_token_metadata_handler(
         token_address: token_address.to_bytes().to_vec(),
     });
 
-    let gmp_accounts = ctx.accounts.to_gmp_accounts();
+    let gmp_accounts = ctx
+        .accounts
+        .to_gmp_accounts()
+        .with_destination_chain_registry(ctx.remaining_accounts);
 
     send_to_hub(gmp_accounts, payload, gas_value)?;
 
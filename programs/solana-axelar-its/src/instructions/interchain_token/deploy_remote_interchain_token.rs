@@ -105,6 +105,7 @@ impl<'info> ToGMPAccounts<'info> for DeployRemoteInterchainToken<'info> {
             gas_service: self.gas_service.to_account_info(),
             gas_treasury: self.gas_treasury.to_account_info(),
             gas_event_authority: self.gas_event_authority.to_account_info(),
+            gateway_destination_chain_registry: None,
         }
     }
 }
@@ -146,7 +147,10 @@ pub fn deploy_remote_interchain_token_handler(
         minter: None,
     });
 
-    let gmp_accounts = ctx.accounts.to_gmp_accounts();
+    let gmp_accounts = ctx
+        .accounts
+        .to_gmp_accounts()
+        .with_destination_chain_registry(ctx.remaining_accounts);
 
     send_to_hub_wrap(gmp_accounts, payload, destination_chain, gas_value)?;
 
@@ -141,6 +141,7 @@ impl<'info> ToGMPAccounts<'info> for InterchainTransfer<'info> {
             gas_service: self.gas_service.to_account_info(),
             gas_treasury: self.gas_treasury.to_account_info(),
             gas_event_authority: self.gas_event_authority.to_account_info(),
+            gateway_destination_chain_registry: None,
         }
     }
 }
@@ -257,7 +258,10 @@ fn process_outbound_transfer(
         data,
     });
 
-    let gmp_accounts = ctx.accounts.to_gmp_accounts();
+    let gmp_accounts = ctx
+        .accounts
+        .to_gmp_accounts()
+        .with_destination_chain_registry(ctx.remaining_accounts);
     send_to_hub_wrap(gmp_accounts, payload, destination_chain, gas_value)?;
 
     Ok(())
@@ -310,6 +310,18 @@ pub mod solana_axelar_its {
         instructions::transfer_operatorship_handler(ctx)
     }
 
+    pub fn propose_operatorship(ctx: Context<ProposeOperatorship>) -> Result<()> {
+        instructions::propose_operatorship_handler(ctx)
+    }
+
+    pub fn accept_operatorship(ctx: Context<AcceptOperatorship>) -> Result<()> {
+        instructions::accept_operatorship_handler(ctx)
+    }
+
+    pub fn cancel_operatorship_proposal(ctx: Context<CancelOperatorshipProposal>) -> Result<()> {
+        instructions::cancel_operatorship_proposal_handler(ctx)
+    }
+
     pub fn add_token_manager_flow_limiter(ctx: Context<AddTokenManagerFlowLimiter>) -> Result<()> {
         instructions::add_token_manager_flow_limiter_handler(ctx)
     }
@@ -12,3 +12,6 @@ pub use flow_state::*;
 
 pub mod interchain_transfer_execute;
 pub use interchain_transfer_execute::*;
+
+pub mod role_proposal;
+pub use role_proposal::*;
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+/// A pending transfer of roles over a resource, from the origin user to the
+/// destination user. The roles are only moved once the destination user
+/// accepts the proposal.
+#[account]
+#[derive(InitSpace, PartialEq, Eq, Copy, Debug)]
+pub struct RoleProposal {
+    /// The proposed roles
+    pub roles: u8,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+}
+
+impl RoleProposal {
+    /// The seeds for the PDA are:
+    /// - SEED_PREFIX
+    /// - Resource key (e.g. the ITS Root PDA)
+    /// - Origin user key
+    /// - Destination user key
+    pub const SEED_PREFIX: &'static [u8] = b"role-proposal";
+
+    pub fn pda_seeds<'a>(
+        resource: &'a Pubkey,
+        origin: &'a Pubkey,
+        destination: &'a Pubkey,
+    ) -> [&'a [u8]; 4] {
+        [
+            Self::SEED_PREFIX,
+            resource.as_ref(),
+            origin.as_ref(),
+            destination.as_ref(),
+        ]
+    }
+
+    pub fn try_find_pda(
+        resource: &Pubkey,
+        origin: &Pubkey,
+        destination: &Pubkey,
+    ) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &Self::pda_seeds(resource, origin, destination),
+            &crate::ID,
+        )
+    }
+
+    pub fn find_pda(resource: &Pubkey, origin: &Pubkey, destination: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::pda_seeds(resource, origin, destination), &crate::ID)
+    }
+}
@@ -7,9 +7,11 @@ use mollusk_harness::{ItsTestHarness, TestHarness};
 use mollusk_svm::result::Check;
 
 use solana_axelar_its::instructions::{
-    make_initialize_instruction, make_set_pause_status_instruction,
+    make_accept_operatorship_instruction, make_cancel_operatorship_proposal_instruction,
+    make_initialize_instruction, make_propose_operatorship_instruction,
+    make_set_pause_status_instruction,
 };
-use solana_axelar_its::{roles, ItsError, UserRoles};
+use solana_axelar_its::{roles, ItsError, RoleProposal, UserRoles};
 
 //
 // Initialize
@@ -207,3 +209,103 @@ fn transfer_operatorship_without_permissions() {
         .ctx
         .process_and_validate_instruction(&ix, &[Check::err(ItsError::MissingOperatorRole.into())]);
 }
+
+//
+// Two-step operatorship transfer
+//
+
+#[test]
+fn propose_and_accept_operatorship() {
+    let mut its_harness = ItsTestHarness::new();
+
+    let curr_operator = its_harness.operator;
+    let new_operator = its_harness.get_new_wallet();
+
+    its_harness.ensure_propose_operatorship(new_operator);
+
+    // Proposing doesn't move the role yet
+    let new_roles_pda = UserRoles::find_pda(&its_harness.its_root, &new_operator).0;
+    assert!(!its_harness.account_exists(&new_roles_pda));
+
+    its_harness.ensure_accept_operatorship(new_operator);
+
+    let curr_roles_pda = UserRoles::find_pda(&its_harness.its_root, &curr_operator).0;
+    assert!(
+        !its_harness.account_exists(&curr_roles_pda),
+        "previous operator roles account should be closed"
+    );
+}
+
+#[test]
+fn accept_operatorship_by_other_account() {
+    let its_harness = ItsTestHarness::new();
+
+    let new_operator = its_harness.get_new_wallet();
+    let other = its_harness.get_new_wallet();
+
+    its_harness.ensure_propose_operatorship(new_operator);
+
+    // Only the proposed operator can accept
+    let ix = make_accept_operatorship_instruction(its_harness.payer, other, its_harness.operator).0;
+
+    its_harness.ctx.process_and_validate_instruction(
+        &ix,
+        &[Check::err({
+            let e: anchor_lang::error::Error =
+                anchor_lang::error::ErrorCode::AccountNotInitialized.into();
+            e.into()
+        })],
+    );
+}
+
+#[test]
+fn cancel_operatorship_proposal() {
+    let its_harness = ItsTestHarness::new();
+
+    let new_operator = its_harness.get_new_wallet();
+    its_harness.ensure_propose_operatorship(new_operator);
+
+    let ix = make_cancel_operatorship_proposal_instruction(its_harness.operator, new_operator).0;
+    its_harness
+        .ctx
+        .process_and_validate_instruction(&ix, &[Check::success()]);
+
+    let proposal_pda =
+        RoleProposal::find_pda(&its_harness.its_root, &its_harness.operator, &new_operator).0;
+    assert!(!its_harness.account_exists(&proposal_pda));
+
+    // The cancelled proposal can't be accepted anymore
+    let ix =
+        make_accept_operatorship_instruction(its_harness.payer, new_operator, its_harness.operator)
+            .0;
+
+    its_harness.ctx.process_and_validate_instruction(
+        &ix,
+        &[Check::err({
+            let e: anchor_lang::error::Error =
+                anchor_lang::error::ErrorCode::AccountNotInitialized.into();
+            e.into()
+        })],
+    );
+}
+
+#[test]
+fn propose_operatorship_without_permissions() {
+    let mut its_harness = ItsTestHarness::new();
+
+    let curr_operator = its_harness.operator;
+    let curr_roles_pda = UserRoles::find_pda(&its_harness.its_root, &curr_operator).0;
+
+    // Set only FLOW_LIMITER role to current operator
+    its_harness
+        .update_account_as::<UserRoles, _>(&curr_roles_pda, |ur| ur.roles = roles::FLOW_LIMITER);
+
+    let new_operator = its_harness.get_new_wallet();
+
+    let ix =
+        make_propose_operatorship_instruction(its_harness.payer, curr_operator, new_operator).0;
+
+    its_harness
+        .ctx
+        .process_and_validate_instruction(&ix, &[Check::err(ItsError::MissingOperatorRole.into())]);
+}
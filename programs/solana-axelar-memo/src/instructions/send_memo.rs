@@ -51,6 +51,9 @@ pub fn send_memo_handler(
         caller: ctx.accounts.memo_program.to_account_info(),
         signing_pda: Some(ctx.accounts.signing_pda.to_account_info()),
         gateway_root_pda: ctx.accounts.gateway_root_pda.to_account_info(),
+        // The gateway's destination chain registry, once it has been initialized
+        destination_chain_registry: ctx.remaining_accounts.first().cloned(),
+        call_contract_nonce: None,
         // For event_cpi
         event_authority: ctx.accounts.gateway_event_authority.to_account_info(),
         program: ctx.accounts.gateway_program.to_account_info(),
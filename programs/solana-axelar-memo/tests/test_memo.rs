@@ -8,11 +8,7 @@ use solana_axelar_gateway::executable::{ExecutablePayload, ExecutablePayloadEnco
 use solana_axelar_memo::{Counter, ID as MEMO_PROGRAM_ID};
 use solana_sdk::instruction::{AccountMeta, Instruction};
 
-#[test]
-fn send_memo_to_gateway() {
-    let mut harness = ItsTestHarness::new();
-    harness.ensure_memo_program_initialized();
-
+fn send_memo_ix(harness: &ItsTestHarness) -> Instruction {
     let send_memo_ix = solana_axelar_memo::instruction::SendMemo {
         destination_chain: "ethereum".to_owned(),
         destination_address: "0xDestinationAddress".to_owned(),
@@ -30,12 +26,46 @@ fn send_memo_to_gateway() {
         gateway_program: solana_axelar_gateway::ID,
     };
 
-    let ix = Instruction {
+    Instruction {
         program_id: MEMO_PROGRAM_ID,
         accounts: accounts.to_account_metas(None),
         data: send_memo_ix.data(),
-    };
+    }
+}
+
+#[test]
+fn send_memo_to_gateway() {
+    let mut harness = ItsTestHarness::new();
+    harness.ensure_memo_program_initialized();
+
+    let ix = send_memo_ix(&harness);
+    harness
+        .ctx
+        .process_and_validate_instruction(&ix, &[Check::success()]);
+}
+
+#[test]
+fn send_memo_with_destination_chain_registry() {
+    let mut harness = ItsTestHarness::new();
+    harness.ensure_memo_program_initialized();
+    harness.ensure_destination_chain_registered("ethereum");
+
+    // The gateway requires the registry once it has been initialized
+    let mut ix = send_memo_ix(&harness);
+    harness.ctx.process_and_validate_instruction(
+        &ix,
+        &[Check::err(
+            anchor_lang::error::Error::from(
+                solana_axelar_gateway::GatewayError::MissingDestinationChainRegistry,
+            )
+            .into(),
+        )],
+    );
 
+    ix.accounts.push(AccountMeta::new_readonly(
+        solana_axelar_gateway::DestinationChainRegistry::find_pda().0,
+        false,
+    ));
     harness
         .ctx
         .process_and_validate_instruction(&ix, &[Check::success()]);
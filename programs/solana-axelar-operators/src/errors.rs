@@ -6,4 +6,8 @@ pub enum ErrorCode {
     UnauthorizedOwner,
     #[msg("New owner cannot be the same as current owner")]
     SameOwner,
+    #[msg("Only the proposed owner can accept the ownership")]
+    UnauthorizedProposedOwner,
+    #[msg("The ownership proposal was made by a previous owner")]
+    StaleOwnershipProposal,
 }
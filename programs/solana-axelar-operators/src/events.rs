@@ -5,3 +5,15 @@ pub struct OwnershipTransferred {
     pub old_owner: Pubkey,
     pub new_owner: Pubkey,
 }
+
+#[event]
+pub struct OwnershipProposed {
+    pub owner: Pubkey,
+    pub proposed_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnershipProposalCancelled {
+    pub owner: Pubkey,
+    pub proposed_owner: Pubkey,
+}
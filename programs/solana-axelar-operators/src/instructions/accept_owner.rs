@@ -0,0 +1,56 @@
+use crate::events::OwnershipTransferred;
+use crate::state::*;
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    #[account(
+        address = proposal.proposed_owner @ ErrorCode::UnauthorizedProposedOwner
+    )]
+    pub proposed_owner: Signer<'info>,
+
+    /// CHECK: The owner that made the proposal, which paid for it and gets its rent back
+    #[account(
+        mut,
+        address = proposal.owner @ ErrorCode::UnauthorizedOwner
+    )]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OperatorRegistry::SEED_PREFIX],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, OperatorRegistry>,
+
+    #[account(
+        mut,
+        seeds = [
+            OwnershipProposal::SEED_PREFIX,
+            proposal.owner.as_ref(),
+            proposed_owner.key().as_ref(),
+        ],
+        bump = proposal.bump,
+        // The owner that made the proposal still owns the registry
+        constraint = proposal.owner == registry.owner @ ErrorCode::StaleOwnershipProposal,
+        close = owner,
+    )]
+    pub proposal: Account<'info, OwnershipProposal>,
+}
+
+/// Accepts a pending ownership proposal, transferring the ownership to the
+/// proposed owner.
+pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    // Update the owner
+    registry.owner = ctx.accounts.proposed_owner.key();
+
+    emit!(OwnershipTransferred {
+        old_owner: ctx.accounts.owner.key(),
+        new_owner: ctx.accounts.proposed_owner.key(),
+    });
+
+    Ok(())
+}
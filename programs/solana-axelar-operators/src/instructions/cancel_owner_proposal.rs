@@ -0,0 +1,49 @@
+use crate::events::OwnershipProposalCancelled;
+use crate::state::*;
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelOwnerProposal<'info> {
+    #[account(
+        mut,
+        address = registry.owner @ ErrorCode::UnauthorizedOwner
+    )]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [OperatorRegistry::SEED_PREFIX],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, OperatorRegistry>,
+
+    #[account(
+        mut,
+        seeds = [
+            OwnershipProposal::SEED_PREFIX,
+            proposal.owner.as_ref(),
+            proposal.proposed_owner.as_ref(),
+        ],
+        bump = proposal.bump,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, OwnershipProposal>,
+
+    /// CHECK: The owner that made the proposal, which paid for it and gets its rent back
+    #[account(
+        mut,
+        address = proposal.owner @ ErrorCode::UnauthorizedOwner
+    )]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+/// Cancels a pending ownership proposal, including stale ones made by a
+/// previous owner.
+pub fn cancel_owner_proposal(ctx: Context<CancelOwnerProposal>) -> Result<()> {
+    emit!(OwnershipProposalCancelled {
+        owner: ctx.accounts.owner.key(),
+        proposed_owner: ctx.accounts.proposal.proposed_owner,
+    });
+
+    Ok(())
+}
@@ -9,3 +9,12 @@ pub use remove_operator::*;
 
 pub mod transfer_owner;
 pub use transfer_owner::*;
+
+pub mod propose_owner;
+pub use propose_owner::*;
+
+pub mod accept_owner;
+pub use accept_owner::*;
+
+pub mod cancel_owner_proposal;
+pub use cancel_owner_proposal::*;
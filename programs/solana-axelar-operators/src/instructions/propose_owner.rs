@@ -0,0 +1,57 @@
+use crate::events::OwnershipProposed;
+use crate::state::*;
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    #[account(
+        mut,
+        address = registry.owner @ ErrorCode::UnauthorizedOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The proposed owner pubkey
+    #[account(
+        // Ensure the proposed owner is not the same as the current owner
+        constraint = proposed_owner.key() != registry.owner @ ErrorCode::SameOwner
+    )]
+    pub proposed_owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [OperatorRegistry::SEED_PREFIX],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, OperatorRegistry>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = OwnershipProposal::DISCRIMINATOR.len() + OwnershipProposal::INIT_SPACE,
+        seeds = [
+            OwnershipProposal::SEED_PREFIX,
+            owner.key().as_ref(),
+            proposed_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, OwnershipProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes a new owner, which only takes over once it signs `accept_owner`.
+pub fn propose_owner(ctx: Context<ProposeOwner>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.owner = ctx.accounts.owner.key();
+    proposal.proposed_owner = ctx.accounts.proposed_owner.key();
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(OwnershipProposed {
+        owner: ctx.accounts.owner.key(),
+        proposed_owner: ctx.accounts.proposed_owner.key(),
+    });
+
+    Ok(())
+}
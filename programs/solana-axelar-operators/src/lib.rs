@@ -48,4 +48,16 @@ pub mod operators {
     pub fn transfer_owner(ctx: Context<TransferOwner>) -> Result<()> {
         instructions::transfer_owner(ctx)
     }
+
+    pub fn propose_owner(ctx: Context<ProposeOwner>) -> Result<()> {
+        instructions::propose_owner(ctx)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        instructions::accept_owner(ctx)
+    }
+
+    pub fn cancel_owner_proposal(ctx: Context<CancelOwnerProposal>) -> Result<()> {
+        instructions::cancel_owner_proposal(ctx)
+    }
 }
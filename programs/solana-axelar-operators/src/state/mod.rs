@@ -51,3 +51,33 @@ impl OperatorAccount {
         Pubkey::find_program_address(&Self::pda_seeds(operator), &crate::ID)
     }
 }
+
+/// A pending registry ownership transfer, closed once the proposed owner
+/// accepts it or the proposal is cancelled. It can only be accepted as long as
+/// the owner that made it still owns the registry.
+#[account]
+#[derive(InitSpace)]
+pub struct OwnershipProposal {
+    /// The owner that made the proposal and paid for it
+    pub owner: Pubkey,
+    /// The account that has to accept the ownership
+    pub proposed_owner: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl OwnershipProposal {
+    pub const SEED_PREFIX: &'static [u8] = b"ownership_proposal";
+
+    pub fn pda_seeds<'a>(owner: &'a Pubkey, proposed_owner: &'a Pubkey) -> [&'a [u8]; 3] {
+        [Self::SEED_PREFIX, owner.as_ref(), proposed_owner.as_ref()]
+    }
+
+    pub fn try_find_pda(owner: &Pubkey, proposed_owner: &Pubkey) -> Option<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(&Self::pda_seeds(owner, proposed_owner), &crate::ID)
+    }
+
+    pub fn find_pda(owner: &Pubkey, proposed_owner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::pda_seeds(owner, proposed_owner), &crate::ID)
+    }
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 use anchor_lang::{AccountDeserialize, Key};
 use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
-use solana_axelar_operators::{OperatorAccount, OperatorRegistry};
+use solana_axelar_operators::{OperatorAccount, OperatorRegistry, OwnershipProposal};
 use solana_sdk::account::ReadableAccount;
 use {
     anchor_lang::{
@@ -331,3 +331,252 @@ fn transfer_master_works() {
         (operator, operator_account),
     );
 }
+
+fn propose_owner_ix(
+    program_id: Pubkey,
+    registry: Pubkey,
+    owner: Pubkey,
+    proposed_owner: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: solana_axelar_operators::accounts::ProposeOwner {
+            owner,
+            proposed_owner,
+            registry,
+            proposal: OwnershipProposal::find_pda(&owner, &proposed_owner).0,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_operators::instruction::ProposeOwner {}.data(),
+    }
+}
+
+fn accept_owner_ix(
+    program_id: Pubkey,
+    registry: Pubkey,
+    proposal: Pubkey,
+    owner: Pubkey,
+    proposed_owner: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: solana_axelar_operators::accounts::AcceptOwner {
+            proposed_owner,
+            owner,
+            registry,
+            proposal,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_operators::instruction::AcceptOwner {}.data(),
+    }
+}
+
+fn cancel_owner_proposal_ix(
+    program_id: Pubkey,
+    registry: Pubkey,
+    owner: Pubkey,
+    proposer: Pubkey,
+    proposed_owner: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: solana_axelar_operators::accounts::CancelOwnerProposal {
+            owner,
+            registry,
+            proposal: OwnershipProposal::find_pda(&proposer, &proposed_owner).0,
+            proposer,
+        }
+        .to_account_metas(None),
+        data: solana_axelar_operators::instruction::CancelOwnerProposal {}.data(),
+    }
+}
+
+#[test]
+fn propose_and_accept_owner() {
+    let program_id = solana_axelar_operators::id();
+    let mollusk = setup_mollusk(&program_id, "solana_axelar_operators");
+
+    let owner = Pubkey::new_unique();
+    let owner_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let (registry, registry_account) = setup_registry(&mollusk, program_id, owner, &owner_account);
+
+    let new_owner = Pubkey::new_unique();
+    let new_owner_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let proposal = OwnershipProposal::find_pda(&owner, &new_owner).0;
+
+    let result = mollusk.process_and_validate_instruction(
+        &propose_owner_ix(program_id, registry, owner, new_owner),
+        &[
+            (owner, owner_account),
+            (new_owner, new_owner_account.clone()),
+            (registry, registry_account),
+            (proposal, Account::new(0, 0, &system_program::ID)),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            // Proposing doesn't transfer the ownership yet
+            Check::account(&registry)
+                .data_slice(OperatorRegistry::DISCRIMINATOR.len(), owner.as_array())
+                .build(),
+        ],
+    );
+
+    let get = |key: &Pubkey| {
+        result
+            .get_account(key)
+            .expect("account should exist")
+            .clone()
+    };
+
+    mollusk.process_and_validate_instruction(
+        &accept_owner_ix(program_id, registry, proposal, owner, new_owner),
+        &[
+            (new_owner, new_owner_account),
+            (owner, get(&owner)),
+            (registry, get(&registry)),
+            (proposal, get(&proposal)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&registry)
+                .data_slice(OperatorRegistry::DISCRIMINATOR.len(), new_owner.as_array())
+                .build(),
+            Check::account(&proposal).closed().build(),
+        ],
+    );
+}
+
+#[test]
+fn accept_owner_unauthorized() {
+    let program_id = solana_axelar_operators::id();
+    let mollusk = setup_mollusk(&program_id, "solana_axelar_operators");
+
+    let owner = Pubkey::new_unique();
+    let owner_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let (registry, registry_account) = setup_registry(&mollusk, program_id, owner, &owner_account);
+
+    let new_owner = Pubkey::new_unique();
+    let new_owner_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let other = Pubkey::new_unique();
+    let other_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let proposal = OwnershipProposal::find_pda(&owner, &new_owner).0;
+
+    let result = mollusk.process_and_validate_instruction(
+        &propose_owner_ix(program_id, registry, owner, new_owner),
+        &[
+            (owner, owner_account),
+            (new_owner, new_owner_account),
+            (registry, registry_account),
+            (proposal, Account::new(0, 0, &system_program::ID)),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::success()],
+    );
+
+    let get = |key: &Pubkey| {
+        result
+            .get_account(key)
+            .expect("account should exist")
+            .clone()
+    };
+
+    let err: anchor_lang::error::Error =
+        solana_axelar_operators::ErrorCode::UnauthorizedProposedOwner.into();
+    mollusk.process_and_validate_instruction(
+        &accept_owner_ix(program_id, registry, proposal, owner, other),
+        &[
+            (other, other_account),
+            (owner, get(&owner)),
+            (registry, get(&registry)),
+            (proposal, get(&proposal)),
+        ],
+        &[Check::err(err.into())],
+    );
+}
+
+#[test]
+fn accept_stale_owner_proposal() {
+    let program_id = solana_axelar_operators::id();
+    let mollusk = setup_mollusk(&program_id, "solana_axelar_operators");
+
+    let owner = Pubkey::new_unique();
+    let owner_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let (registry, registry_account) = setup_registry(&mollusk, program_id, owner, &owner_account);
+
+    let first = Pubkey::new_unique();
+    let first_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let second = Pubkey::new_unique();
+    let second_account = Account::new(1_000_000_000, 0, &system_program::ID);
+    let first_proposal = OwnershipProposal::find_pda(&owner, &first).0;
+    let second_proposal = OwnershipProposal::find_pda(&owner, &second).0;
+
+    // A pending proposal doesn't block proposing someone else
+    let result = mollusk.process_and_validate_instruction_chain(
+        &[
+            (
+                &propose_owner_ix(program_id, registry, owner, first),
+                &[Check::success()],
+            ),
+            (
+                &propose_owner_ix(program_id, registry, owner, second),
+                &[Check::success()],
+            ),
+            (
+                &accept_owner_ix(program_id, registry, second_proposal, owner, second),
+                &[Check::success()],
+            ),
+        ],
+        &[
+            (owner, owner_account),
+            (first, first_account.clone()),
+            (second, second_account),
+            (registry, registry_account),
+            (first_proposal, Account::new(0, 0, &system_program::ID)),
+            (second_proposal, Account::new(0, 0, &system_program::ID)),
+            keyed_account_for_system_program(),
+        ],
+    );
+
+    let get = |key: &Pubkey| {
+        result
+            .get_account(key)
+            .expect("account should exist")
+            .clone()
+    };
+
+    // The first proposal was made by the previous owner
+    let err: anchor_lang::error::Error =
+        solana_axelar_operators::ErrorCode::StaleOwnershipProposal.into();
+    mollusk.process_and_validate_instruction(
+        &accept_owner_ix(program_id, registry, first_proposal, owner, first),
+        &[
+            (first, first_account),
+            (owner, get(&owner)),
+            (registry, get(&registry)),
+            (first_proposal, get(&first_proposal)),
+        ],
+        &[
+            Check::err(err.into()),
+            Check::account(&registry)
+                .data_slice(OperatorRegistry::DISCRIMINATOR.len(), second.as_array())
+                .build(),
+        ],
+    );
+
+    // The new owner can clean it up, refunding the previous owner
+    mollusk.process_and_validate_instruction(
+        &cancel_owner_proposal_ix(program_id, registry, second, owner, first),
+        &[
+            (second, get(&second)),
+            (registry, get(&registry)),
+            (first_proposal, get(&first_proposal)),
+            (owner, get(&owner)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&first_proposal).closed().build(),
+        ],
+    );
+}
@@ -118,6 +118,22 @@ fn main() -> eyre::Result<()> {
                 "cargo clippy --no-deps --all-targets --workspace --locked -- -D warnings"
             )
             .run()?;
+            // Feature-gated code isn't built by the workspace run
+            cmd!(
+                sh,
+                "cargo clippy --no-deps --all-targets -p solana-axelar-gateway --features client --locked -- -D warnings"
+            )
+            .run()?;
+            cmd!(
+                sh,
+                "cargo clippy --no-deps --all-targets -p solana-axelar-std --features verify --locked -- -D warnings"
+            )
+            .run()?;
+            cmd!(
+                sh,
+                "cargo clippy --no-deps --all-targets -p solana-axelar-std --features serde --locked -- -D warnings"
+            )
+            .run()?;
             // For some reason cargo clippy doesn't catch all issues in solana-axelar-std
             // TODO check why and possibly remove this extra check
             cmd!(sh, "cargo check -p solana-axelar-std").run()?;